@@ -17,9 +17,6 @@ CHECKOUT="${GITHUB_HEAD_REF:-$GITHUB_SHA}"
 git checkout "$CHECKOUT" &&
 cargo bench --bench eulerbench -- --noplot --save-baseline after &&
 
-# Install https://github.com/BurntSushi/critcmp
-cargo install critcmp --force &&
-
-# Compare the two generated benches
-critcmp before after;
+# Compare the two generated benches, failing on significant regressions
+cargo run --release -p summarize -- --all --compare before after --max-regression 10
 
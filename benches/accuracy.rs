@@ -0,0 +1,531 @@
+//! Measures the numerical accuracy of the benchmarked operations. Each library
+//! computes an operation in f32 over seeded inputs, and the result is compared
+//! against glam's f64 implementation evaluated on the same input values.
+//!
+//! Run with `cargo bench --bench accuracy`. The results are written to
+//! `mathbench-accuracy/<baseline>.json` next to the Criterion output, where
+//! `summarize` picks them up.
+#[path = "support/environment.rs"]
+mod environment;
+use glam::{DMat2, DMat3, DMat4, DQuat, DVec3, DVec4};
+use mathbench::{BenchValue, Components};
+use serde::Serialize;
+use std::env;
+
+const SAMPLES: usize = 10_000;
+
+/// An operation and its f64 reference implementation. Inputs and outputs are
+/// flattened by [`Components`]. Unary operations ignore the second input.
+struct Operation {
+    name: &'static str,
+    reference: fn(&[f64], &[f64]) -> Vec<f64>,
+}
+
+const MATRIX2_DETERMINANT: Operation = Operation {
+    name: "matrix2 determinant",
+    reference: |a, _| vec![DMat2::from_cols_slice(a).determinant()],
+};
+const MATRIX2_INVERSE: Operation = Operation {
+    name: "matrix2 inverse",
+    reference: |a, _| DMat2::from_cols_slice(a).inverse().to_cols_array().to_vec(),
+};
+const MATRIX2_MUL_MATRIX2: Operation = Operation {
+    name: "matrix2 mul matrix2",
+    reference: |a, b| {
+        (DMat2::from_cols_slice(a) * DMat2::from_cols_slice(b))
+            .to_cols_array()
+            .to_vec()
+    },
+};
+const MATRIX3_DETERMINANT: Operation = Operation {
+    name: "matrix3 determinant",
+    reference: |a, _| vec![DMat3::from_cols_slice(a).determinant()],
+};
+const MATRIX3_INVERSE: Operation = Operation {
+    name: "matrix3 inverse",
+    reference: |a, _| DMat3::from_cols_slice(a).inverse().to_cols_array().to_vec(),
+};
+const MATRIX3_MUL_MATRIX3: Operation = Operation {
+    name: "matrix3 mul matrix3",
+    reference: |a, b| {
+        (DMat3::from_cols_slice(a) * DMat3::from_cols_slice(b))
+            .to_cols_array()
+            .to_vec()
+    },
+};
+const MATRIX4_DETERMINANT: Operation = Operation {
+    name: "matrix4 determinant",
+    reference: |a, _| vec![DMat4::from_cols_slice(a).determinant()],
+};
+const MATRIX4_INVERSE: Operation = Operation {
+    name: "matrix4 inverse",
+    reference: |a, _| DMat4::from_cols_slice(a).inverse().to_cols_array().to_vec(),
+};
+const MATRIX4_MUL_MATRIX4: Operation = Operation {
+    name: "matrix4 mul matrix4",
+    reference: |a, b| {
+        (DMat4::from_cols_slice(a) * DMat4::from_cols_slice(b))
+            .to_cols_array()
+            .to_vec()
+    },
+};
+const MATRIX4_MUL_VECTOR4: Operation = Operation {
+    name: "matrix4 mul vector4",
+    reference: |a, b| {
+        (DMat4::from_cols_slice(a) * DVec4::from_slice(b))
+            .to_array()
+            .to_vec()
+    },
+};
+const VECTOR3_LENGTH: Operation = Operation {
+    name: "vector3 length",
+    reference: |a, _| vec![DVec3::from_slice(a).length()],
+};
+const VECTOR3_NORMALIZE: Operation = Operation {
+    name: "vector3 normalize",
+    reference: |a, _| DVec3::from_slice(a).normalize().to_array().to_vec(),
+};
+const VECTOR3_DOT: Operation = Operation {
+    name: "vector3 dot",
+    reference: |a, b| vec![DVec3::from_slice(a).dot(DVec3::from_slice(b))],
+};
+const VECTOR3_CROSS: Operation = Operation {
+    name: "vector3 cross",
+    reference: |a, b| {
+        DVec3::from_slice(a)
+            .cross(DVec3::from_slice(b))
+            .to_array()
+            .to_vec()
+    },
+};
+const ROTATION3_MUL_ROTATION3: Operation = Operation {
+    name: "rotation3 mul rotation3",
+    reference: |a, b| {
+        (DQuat::from_slice(a) * DQuat::from_slice(b))
+            .to_array()
+            .to_vec()
+    },
+};
+const ROTATION3_MUL_VECTOR3: Operation = Operation {
+    name: "rotation3 mul vector3",
+    // f32 rotations are only unit length to within rounding, the reference
+    // rotates by the exact rotation they represent
+    reference: |a, b| {
+        (DQuat::from_slice(a).normalize() * DVec3::from_slice(b))
+            .to_array()
+            .to_vec()
+    },
+};
+const TRANSFORM_VECTOR3: Operation = Operation {
+    name: "transform vector3",
+    reference: |a, b| {
+        DMat4::from_cols_slice(a)
+            .transform_vector3(DVec3::from_slice(b))
+            .to_array()
+            .to_vec()
+    },
+};
+const TRANSFORM_POINT3: Operation = Operation {
+    name: "transform point3",
+    // some libraries accept projective matrices, so divide by w
+    reference: |a, b| {
+        DMat4::from_cols_slice(a)
+            .project_point3(DVec3::from_slice(b))
+            .to_array()
+            .to_vec()
+    },
+};
+
+/// The accuracy of one library's implementation of an operation. Errors are
+/// measured per sample: ULPs are the largest component error in units of the
+/// f32 spacing at the largest reference component, relative error is the
+/// largest component error divided by the largest reference component. Both
+/// are measured against the largest component so components that cancel to
+/// near zero don't dominate the error.
+#[derive(Serialize)]
+struct Record {
+    operation: &'static str,
+    library: &'static str,
+    samples: usize,
+    /// samples where the library returned no result or a non-finite result
+    failures: usize,
+    max_ulps: f64,
+    mean_ulps: f64,
+    max_relative: f64,
+    mean_relative: f64,
+}
+
+/// Returns the distance from `x` to the next f32 away from zero.
+fn ulp(x: f64) -> f64 {
+    let x = (x as f32).abs();
+    f64::from(x.next_up()) - f64::from(x)
+}
+
+impl Record {
+    fn new(
+        operation: &Operation,
+        library: &'static str,
+        samples: impl Iterator<Item = (Vec<f64>, Vec<f64>, Vec<f64>)>,
+    ) -> Self {
+        let mut record = Record {
+            operation: operation.name,
+            library,
+            samples: 0,
+            failures: 0,
+            max_ulps: 0.0,
+            mean_ulps: 0.0,
+            max_relative: 0.0,
+            mean_relative: 0.0,
+        };
+        for (a, b, result) in samples {
+            let expected = (operation.reference)(&a, &b);
+            if expected.iter().any(|e| !e.is_finite()) {
+                // the input is singular even in f64
+                continue;
+            }
+            record.samples += 1;
+            if result.iter().all(|r| r.is_finite()) && !result.is_empty() {
+                assert_eq!(
+                    result.len(),
+                    expected.len(),
+                    "{} {} returned the wrong number of components",
+                    library,
+                    operation.name
+                );
+            } else {
+                record.failures += 1;
+                continue;
+            }
+            let mut error = 0.0_f64;
+            let mut magnitude = 0.0_f64;
+            for (&r, &e) in result.iter().zip(&expected) {
+                error = error.max((r - e).abs());
+                magnitude = magnitude.max(e.abs());
+            }
+            let ulps = error / ulp(magnitude);
+            let relative = if magnitude > 0.0 {
+                error / magnitude
+            } else {
+                error
+            };
+            record.max_ulps = record.max_ulps.max(ulps);
+            record.mean_ulps += ulps;
+            record.max_relative = record.max_relative.max(relative);
+            record.mean_relative += relative;
+        }
+        let measured = (record.samples - record.failures).max(1) as f64;
+        record.mean_ulps /= measured;
+        record.mean_relative /= measured;
+        record
+    }
+}
+
+/// Measures every supported operation for a library.
+type Measure = fn(Library);
+
+struct Library<'a> {
+    name: &'static str,
+    records: &'a mut Vec<Record>,
+}
+
+impl Library<'_> {
+    fn unop<T, O>(&mut self, operation: Operation, op: impl Fn(T) -> O)
+    where
+        T: BenchValue + Components,
+        O: Components,
+    {
+        let mut rng = mathbench::rng(operation.name);
+        let samples = (0..SAMPLES).map(|_| {
+            let a = T::random_value(&mut rng);
+            let a_components = a.components();
+            (a_components, Vec::new(), op(a).components())
+        });
+        self.records
+            .push(Record::new(&operation, self.name, samples));
+    }
+
+    fn binop<T1, T2, O>(&mut self, operation: Operation, op: impl Fn(T1, T2) -> O)
+    where
+        T1: BenchValue + Components,
+        T2: BenchValue + Components,
+        O: Components,
+    {
+        let mut rng = mathbench::rng(operation.name);
+        let samples = (0..SAMPLES).map(|_| {
+            let a = T1::random_value(&mut rng);
+            let b = T2::random_value(&mut rng);
+            let a_components = a.components();
+            let b_components = b.components();
+            (a_components, b_components, op(a, b).components())
+        });
+        self.records
+            .push(Record::new(&operation, self.name, samples));
+    }
+}
+
+fn glam(mut lib: Library) {
+    use glam::{Affine3A, Mat2, Mat3, Mat4, Quat, Vec3, Vec3A, Vec4};
+    lib.unop(MATRIX2_DETERMINANT, |m: Mat2| m.determinant());
+    lib.unop(MATRIX2_INVERSE, |m: Mat2| m.inverse());
+    lib.binop(MATRIX2_MUL_MATRIX2, |a: Mat2, b: Mat2| a * b);
+    lib.unop(MATRIX3_DETERMINANT, |m: Mat3| m.determinant());
+    lib.unop(MATRIX3_INVERSE, |m: Mat3| m.inverse());
+    lib.binop(MATRIX3_MUL_MATRIX3, |a: Mat3, b: Mat3| a * b);
+    lib.unop(MATRIX4_DETERMINANT, |m: Mat4| m.determinant());
+    lib.unop(MATRIX4_INVERSE, |m: Mat4| m.inverse());
+    lib.binop(MATRIX4_MUL_MATRIX4, |a: Mat4, b: Mat4| a * b);
+    lib.binop(MATRIX4_MUL_VECTOR4, |m: Mat4, v: Vec4| m * v);
+    lib.unop(VECTOR3_LENGTH, |v: Vec3| v.length());
+    lib.unop(VECTOR3_NORMALIZE, |v: Vec3A| v.normalize());
+    lib.binop(VECTOR3_DOT, |a: Vec3A, b: Vec3A| a.dot(b));
+    lib.binop(VECTOR3_CROSS, |a: Vec3A, b: Vec3A| a.cross(b));
+    lib.binop(ROTATION3_MUL_ROTATION3, |a: Quat, b: Quat| a * b);
+    lib.binop(ROTATION3_MUL_VECTOR3, |q: Quat, v: Vec3| q * v);
+    lib.binop(TRANSFORM_VECTOR3, |m: Affine3A, v: Vec3A| {
+        m.transform_vector3a(v)
+    });
+    lib.binop(TRANSFORM_POINT3, |m: Affine3A, p: Vec3A| {
+        m.transform_point3a(p)
+    });
+}
+
+#[cfg(feature = "cgmath")]
+fn cgmath(mut lib: Library) {
+    use cgmath::{
+        InnerSpace, Matrix2, Matrix3, Matrix4, Point3, Quaternion, SquareMatrix, Transform,
+        Vector3, Vector4,
+    };
+    lib.unop(MATRIX2_DETERMINANT, |m: Matrix2<f32>| m.determinant());
+    lib.unop(MATRIX2_INVERSE, |m: Matrix2<f32>| m.invert());
+    lib.binop(MATRIX2_MUL_MATRIX2, |a: Matrix2<f32>, b: Matrix2<f32>| {
+        a * b
+    });
+    lib.unop(MATRIX3_DETERMINANT, |m: Matrix3<f32>| m.determinant());
+    lib.unop(MATRIX3_INVERSE, |m: Matrix3<f32>| m.invert());
+    lib.binop(MATRIX3_MUL_MATRIX3, |a: Matrix3<f32>, b: Matrix3<f32>| {
+        a * b
+    });
+    lib.unop(MATRIX4_DETERMINANT, |m: Matrix4<f32>| m.determinant());
+    lib.unop(MATRIX4_INVERSE, |m: Matrix4<f32>| m.invert());
+    lib.binop(MATRIX4_MUL_MATRIX4, |a: Matrix4<f32>, b: Matrix4<f32>| {
+        a * b
+    });
+    lib.binop(MATRIX4_MUL_VECTOR4, |m: Matrix4<f32>, v: Vector4<f32>| {
+        m * v
+    });
+    lib.unop(VECTOR3_LENGTH, |v: Vector3<f32>| v.magnitude());
+    lib.unop(VECTOR3_NORMALIZE, |v: Vector3<f32>| v.normalize());
+    lib.binop(VECTOR3_DOT, |a: Vector3<f32>, b: Vector3<f32>| a.dot(b));
+    lib.binop(VECTOR3_CROSS, |a: Vector3<f32>, b: Vector3<f32>| a.cross(b));
+    lib.binop(
+        ROTATION3_MUL_ROTATION3,
+        |a: Quaternion<f32>, b: Quaternion<f32>| a * b,
+    );
+    lib.binop(
+        ROTATION3_MUL_VECTOR3,
+        |q: Quaternion<f32>, v: Vector3<f32>| q * v,
+    );
+    lib.binop(TRANSFORM_VECTOR3, |m: Matrix4<f32>, v: Vector3<f32>| {
+        m.transform_vector(v)
+    });
+    lib.binop(TRANSFORM_POINT3, |m: Matrix4<f32>, p: Point3<f32>| {
+        m.transform_point(p)
+    });
+}
+
+#[cfg(feature = "nalgebra")]
+fn nalgebra(mut lib: Library) {
+    use nalgebra::{
+        Matrix2, Matrix3, Matrix4, Point3, Transform3, UnitQuaternion, Vector3, Vector4,
+    };
+    lib.unop(MATRIX2_DETERMINANT, |m: Matrix2<f32>| m.determinant());
+    lib.unop(MATRIX2_INVERSE, |m: Matrix2<f32>| m.try_inverse());
+    lib.binop(MATRIX2_MUL_MATRIX2, |a: Matrix2<f32>, b: Matrix2<f32>| {
+        a * b
+    });
+    lib.unop(MATRIX3_DETERMINANT, |m: Matrix3<f32>| m.determinant());
+    lib.unop(MATRIX3_INVERSE, |m: Matrix3<f32>| m.try_inverse());
+    lib.binop(MATRIX3_MUL_MATRIX3, |a: Matrix3<f32>, b: Matrix3<f32>| {
+        a * b
+    });
+    lib.unop(MATRIX4_DETERMINANT, |m: Matrix4<f32>| m.determinant());
+    lib.unop(MATRIX4_INVERSE, |m: Matrix4<f32>| m.try_inverse());
+    lib.binop(MATRIX4_MUL_MATRIX4, |a: Matrix4<f32>, b: Matrix4<f32>| {
+        a * b
+    });
+    lib.binop(MATRIX4_MUL_VECTOR4, |m: Matrix4<f32>, v: Vector4<f32>| {
+        m * v
+    });
+    lib.unop(VECTOR3_LENGTH, |v: Vector3<f32>| v.magnitude());
+    lib.unop(VECTOR3_NORMALIZE, |v: Vector3<f32>| v.normalize());
+    lib.binop(VECTOR3_DOT, |a: Vector3<f32>, b: Vector3<f32>| a.dot(&b));
+    lib.binop(VECTOR3_CROSS, |a: Vector3<f32>, b: Vector3<f32>| {
+        a.cross(&b)
+    });
+    lib.binop(
+        ROTATION3_MUL_ROTATION3,
+        |a: UnitQuaternion<f32>, b: UnitQuaternion<f32>| a * b,
+    );
+    lib.binop(
+        ROTATION3_MUL_VECTOR3,
+        |q: UnitQuaternion<f32>, v: Vector3<f32>| q * v,
+    );
+    lib.binop(TRANSFORM_VECTOR3, |m: Transform3<f32>, v: Vector3<f32>| {
+        m.transform_vector(&v)
+    });
+    lib.binop(TRANSFORM_POINT3, |m: Transform3<f32>, p: Point3<f32>| {
+        m.transform_point(&p)
+    });
+}
+
+#[cfg(feature = "ultraviolet")]
+fn ultraviolet(mut lib: Library) {
+    use ultraviolet::{Mat2, Mat3, Mat4, Rotor3, Vec3, Vec4};
+    lib.unop(MATRIX2_DETERMINANT, |m: Mat2| m.determinant());
+    lib.unop(MATRIX2_INVERSE, |m: Mat2| m.inversed());
+    lib.binop(MATRIX2_MUL_MATRIX2, |a: Mat2, b: Mat2| a * b);
+    lib.unop(MATRIX3_DETERMINANT, |m: Mat3| m.determinant());
+    lib.unop(MATRIX3_INVERSE, |m: Mat3| m.inversed());
+    lib.binop(MATRIX3_MUL_MATRIX3, |a: Mat3, b: Mat3| a * b);
+    lib.unop(MATRIX4_DETERMINANT, |m: Mat4| m.determinant());
+    lib.unop(MATRIX4_INVERSE, |m: Mat4| m.inversed());
+    lib.binop(MATRIX4_MUL_MATRIX4, |a: Mat4, b: Mat4| a * b);
+    lib.binop(MATRIX4_MUL_VECTOR4, |m: Mat4, v: Vec4| m * v);
+    lib.unop(VECTOR3_LENGTH, |v: Vec3| v.mag());
+    lib.unop(VECTOR3_NORMALIZE, |v: Vec3| v.normalized());
+    lib.binop(VECTOR3_DOT, |a: Vec3, b: Vec3| a.dot(b));
+    lib.binop(VECTOR3_CROSS, |a: Vec3, b: Vec3| a.cross(b));
+    lib.binop(ROTATION3_MUL_ROTATION3, |a: Rotor3, b: Rotor3| a * b);
+    lib.binop(ROTATION3_MUL_VECTOR3, |r: Rotor3, v: Vec3| r * v);
+    lib.binop(TRANSFORM_VECTOR3, |m: Mat4, v: Vec3| m.transform_vec3(v));
+    lib.binop(TRANSFORM_POINT3, |m: Mat4, p: Vec3| m.transform_point3(p));
+}
+
+#[cfg(feature = "euclid")]
+fn euclid(mut lib: Library) {
+    use euclid::default::{Point3D, Rotation3D, Transform3D, Vector3D};
+    lib.unop(MATRIX4_DETERMINANT, |m: Transform3D<f32>| m.determinant());
+    lib.unop(MATRIX4_INVERSE, |m: Transform3D<f32>| m.inverse());
+    // `a.then(b)` applies `a` first, which is `b * a` for column vectors
+    lib.binop(
+        MATRIX4_MUL_MATRIX4,
+        |a: Transform3D<f32>, b: Transform3D<f32>| b.then(&a),
+    );
+    lib.unop(VECTOR3_LENGTH, |v: Vector3D<f32>| v.length());
+    lib.unop(VECTOR3_NORMALIZE, |v: Vector3D<f32>| v.normalize());
+    lib.binop(VECTOR3_DOT, |a: Vector3D<f32>, b: Vector3D<f32>| a.dot(b));
+    lib.binop(VECTOR3_CROSS, |a: Vector3D<f32>, b: Vector3D<f32>| {
+        a.cross(b)
+    });
+    lib.binop(
+        ROTATION3_MUL_ROTATION3,
+        |a: Rotation3D<f32>, b: Rotation3D<f32>| b.then(&a),
+    );
+    lib.binop(
+        ROTATION3_MUL_VECTOR3,
+        |r: Rotation3D<f32>, p: Point3D<f32>| r.transform_point3d(p),
+    );
+    lib.binop(
+        TRANSFORM_VECTOR3,
+        |m: Transform3D<f32>, v: Vector3D<f32>| m.transform_vector3d(v),
+    );
+    lib.binop(TRANSFORM_POINT3, |m: Transform3D<f32>, p: Point3D<f32>| {
+        m.transform_point3d(p)
+    });
+}
+
+#[cfg(feature = "vek")]
+fn vek(mut lib: Library) {
+    use vek::{Mat2, Mat3, Mat4, Quaternion, Vec3, Vec4};
+    lib.unop(MATRIX2_DETERMINANT, |m: Mat2<f32>| m.determinant());
+    lib.binop(MATRIX2_MUL_MATRIX2, |a: Mat2<f32>, b: Mat2<f32>| a * b);
+    lib.unop(MATRIX3_DETERMINANT, |m: Mat3<f32>| m.determinant());
+    lib.binop(MATRIX3_MUL_MATRIX3, |a: Mat3<f32>, b: Mat3<f32>| a * b);
+    lib.unop(MATRIX4_DETERMINANT, |m: Mat4<f32>| m.determinant());
+    lib.unop(MATRIX4_INVERSE, |m: Mat4<f32>| m.inverted());
+    lib.binop(MATRIX4_MUL_MATRIX4, |a: Mat4<f32>, b: Mat4<f32>| a * b);
+    lib.binop(MATRIX4_MUL_VECTOR4, |m: Mat4<f32>, v: Vec4<f32>| m * v);
+    lib.unop(VECTOR3_LENGTH, |v: Vec3<f32>| v.magnitude());
+    lib.unop(VECTOR3_NORMALIZE, |v: Vec3<f32>| v.normalized());
+    lib.binop(VECTOR3_DOT, |a: Vec3<f32>, b: Vec3<f32>| a.dot(b));
+    lib.binop(VECTOR3_CROSS, |a: Vec3<f32>, b: Vec3<f32>| a.cross(b));
+    lib.binop(
+        ROTATION3_MUL_ROTATION3,
+        |a: Quaternion<f32>, b: Quaternion<f32>| a * b,
+    );
+    lib.binop(ROTATION3_MUL_VECTOR3, |q: Quaternion<f32>, v: Vec3<f32>| {
+        q * v
+    });
+    lib.binop(TRANSFORM_VECTOR3, |m: Mat4<f32>, v: Vec3<f32>| {
+        m.mul_direction(v)
+    });
+    lib.binop(TRANSFORM_POINT3, |m: Mat4<f32>, p: Vec3<f32>| {
+        m.mul_point(p)
+    });
+}
+
+#[cfg(feature = "pathfinder_geometry")]
+fn pathfinder(mut lib: Library) {
+    // pathfinder only has a 2x2 matrix type that isn't an affine transform
+    use pathfinder_geometry::{transform2d::Matrix2x2F, transform3d::Transform4F};
+    lib.unop(MATRIX2_DETERMINANT, |m: Matrix2x2F| m.det());
+    lib.unop(MATRIX2_INVERSE, |m: Matrix2x2F| m.inverse());
+    lib.binop(MATRIX2_MUL_MATRIX2, |a: Matrix2x2F, b: Matrix2x2F| a * b);
+    lib.unop(MATRIX4_INVERSE, |m: Transform4F| m.inverse());
+    lib.binop(MATRIX4_MUL_MATRIX4, |a: Transform4F, b: Transform4F| a * b);
+}
+
+fn main() {
+    let mut records = Vec::new();
+    let libraries: &[(&'static str, Measure)] = &[
+        ("glam", glam),
+        #[cfg(feature = "cgmath")]
+        ("cgmath", cgmath),
+        #[cfg(feature = "nalgebra")]
+        ("nalgebra", nalgebra),
+        #[cfg(feature = "ultraviolet")]
+        ("ultraviolet", ultraviolet),
+        #[cfg(feature = "euclid")]
+        ("euclid", euclid),
+        #[cfg(feature = "vek")]
+        ("vek", vek),
+        #[cfg(feature = "pathfinder_geometry")]
+        ("pathfinder", pathfinder),
+    ];
+    for (name, measure) in libraries {
+        measure(Library {
+            name,
+            records: &mut records,
+        });
+    }
+
+    for record in &records {
+        println!(
+            "{}/{}: max {:.1} ulps, mean {:.2} ulps, max relative error {:.1e}{}",
+            record.operation,
+            record.library,
+            record.max_ulps,
+            record.mean_ulps,
+            record.max_relative,
+            if record.failures > 0 {
+                format!(", {} of {} failed", record.failures, record.samples)
+            } else {
+                String::new()
+            }
+        );
+    }
+
+    let args: Vec<String> = env::args().skip(1).collect();
+    // `cargo test --benches` runs benchmarks once as tests, don't record those
+    if !args.iter().any(|arg| arg == "--bench") {
+        return;
+    }
+    environment::record();
+    environment::record_group("accuracy");
+    let result = serde_json::to_string_pretty(&records)
+        .map_err(|e| e.into())
+        .and_then(|json| environment::write_sidecar("mathbench-accuracy", &json, &args));
+    if let Err(e) = result {
+        eprintln!("Failed to record the accuracy results: {}", e);
+    }
+}
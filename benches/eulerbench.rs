@@ -1,19 +1,59 @@
+#![allow(clippy::single_element_loop)]
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 
 const UPDATE_RATE: f32 = 1.0 / 60.0;
+#[allow(dead_code)]
+const UPDATE_RATE_F64: f64 = 1.0 / 60.0;
+
+/// Integrates a second of updates from rest, so the check compares positions
+/// of the magnitude of the acceleration.
+macro_rules! euler_second {
+    ($acceleration: expr, ty => $t: ty, zero => $zero: expr, dt => $dt: expr) => {{
+        let (mut velocity, mut position): ($t, $t) = ($zero, $zero);
+        for _ in 0..60 {
+            velocity += $acceleration * $dt;
+            position += velocity * $dt;
+        }
+        position
+    }};
+}
 
 macro_rules! bench_euler {
+    // pathfinder has no 3D vector arithmetic, so integrates `Vector4F`s, which
+    // are checked as `Vector3F`s
+    ($b: ident, $size:expr, ty => Vector4F, zero => $zero: expr, dt => $dt: expr) => {{
+        use pathfinder_geometry::vector::Vector3F;
+        let dt = $dt;
+        $crate::check::Check::check(&mut *$b, move |(acceleration,): &(Vector3F,)| {
+            let acceleration =
+                Vector4F::new(acceleration.x(), acceleration.y(), acceleration.z(), 0.0);
+            let position = euler_second!(acceleration, ty => Vector4F, zero => $zero, dt => dt);
+            Vector3F::new(position.x(), position.y(), position.z())
+        });
+        bench_euler!(@bench $b, $size, ty => Vector4F, zero => $zero, dt => $dt)
+    }};
     ($b: ident, $size:expr, ty => $t: ty, zero => $zero: expr, dt => $dt: expr) => {{
+        let dt = $dt;
+        $crate::check::Check::check(&mut *$b, move |(acceleration,): &($t,)| {
+            euler_second!(*acceleration, ty => $t, zero => $zero, dt => dt)
+        });
+        bench_euler!(@bench $b, $size, ty => $t, zero => $zero, dt => $dt)
+    }};
+    (@bench $b: ident, $size:expr, ty => $t: ty, zero => $zero: expr, dt => $dt: expr) => {{
         struct TestData {
             acc: Vec<$t>,
             vel: Vec<$t>,
             pos: Vec<$t>,
         }
 
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut rng = mathbench::rng("euler");
         let mut data = TestData {
             acc: vec![<$t as mathbench::BenchValue>::random_value(&mut rng); *$size],
             vel: vec![$zero; *$size],
@@ -26,15 +66,15 @@ macro_rules! bench_euler {
             for ((position, acceleration), velocity) in
                 data.pos.iter_mut().zip(&data.acc).zip(&mut data.vel)
             {
-                *velocity = *velocity + *acceleration * dt;
-                *position = *position + *velocity * dt;
+                *velocity += *acceleration * dt;
+                *position += *velocity * dt;
             }
         })
     }};
 }
 
 fn bench_euler_3d(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar euler 3d");
+    let mut group = check::benchmark_group(c, "scalar euler 3d");
     for size in [10000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -71,7 +111,7 @@ fn bench_euler_3d(c: &mut Criterion) {
 }
 
 fn bench_euler_3d_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide euler 3d");
+    let mut group = check::benchmark_group(c, "wide euler 3d");
     for size in [80000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -100,12 +140,19 @@ fn bench_euler_3d_wide(c: &mut Criterion) {
             use simba::simd::{f32x8, SimdValue};
             bench_euler!(b, &((*size as f32 / 8.0).ceil() as usize), ty => Vector3<f32x8>, zero => zero(), dt => f32x8::splat(UPDATE_RATE));
         });
+
+        // avx-512
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::{f32x16, SimdValue};
+            bench_euler!(b, &((*size as f32 / 16.0).ceil() as usize), ty => Vector3<f32x16>, zero => zero(), dt => f32x16::splat(UPDATE_RATE));
+        });
     }
     group.finish();
 }
 
 fn bench_euler_2d(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar euler 2d");
+    let mut group = check::benchmark_group(c, "scalar euler 2d");
     for size in [10000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -142,7 +189,7 @@ fn bench_euler_2d(c: &mut Criterion) {
 }
 
 fn bench_euler_2d_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide euler 2d");
+    let mut group = check::benchmark_group(c, "wide euler 2d");
     for size in [80000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -170,6 +217,95 @@ fn bench_euler_2d_wide(c: &mut Criterion) {
             use simba::simd::{f32x8, SimdValue};
             bench_euler!(b, &((*size as f32 / 8.0).ceil() as usize), ty => Vector2<f32x8>, zero => zero(), dt => f32x8::splat(UPDATE_RATE));
         });
+
+        // avx-512
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::{zero, Vector2};
+            use simba::simd::{f32x16, SimdValue};
+            bench_euler!(b, &((*size as f32 / 16.0).ceil() as usize), ty => Vector2<f32x16>, zero => zero(), dt => f32x16::splat(UPDATE_RATE));
+        });
+    }
+    group.finish();
+}
+
+fn bench_euler_3d_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 euler 3d");
+    for size in [80000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::DVec3;
+            bench_euler!(b, size, ty => DVec3, zero => DVec3::ZERO, dt => DVec3::splat(UPDATE_RATE_F64))
+        });
+
+        // sse
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::{f64x2, DVec3x2};
+            bench_euler!(b, &((*size as f32 / 2.0).ceil() as usize), ty => DVec3x2, zero => DVec3x2::zero(), dt => f64x2::splat(UPDATE_RATE_F64))
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::{f64x2, SimdValue};
+            bench_euler!(b, &((*size as f32 / 2.0).ceil() as usize), ty => Vector3<f64x2>, zero => zero(), dt => f64x2::splat(UPDATE_RATE_F64));
+        });
+
+        // avx
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::{f64x4, DVec3x4};
+            bench_euler!(b, &((*size as f32 / 4.0).ceil() as usize), ty => DVec3x4, zero => DVec3x4::zero(), dt => f64x4::splat(UPDATE_RATE_F64))
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::{f64x4, SimdValue};
+            bench_euler!(b, &((*size as f32 / 4.0).ceil() as usize), ty => Vector3<f64x4>, zero => zero(), dt => f64x4::splat(UPDATE_RATE_F64));
+        });
+
+        // avx-512
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::{f64x8, SimdValue};
+            bench_euler!(b, &((*size as f32 / 8.0).ceil() as usize), ty => Vector3<f64x8>, zero => zero(), dt => f64x8::splat(UPDATE_RATE_F64));
+        });
+    }
+    group.finish();
+}
+
+fn bench_euler_2d_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 euler 2d");
+    for size in [80000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::DVec2;
+            bench_euler!(b, size, ty => DVec2, zero => DVec2::ZERO, dt => DVec2::splat(UPDATE_RATE_F64))
+        });
+
+        // sse
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::{f64x2, DVec2x2};
+            bench_euler!(b, &((*size as f32 / 2.0).ceil() as usize), ty => DVec2x2, zero => DVec2x2::zero(), dt => f64x2::splat(UPDATE_RATE_F64))
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::{zero, Vector2};
+            use simba::simd::{f64x2, SimdValue};
+            bench_euler!(b, &((*size as f32 / 2.0).ceil() as usize), ty => Vector2<f64x2>, zero => zero(), dt => f64x2::splat(UPDATE_RATE_F64));
+        });
+
+        // avx
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::{f64x4, DVec2x4};
+            bench_euler!(b, &((*size as f32 / 4.0).ceil() as usize), ty => DVec2x4, zero => DVec2x4::zero(), dt => f64x4::splat(UPDATE_RATE_F64))
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::{zero, Vector2};
+            use simba::simd::{f64x4, SimdValue};
+            bench_euler!(b, &((*size as f32 / 4.0).ceil() as usize), ty => Vector2<f64x4>, zero => zero(), dt => f64x4::splat(UPDATE_RATE_F64));
+        });
+
+        // avx-512
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::{zero, Vector2};
+            use simba::simd::{f64x8, SimdValue};
+            bench_euler!(b, &((*size as f32 / 8.0).ceil() as usize), ty => Vector2<f64x8>, zero => zero(), dt => f64x8::splat(UPDATE_RATE_F64));
+        });
     }
     group.finish();
 }
@@ -179,7 +315,9 @@ criterion_group!(
     bench_euler_2d,
     bench_euler_3d,
     bench_euler_3d_wide,
-    bench_euler_2d_wide
+    bench_euler_2d_wide,
+    bench_euler_3d_wide_f64,
+    bench_euler_2d_wide_f64,
 );
 
-criterion_main!(benches);
+criterion_main!(environment::record, benches);
@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -8,7 +12,7 @@ use std::ops::Mul;
 
 // returns self to check overhead of benchmark
 fn bench_matrix2_ret_self(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix2 return self");
+    let mut group = check::benchmark_group(c, "scalar matrix2 return self");
     bench!("glam", group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => ret_self, ty => Mat2)
@@ -39,7 +43,7 @@ fn bench_matrix2_ret_self(c: &mut Criterion) {
 
 fn bench_matrix2_ret_self_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix2 return self");
+    let mut group = check::benchmark_group(c, "wide matrix2 return self");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat2;
@@ -63,11 +67,50 @@ fn bench_matrix2_ret_self_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Matrix2<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => ret_self, ty => Matrix2<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_ret_self_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix2 return self");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat2;
+        bench_unop_wide!(b, size, width => 1, op => ret_self, ty => DMat2)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat2x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => DMat2x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => Matrix2<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat2x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => DMat2x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => Matrix2<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Matrix2<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_matrix2_transpose(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix2 transpose");
+    let mut group = check::benchmark_group(c, "scalar matrix2 transpose");
     bench!("glam", group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => transpose, ty => Mat2)
@@ -92,8 +135,34 @@ fn bench_matrix2_transpose(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_transpose_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix2 transpose");
+    bench!("glam", group, |b| {
+        use glam::DMat2;
+        bench_unop!(b, op => transpose, ty => DMat2)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => transpose, ty => Matrix2<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat2;
+        bench_unop!(b, op => transposed, ty => DMat2)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => transpose, ty => Matrix2<f64>)
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat2;
+        bench_unop!(b, op => transposed, ty => Mat2<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix2_transpose_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix2 transpose");
+    let mut group = check::benchmark_group(c, "wide matrix2 transpose");
     let size = &MIN_WIDE_BENCH_SIZE;
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
@@ -118,11 +187,50 @@ fn bench_matrix2_transpose_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => transpose, ty => Matrix2<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => transpose, ty => Matrix2<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_matrix2_transpose_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix2 transpose");
+    let size = &MIN_WIDE_BENCH_SIZE;
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat2;
+        bench_unop_wide!(b, size, width => 1, op => transpose, ty => DMat2)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat2x2;
+        bench_unop_wide!(b, size, width => 2, op => transposed, ty => DMat2x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => transpose, ty => Matrix2<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat2x4;
+        bench_unop_wide!(b, size, width => 4, op => transposed, ty => DMat2x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => transpose, ty => Matrix2<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Matrix2;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => transpose, ty => Matrix2<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_matrix2_determinant(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix2 determinant");
+    let mut group = check::benchmark_group(c, "scalar matrix2 determinant");
     bench!("glam", group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => determinant, ty => Mat2)
@@ -151,9 +259,35 @@ fn bench_matrix2_determinant(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_determinant_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix2 determinant");
+    bench!("glam", group, |b| {
+        use glam::DMat2;
+        bench_unop!(b, op => determinant, ty => DMat2)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => determinant, ty => Matrix2<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat2;
+        bench_unop!(b, op => determinant, ty => DMat2)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => determinant, ty => Matrix2<f64>)
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat2;
+        bench_unop!(b, op => determinant, ty => Mat2<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix2_determinant_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix2 determinant");
+    let mut group = check::benchmark_group(c, "wide matrix2 determinant");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat2;
@@ -170,8 +304,27 @@ fn bench_matrix2_determinant_wide(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_determinant_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix2 determinant");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat2;
+        bench_unop_wide!(b, size, width => 1, op => determinant, ty => DMat2)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat2x2;
+        bench_unop_wide!(b, size, width => 2, op => determinant, ty => DMat2x2)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat2x4;
+        bench_unop_wide!(b, size, width => 4, op => determinant, ty => DMat2x4)
+    });
+    group.finish();
+}
+
 fn bench_matrix2_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix2 inverse");
+    let mut group = check::benchmark_group(c, "scalar matrix2 inverse");
     bench!("glam", group, |b| {
         use glam::Mat2;
         bench_unop!(b, op => inverse, ty => Mat2)
@@ -196,9 +349,30 @@ fn bench_matrix2_inverse(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_inverse_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix2 inverse");
+    bench!("glam", group, |b| {
+        use glam::DMat2;
+        bench_unop!(b, op => inverse, ty => DMat2)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix2};
+        bench_unop!(b, op => invert, ty => Matrix2<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat2;
+        bench_unop!(b, op => inversed, ty => DMat2)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix2;
+        bench_unop!(b, op => try_inverse, ty => Matrix2<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix2_inverse_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix2 inverse");
+    let mut group = check::benchmark_group(c, "wide matrix2 inverse");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat2;
@@ -215,8 +389,27 @@ fn bench_matrix2_inverse_wide(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_inverse_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix2 inverse");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat2;
+        bench_unop_wide!(b, size, width => 1, op => inverse, ty => DMat2)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat2x2;
+        bench_unop_wide!(b, size, width => 2, op => inversed, ty => DMat2x2)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat2x4;
+        bench_unop_wide!(b, size, width => 4, op => inversed, ty => DMat2x4)
+    });
+    group.finish();
+}
+
 fn bench_matrix2_mul_matrix2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix2 mul matrix2");
+    let mut group = check::benchmark_group(c, "scalar matrix2 mul matrix2");
     bench!("glam", group, |b| {
         use glam::Mat2;
         bench_binop!(b, op => mul, ty1 => Mat2, ty2 => Mat2)
@@ -245,8 +438,34 @@ fn bench_matrix2_mul_matrix2(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_mul_matrix2_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix2 mul matrix2");
+    bench!("glam", group, |b| {
+        use glam::DMat2;
+        bench_binop!(b, op => mul, ty1 => DMat2, ty2 => DMat2)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::Matrix2;
+        bench_binop!(b, op => mul, ty1 => Matrix2<f64>, ty2 => Matrix2<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat2;
+        bench_binop!(b, op => mul, ty1 => DMat2, ty2 => DMat2)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix2;
+        bench_binop!(b, op => mul, ty1 => Matrix2<f64>, ty2 => Matrix2<f64>)
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat2;
+        bench_binop!(b, op => mul, ty1 => Mat2<f64>, ty2 => Mat2<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix2_mul_matrix2_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix2 mul matrix2");
+    let mut group = check::benchmark_group(c, "wide matrix2 mul matrix2");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -271,12 +490,52 @@ fn bench_matrix2_mul_matrix2_wide(c: &mut Criterion) {
             use simba::simd::f32x8;
             bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix2<f32x8>, ty2 => Matrix2<f32x8>)
         });
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::Matrix2;
+            use simba::simd::f32x16;
+            bench_binop_wide!(b, size, width => 16, op => mul, ty1 => Matrix2<f32x16>, ty2 => Matrix2<f32x16>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix2_mul_matrix2_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix2 mul matrix2");
+    for size in [16, 256].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::DMat2;
+            bench_binop_wide!(b, size, width => 1, op => mul, ty1 => DMat2, ty2 => DMat2)
+        });
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::DMat2x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => DMat2x2, ty2 => DMat2x2)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::Matrix2;
+            use simba::simd::f64x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => Matrix2<f64x2>, ty2 => Matrix2<f64x2>)
+        });
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::DMat2x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => DMat2x4, ty2 => DMat2x4)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::Matrix2;
+            use simba::simd::f64x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => Matrix2<f64x4>, ty2 => Matrix2<f64x4>)
+        });
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::Matrix2;
+            use simba::simd::f64x8;
+            bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix2<f64x8>, ty2 => Matrix2<f64x8>)
+        });
     }
     group.finish();
 }
 
 fn bench_matrix2_mul_vector2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix2 mul vector2");
+    let mut group = check::benchmark_group(c, "scalar matrix2 mul vector2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -308,8 +567,37 @@ fn bench_matrix2_mul_vector2(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix2_mul_vector2_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix2 mul vector2");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DMat2, DVec2};
+            bench_binop!(b, size, op => mul, ty1 => DMat2, ty2 => DVec2)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Matrix2, Vector2};
+            bench_binop!(b, size, op => mul, ty1 => Matrix2<f64>, ty2 => Vector2<f64>)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat2, DVec2};
+            bench_binop!(b, size, op => mul, ty1 => DMat2, ty2 => DVec2)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Matrix2, Vector2};
+            bench_binop!(b, size, op => mul, ty1 => Matrix2<f64>, ty2 => Vector2<f64>)
+        });
+
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat2, Vec2};
+            bench_binop!(b, size, op => mul, ty1 => Mat2<f64>, ty2 => Vec2<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_matrix2_mul_vector2_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix2 mul vector2");
+    let mut group = check::benchmark_group(c, "wide matrix2 mul vector2");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -334,6 +622,46 @@ fn bench_matrix2_mul_vector2_wide(c: &mut Criterion) {
             use simba::simd::f32x8;
             bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix2<f32x8>, ty2 => Vector2<f32x8>)
         });
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::{Matrix2, Vector2};
+            use simba::simd::f32x16;
+            bench_binop_wide!(b, size, width => 16, op => mul, ty1 => Matrix2<f32x16>, ty2 => Vector2<f32x16>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix2_mul_vector2_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix2 mul vector2");
+    for size in [16, 256].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::{DMat2, DVec2};
+            bench_binop_wide!(b, size, width => 1, op => mul, ty1 => DMat2, ty2 => DVec2)
+        });
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::{DMat2x2, DVec2x2};
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => DMat2x2, ty2 => DVec2x2)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::{Matrix2, Vector2};
+            use simba::simd::f64x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => Matrix2<f64x2>, ty2 => Vector2<f64x2>)
+        });
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::{DMat2x4, DVec2x4};
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => DMat2x4, ty2 => DVec2x4)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::{Matrix2, Vector2};
+            use simba::simd::f64x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => Matrix2<f64x4>, ty2 => Vector2<f64x4>)
+        });
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::{Matrix2, Vector2};
+            use simba::simd::f64x8;
+            bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix2<f64x8>, ty2 => Vector2<f64x8>)
+        });
     }
     group.finish();
 }
@@ -342,15 +670,26 @@ criterion_group!(
     matrix2_benches,
     bench_matrix2_ret_self,
     bench_matrix2_ret_self_wide,
+    bench_matrix2_ret_self_wide_f64,
     bench_matrix2_transpose,
+    bench_matrix2_transpose_f64,
     bench_matrix2_transpose_wide,
+    bench_matrix2_transpose_wide_f64,
     bench_matrix2_determinant,
+    bench_matrix2_determinant_f64,
     bench_matrix2_determinant_wide,
+    bench_matrix2_determinant_wide_f64,
     bench_matrix2_inverse,
+    bench_matrix2_inverse_f64,
     bench_matrix2_inverse_wide,
+    bench_matrix2_inverse_wide_f64,
     bench_matrix2_mul_matrix2,
+    bench_matrix2_mul_matrix2_f64,
     bench_matrix2_mul_matrix2_wide,
+    bench_matrix2_mul_matrix2_wide_f64,
     bench_matrix2_mul_vector2,
+    bench_matrix2_mul_vector2_f64,
     bench_matrix2_mul_vector2_wide,
+    bench_matrix2_mul_vector2_wide_f64,
 );
-criterion_main!(matrix2_benches);
+criterion_main!(environment::record, matrix2_benches);
@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -7,7 +11,7 @@ use mathbench::BenchValue;
 use std::ops::Mul;
 
 fn bench_matrix3_ret_self(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix3 return self");
+    let mut group = check::benchmark_group(c, "scalar matrix3 return self");
     bench!("glam", group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => ret_self, ty => Mat3)
@@ -34,7 +38,7 @@ fn bench_matrix3_ret_self(c: &mut Criterion) {
 
 fn bench_matrix3_ret_self_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix3 return self");
+    let mut group = check::benchmark_group(c, "wide matrix3 return self");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat3;
@@ -58,11 +62,50 @@ fn bench_matrix3_ret_self_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Matrix3<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => ret_self, ty => Matrix3<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_ret_self_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix3 return self");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat3;
+        bench_unop_wide!(b, size, width => 1, op => ret_self, ty => DMat3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat3x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => DMat3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => Matrix3<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat3x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => DMat3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => Matrix3<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Matrix3<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_matrix3_transpose(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix3 transpose");
+    let mut group = check::benchmark_group(c, "scalar matrix3 transpose");
     bench!("glam", group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => transpose, ty => Mat3)
@@ -87,9 +130,35 @@ fn bench_matrix3_transpose(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_transpose_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix3 transpose");
+    bench!("glam", group, |b| {
+        use glam::DMat3;
+        bench_unop!(b, op => transpose, ty => DMat3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => transpose, ty => Matrix3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat3;
+        bench_unop!(b, op => transposed, ty => DMat3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => transpose, ty => Matrix3<f64>)
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat3;
+        bench_unop!(b, op => transposed, ty => Mat3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix3_transpose_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix3 transpose");
+    let mut group = check::benchmark_group(c, "wide matrix3 transpose");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat3;
@@ -113,11 +182,50 @@ fn bench_matrix3_transpose_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => transpose, ty => Matrix3<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => transpose, ty => Matrix3<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_matrix3_transpose_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix3 transpose");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat3;
+        bench_unop_wide!(b, size, width => 1, op => transpose, ty => DMat3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat3x2;
+        bench_unop_wide!(b, size, width => 2, op => transposed, ty => DMat3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => transpose, ty => Matrix3<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat3x4;
+        bench_unop_wide!(b, size, width => 4, op => transposed, ty => DMat3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => transpose, ty => Matrix3<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Matrix3;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => transpose, ty => Matrix3<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_matrix3_determinant(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix3 determinant");
+    let mut group = check::benchmark_group(c, "scalar matrix3 determinant");
     bench!("glam", group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => determinant, ty => Mat3)
@@ -142,9 +250,35 @@ fn bench_matrix3_determinant(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_determinant_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix3 determinant");
+    bench!("glam", group, |b| {
+        use glam::DMat3;
+        bench_unop!(b, op => determinant, ty => DMat3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => determinant, ty => Matrix3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat3;
+        bench_unop!(b, op => determinant, ty => DMat3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => determinant, ty => Matrix3<f64>)
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat3;
+        bench_unop!(b, op => determinant, ty => Mat3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix3_determinant_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix3 determinant");
+    let mut group = check::benchmark_group(c, "wide matrix3 determinant");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat3;
@@ -161,8 +295,27 @@ fn bench_matrix3_determinant_wide(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_determinant_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix3 determinant");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat3;
+        bench_unop_wide!(b, size, width => 1, op => determinant, ty => DMat3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat3x2;
+        bench_unop_wide!(b, size, width => 2, op => determinant, ty => DMat3x2)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat3x4;
+        bench_unop_wide!(b, size, width => 4, op => determinant, ty => DMat3x4)
+    });
+    group.finish();
+}
+
 fn bench_matrix3_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix3 inverse");
+    let mut group = check::benchmark_group(c, "scalar matrix3 inverse");
     bench!("glam", group, |b| {
         use glam::Mat3;
         bench_unop!(b, op => inverse, ty => Mat3)
@@ -183,9 +336,30 @@ fn bench_matrix3_inverse(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_inverse_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix3 inverse");
+    bench!("glam", group, |b| {
+        use glam::DMat3;
+        bench_unop!(b, op => inverse, ty => DMat3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix3};
+        bench_unop!(b, op => invert, ty => Matrix3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat3;
+        bench_unop!(b, op => inversed, ty => DMat3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix3;
+        bench_unop!(b, op => try_inverse, ty => Matrix3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix3_inverse_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix3 inverse");
+    let mut group = check::benchmark_group(c, "wide matrix3 inverse");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat3;
@@ -202,8 +376,27 @@ fn bench_matrix3_inverse_wide(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_inverse_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix3 inverse");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat3;
+        bench_unop_wide!(b, size, width => 1, op => inverse, ty => DMat3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat3x2;
+        bench_unop_wide!(b, size, width => 2, op => inversed, ty => DMat3x2)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat3x4;
+        bench_unop_wide!(b, size, width => 4, op => inversed, ty => DMat3x4)
+    });
+    group.finish();
+}
+
 fn bench_matrix3_mul_matrix3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix3 mul matrix3");
+    let mut group = check::benchmark_group(c, "scalar matrix3 mul matrix3");
     bench!("glam", group, |b| {
         use glam::Mat3;
         bench_binop!(b, op => mul, ty1 => Mat3, ty2 => Mat3)
@@ -228,8 +421,34 @@ fn bench_matrix3_mul_matrix3(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_mul_matrix3_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix3 mul matrix3");
+    bench!("glam", group, |b| {
+        use glam::DMat3;
+        bench_binop!(b, op => mul, ty1 => DMat3, ty2 => DMat3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::Matrix3;
+        bench_binop!(b, op => mul, ty1 => Matrix3<f64>, ty2 => Matrix3<f64>, param => by_ref)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat3;
+        bench_binop!(b, op => mul, ty1 => DMat3, ty2 => DMat3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix3;
+        bench_binop!(b, op => mul, ty1 => Matrix3<f64>, ty2 => Matrix3<f64>, param => by_ref)
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat3;
+        bench_binop!(b, op => mul, ty1 => Mat3<f64>, ty2 => Mat3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix3_mul_matrix3_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix3 mul matrix3");
+    let mut group = check::benchmark_group(c, "wide matrix3 mul matrix3");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -254,12 +473,52 @@ fn bench_matrix3_mul_matrix3_wide(c: &mut Criterion) {
             use simba::simd::f32x8;
             bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix3<f32x8>, ty2 => Matrix3<f32x8>)
         });
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::Matrix3;
+            use simba::simd::f32x16;
+            bench_binop_wide!(b, size, width => 16, op => mul, ty1 => Matrix3<f32x16>, ty2 => Matrix3<f32x16>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix3_mul_matrix3_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix3 mul matrix3");
+    for size in [16, 256].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::DMat3;
+            bench_binop_wide!(b, size, width => 1, op => mul, ty1 => DMat3, ty2 => DMat3)
+        });
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::DMat3x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => DMat3x2, ty2 => DMat3x2)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::Matrix3;
+            use simba::simd::f64x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => Matrix3<f64x2>, ty2 => Matrix3<f64x2>)
+        });
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::DMat3x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => DMat3x4, ty2 => DMat3x4)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::Matrix3;
+            use simba::simd::f64x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => Matrix3<f64x4>, ty2 => Matrix3<f64x4>)
+        });
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::Matrix3;
+            use simba::simd::f64x8;
+            bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix3<f64x8>, ty2 => Matrix3<f64x8>)
+        });
     }
     group.finish();
 }
 
 fn bench_matrix3_mul_vector3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix3 mul vector3");
+    let mut group = check::benchmark_group(c, "scalar matrix3 mul vector3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -287,8 +546,37 @@ fn bench_matrix3_mul_vector3(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix3_mul_vector3_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix3 mul vector3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DMat3, DVec3};
+            bench_binop!(b, size, op => mul, ty1 => DMat3, ty2 => DVec3)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Matrix3, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => Matrix3<f64>, ty2 => Vector3<f64>)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat3, DVec3};
+            bench_binop!(b, size, op => mul, ty1 => DMat3, ty2 => DVec3)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Matrix3, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => Matrix3<f64>, ty2 => Vector3<f64>)
+        });
+
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat3, Vec3};
+            bench_binop!(b, size, op => mul, ty1 => Mat3<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_matrix3_mul_vector3_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix3 mul vector3");
+    let mut group = check::benchmark_group(c, "wide matrix3 mul vector3");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -313,6 +601,46 @@ fn bench_matrix3_mul_vector3_wide(c: &mut Criterion) {
             use simba::simd::f32x8;
             bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix3<f32x8>, ty2 => Vector3<f32x8>)
         });
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::{Matrix3, Vector3};
+            use simba::simd::f32x16;
+            bench_binop_wide!(b, size, width => 16, op => mul, ty1 => Matrix3<f32x16>, ty2 => Vector3<f32x16>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix3_mul_vector3_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix3 mul vector3");
+    for size in [16, 256].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::{DMat3, DVec3};
+            bench_binop_wide!(b, size, width => 1, op => mul, ty1 => DMat3, ty2 => DVec3)
+        });
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::{DMat3x2, DVec3x2};
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => DMat3x2, ty2 => DVec3x2)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::{Matrix3, Vector3};
+            use simba::simd::f64x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => Matrix3<f64x2>, ty2 => Vector3<f64x2>)
+        });
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::{DMat3x4, DVec3x4};
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => DMat3x4, ty2 => DVec3x4)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::{Matrix3, Vector3};
+            use simba::simd::f64x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => Matrix3<f64x4>, ty2 => Vector3<f64x4>)
+        });
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::{Matrix3, Vector3};
+            use simba::simd::f64x8;
+            bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix3<f64x8>, ty2 => Vector3<f64x8>)
+        });
     }
     group.finish();
 }
@@ -321,15 +649,26 @@ criterion_group!(
     matrix3_benches,
     bench_matrix3_ret_self,
     bench_matrix3_ret_self_wide,
+    bench_matrix3_ret_self_wide_f64,
     bench_matrix3_transpose,
+    bench_matrix3_transpose_f64,
     bench_matrix3_transpose_wide,
+    bench_matrix3_transpose_wide_f64,
     bench_matrix3_determinant,
+    bench_matrix3_determinant_f64,
     bench_matrix3_determinant_wide,
+    bench_matrix3_determinant_wide_f64,
     bench_matrix3_inverse,
+    bench_matrix3_inverse_f64,
     bench_matrix3_inverse_wide,
+    bench_matrix3_inverse_wide_f64,
     bench_matrix3_mul_matrix3,
+    bench_matrix3_mul_matrix3_f64,
     bench_matrix3_mul_matrix3_wide,
+    bench_matrix3_mul_matrix3_wide_f64,
     bench_matrix3_mul_vector3,
+    bench_matrix3_mul_vector3_f64,
     bench_matrix3_mul_vector3_wide,
+    bench_matrix3_mul_vector3_wide_f64,
 );
-criterion_main!(matrix3_benches);
+criterion_main!(environment::record, matrix3_benches);
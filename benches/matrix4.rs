@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -8,7 +12,7 @@ use std::ops::Mul;
 
 // returns self to check overhead of benchmark
 fn bench_matrix4_ret_self(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix4 return self");
+    let mut group = check::benchmark_group(c, "scalar matrix4 return self");
     bench!("glam", group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => ret_self, ty => Mat4)
@@ -35,8 +39,8 @@ fn bench_matrix4_ret_self(c: &mut Criterion) {
 
 fn bench_matrix4_ret_self_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix4 return self");
-    group.throughput(criterion::Throughput::Elements(*size as u64));
+    let mut group = check::benchmark_group(c, "wide matrix4 return self");
+    group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat4;
         bench_unop_wide!(b, size, width => 1, op => ret_self, ty => Mat4)
@@ -59,11 +63,50 @@ fn bench_matrix4_ret_self_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Matrix4<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => ret_self, ty => Matrix4<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_ret_self_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix4 return self");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat4;
+        bench_unop_wide!(b, size, width => 1, op => ret_self, ty => DMat4)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat4x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => DMat4x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => Matrix4<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat4x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => DMat4x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => Matrix4<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Matrix4<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_matrix4_transpose(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix4 transpose");
+    let mut group = check::benchmark_group(c, "scalar matrix4 transpose");
     bench!("glam", group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => transpose, ty => Mat4);
@@ -88,9 +131,35 @@ fn bench_matrix4_transpose(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_transpose_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix4 transpose");
+    bench!("glam", group, |b| {
+        use glam::DMat4;
+        bench_unop!(b, op => transpose, ty => DMat4);
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{prelude::*, Matrix4};
+        bench_unop!(b, op => transpose, ty => Matrix4<f64>);
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat4;
+        bench_unop!(b, op => transposed, ty => DMat4)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => transpose, ty => Matrix4<f64>);
+    });
+
+    bench!("vek", group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => transposed, ty => Mat4<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix4_transpose_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix4 transpose");
+    let mut group = check::benchmark_group(c, "wide matrix4 transpose");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat4;
@@ -114,11 +183,50 @@ fn bench_matrix4_transpose_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => transpose, ty => Matrix4<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => transpose, ty => Matrix4<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_matrix4_transpose_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix4 transpose");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat4;
+        bench_unop_wide!(b, size, width => 1, op => transpose, ty => DMat4)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat4x2;
+        bench_unop_wide!(b, size, width => 2, op => transposed, ty => DMat4x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => transpose, ty => Matrix4<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat4x4;
+        bench_unop_wide!(b, size, width => 4, op => transposed, ty => DMat4x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => transpose, ty => Matrix4<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Matrix4;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => transpose, ty => Matrix4<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_matrix4_determinant(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix4 determinant");
+    let mut group = check::benchmark_group(c, "scalar matrix4 determinant");
     bench!("glam", group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => determinant, ty => Mat4)
@@ -147,9 +255,39 @@ fn bench_matrix4_determinant(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_determinant_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix4 determinant");
+    bench!("glam", group, |b| {
+        use glam::DMat4;
+        bench_unop!(b, op => determinant, ty => DMat4)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{Matrix4, SquareMatrix};
+        bench_unop!(b, op => determinant, ty => Matrix4<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat4;
+        bench_unop!(b, op => determinant, ty => DMat4)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => determinant, ty => Matrix4<f64>)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_unop!(b, op => determinant, ty => Transform3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => determinant, ty => Mat4<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix4_determinant_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide matrix4 determinant");
+    let mut group = check::benchmark_group(c, "wide matrix4 determinant");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Mat4;
@@ -166,8 +304,27 @@ fn bench_matrix4_determinant_wide(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_determinant_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 matrix4 determinant");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat4;
+        bench_unop_wide!(b, size, width => 1, op => determinant, ty => DMat4)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat4x2;
+        bench_unop_wide!(b, size, width => 2, op => determinant, ty => DMat4x2)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat4x4;
+        bench_unop_wide!(b, size, width => 4, op => determinant, ty => DMat4x4)
+    });
+    group.finish();
+}
+
 fn bench_matrix4_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix4 inverse");
+    let mut group = check::benchmark_group(c, "scalar matrix4 inverse");
     bench!("glam", group, |b| {
         use glam::Mat4;
         bench_unop!(b, op => inverse, ty => Mat4)
@@ -196,8 +353,38 @@ fn bench_matrix4_inverse(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_inverse_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix4 inverse");
+    bench!("glam", group, |b| {
+        use glam::DMat4;
+        bench_unop!(b, op => inverse, ty => DMat4)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{Matrix4, SquareMatrix};
+        bench_unop!(b, op => invert, ty => Matrix4<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat4;
+        bench_unop!(b, op => inversed, ty => DMat4)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix4;
+        bench_unop!(b, op => try_inverse, ty => Matrix4<f64>)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Transform3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Mat4;
+        bench_unop!(b, op => inverted, ty => Mat4<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix4_inverse_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix4 inverse");
+    let mut group = check::benchmark_group(c, "wide matrix4 inverse");
     let size = &MIN_WIDE_BENCH_SIZE;
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
@@ -215,8 +402,27 @@ fn bench_matrix4_inverse_wide(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_inverse_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix4 inverse");
+    let size = &MIN_WIDE_BENCH_SIZE;
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DMat4;
+        bench_unop_wide!(b, size, width => 1, op => inverse, ty => DMat4)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DMat4x2;
+        bench_unop_wide!(b, size, width => 2, op => inversed, ty => DMat4x2)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DMat4x4;
+        bench_unop_wide!(b, size, width => 4, op => inversed, ty => DMat4x4)
+    });
+    group.finish();
+}
+
 fn bench_matrix4_mul_matrix4(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix4 mul matrix4");
+    let mut group = check::benchmark_group(c, "scalar matrix4 mul matrix4");
     bench!("glam", group, |b| {
         use glam::Mat4;
         bench_binop!(b, op => mul, ty1 => Mat4, ty2 => Mat4)
@@ -236,7 +442,8 @@ fn bench_matrix4_mul_matrix4(c: &mut Criterion) {
 
     bench!("euclid", group, |b| {
         use euclid::{Transform3D, UnknownUnit};
-        bench_binop!(b, op => then, ty => Transform3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Transform3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
     });
     bench!("vek", group, |b| {
         use vek::Mat4;
@@ -245,8 +452,39 @@ fn bench_matrix4_mul_matrix4(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_mul_matrix4_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix4 mul matrix4");
+    bench!("glam", group, |b| {
+        use glam::DMat4;
+        bench_binop!(b, op => mul, ty1 => DMat4, ty2 => DMat4)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f64>, ty2 => Matrix4<f64>, param => by_ref)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DMat4;
+        bench_binop!(b, op => mul, ty1 => DMat4, ty2 => DMat4)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Matrix4;
+        bench_binop!(b, op => mul, ty1 => Matrix4<f64>, ty2 => Matrix4<f64>, param => by_ref)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Transform3D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    bench!("vek", group, |b| {
+        use vek::Mat4;
+        bench_binop!(b, op => mul, ty1 => Mat4<f64>, ty2 => Mat4<f64>)
+    });
+    group.finish();
+}
+
 fn bench_matrix4_mul_matrix4_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix4 mul matrix4");
+    let mut group = check::benchmark_group(c, "wide matrix4 mul matrix4");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -271,12 +509,52 @@ fn bench_matrix4_mul_matrix4_wide(c: &mut Criterion) {
             use simba::simd::f32x8;
             bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix4<f32x8>, ty2 => Matrix4<f32x8>)
         });
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::Matrix4;
+            use simba::simd::f32x16;
+            bench_binop_wide!(b, size, width => 16, op => mul, ty1 => Matrix4<f32x16>, ty2 => Matrix4<f32x16>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix4_mul_matrix4_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix4 mul matrix4");
+    for size in [16, 256].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::DMat4;
+            bench_binop_wide!(b, size, width => 1, op => mul, ty1 => DMat4, ty2 => DMat4)
+        });
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::DMat4x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => DMat4x2, ty2 => DMat4x2)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::Matrix4;
+            use simba::simd::f64x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => Matrix4<f64x2>, ty2 => Matrix4<f64x2>)
+        });
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::DMat4x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => DMat4x4, ty2 => DMat4x4)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::Matrix4;
+            use simba::simd::f64x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => Matrix4<f64x4>, ty2 => Matrix4<f64x4>)
+        });
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::Matrix4;
+            use simba::simd::f64x8;
+            bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix4<f64x8>, ty2 => Matrix4<f64x8>)
+        });
     }
     group.finish();
 }
 
 fn bench_matrix4_mul_vector4(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar matrix4 mul vector4");
+    let mut group = check::benchmark_group(c, "scalar matrix4 mul vector4");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -304,8 +582,37 @@ fn bench_matrix4_mul_vector4(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_matrix4_mul_vector4_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 matrix4 mul vector4");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DMat4, DVec4};
+            bench_binop!(b, size, op => mul, ty1 => DMat4, ty2 => DVec4)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Matrix4, Vector4};
+            bench_binop!(b, size, op => mul, ty1 => Matrix4<f64>, ty2 => Vector4<f64>)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat4, DVec4};
+            bench_binop!(b, size, op => mul, ty1 => DMat4, ty2 => DVec4)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Matrix4, Vector4};
+            bench_binop!(b, size, op => mul, ty1 => Matrix4<f64>, ty2 => Vector4<f64>)
+        });
+
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat4, Vec4};
+            bench_binop!(b, size, op => mul, ty1 => Mat4<f64>, ty2 => Vec4<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_matrix4_mul_vector4_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide matrix4 mul vector4");
+    let mut group = check::benchmark_group(c, "wide matrix4 mul vector4");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -330,6 +637,46 @@ fn bench_matrix4_mul_vector4_wide(c: &mut Criterion) {
             use simba::simd::f32x8;
             bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix4<f32x8>, ty2 => Vector4<f32x8>)
         });
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::{Matrix4, Vector4};
+            use simba::simd::f32x16;
+            bench_binop_wide!(b, size, width => 16, op => mul, ty1 => Matrix4<f32x16>, ty2 => Vector4<f32x16>)
+        });
+    }
+    group.finish();
+}
+
+fn bench_matrix4_mul_vector4_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 matrix4 mul vector4");
+    for size in [16, 256].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::{DMat4, DVec4};
+            bench_binop_wide!(b, size, width => 1, op => mul, ty1 => DMat4, ty2 => DVec4)
+        });
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::{DMat4x2, DVec4x2};
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => DMat4x2, ty2 => DVec4x2)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::{Matrix4, Vector4};
+            use simba::simd::f64x2;
+            bench_binop_wide!(b, size, width => 2, op => mul, ty1 => Matrix4<f64x2>, ty2 => Vector4<f64x2>)
+        });
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::{DMat4x4, DVec4x4};
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => DMat4x4, ty2 => DVec4x4)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::{Matrix4, Vector4};
+            use simba::simd::f64x4;
+            bench_binop_wide!(b, size, width => 4, op => mul, ty1 => Matrix4<f64x4>, ty2 => Vector4<f64x4>)
+        });
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::{Matrix4, Vector4};
+            use simba::simd::f64x8;
+            bench_binop_wide!(b, size, width => 8, op => mul, ty1 => Matrix4<f64x8>, ty2 => Vector4<f64x8>)
+        });
     }
     group.finish();
 }
@@ -338,15 +685,26 @@ criterion_group!(
     matrix4_benches,
     bench_matrix4_ret_self,
     bench_matrix4_ret_self_wide,
+    bench_matrix4_ret_self_wide_f64,
     bench_matrix4_transpose,
+    bench_matrix4_transpose_f64,
     bench_matrix4_transpose_wide,
+    bench_matrix4_transpose_wide_f64,
     bench_matrix4_determinant,
+    bench_matrix4_determinant_f64,
     bench_matrix4_determinant_wide,
+    bench_matrix4_determinant_wide_f64,
     bench_matrix4_inverse,
+    bench_matrix4_inverse_f64,
     bench_matrix4_inverse_wide,
+    bench_matrix4_inverse_wide_f64,
     bench_matrix4_mul_matrix4,
+    bench_matrix4_mul_matrix4_f64,
     bench_matrix4_mul_matrix4_wide,
+    bench_matrix4_mul_matrix4_wide_f64,
     bench_matrix4_mul_vector4,
+    bench_matrix4_mul_vector4_f64,
     bench_matrix4_mul_vector4_wide,
+    bench_matrix4_mul_vector4_wide_f64,
 );
-criterion_main!(matrix4_benches);
+criterion_main!(environment::record, matrix4_benches);
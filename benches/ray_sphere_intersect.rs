@@ -1,11 +1,21 @@
 #![cfg_attr(feature = "unstable", feature(stmt_expr_attributes))]
+#![allow(clippy::single_element_loop)]
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
 
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 
-#[cfg(any(feature = "ultraviolet_f32x4", feature = "ultraviolet_f32x8",))]
+#[cfg(any(
+    feature = "ultraviolet_f32x4",
+    feature = "ultraviolet_f32x8",
+    feature = "ultraviolet_f64x2",
+    feature = "ultraviolet_f64x4",
+))]
 macro_rules! bench_intersection_wide_uv {
     ($b: ident, $size:expr, ty => $t: ty, wt => $wt: ident, zero_vec => $zero: expr, max => $max: expr) => {{
         use wide_mathbench::CmpGt;
@@ -14,7 +24,7 @@ macro_rules! bench_intersection_wide_uv {
             result: Vec<$wt>,
         }
 
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut rng = mathbench::rng("ray sphere intersect");
         let ray_d = (0..*$size)
             .into_iter()
             .map(|_| <$t as mathbench::BenchValue>::random_value(&mut rng).normalized())
@@ -25,7 +35,7 @@ macro_rules! bench_intersection_wide_uv {
         };
 
         let sphere_o = $zero;
-        let ray_o = $zero;
+        let ray_o = <$t>::new($wt::splat(0.0), $wt::splat(0.0), $wt::splat(-11.0));
         let sphere_r_sq = $wt::splat(100.0);
         let miss = $wt::splat($max);
 
@@ -51,6 +61,11 @@ macro_rules! bench_intersection_wide_uv {
 
             *result = t;
         });
+        $crate::check::Check::check(&mut *$b, move |(ray_d,): &($t,)| {
+            let mut result = $wt::splat(0.0);
+            do_inner(&ray_d.normalized(), &mut result);
+            result
+        });
         $b.iter(|| {
             for (ray_d, result) in data.ray_d.iter().zip(&mut data.result) {
                 do_inner(ray_d, result);
@@ -59,15 +74,23 @@ macro_rules! bench_intersection_wide_uv {
     }};
 }
 
-#[cfg(any(feature = "nalgebra_f32x4", feature = "nalgebra_f32x8",))]
+#[cfg(any(
+    feature = "nalgebra_f32x4",
+    feature = "nalgebra_f32x8",
+    feature = "nalgebra_f32x16",
+    feature = "nalgebra_f64x2",
+    feature = "nalgebra_f64x4",
+    feature = "nalgebra_f64x8",
+))]
 macro_rules! bench_intersection_wide_na {
     ($b: ident, $size:expr, ty => $t: ty, wt => $wt: ident, zero_vec => $zero: expr, max => $max: expr) => {{
+        use simba::simd::{SimdComplexField, SimdPartialOrd, SimdValue};
         struct TestData {
             ray_d: Vec<$t>,
             result: Vec<$wt>,
         }
 
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut rng = mathbench::rng("ray sphere intersect");
         let ray_d = (0..*$size)
             .into_iter()
             .map(|_| <$t as mathbench::BenchValue>::random_value(&mut rng).normalize())
@@ -78,7 +101,7 @@ macro_rules! bench_intersection_wide_na {
         };
 
         let sphere_o: $t = $zero;
-        let ray_o: $t = $zero;
+        let ray_o = <$t>::new($wt::splat(0.0), $wt::splat(0.0), $wt::splat(-11.0));
         let sphere_r_sq = $wt::splat(100.0);
         let miss = $wt::splat($max);
 
@@ -89,20 +112,25 @@ macro_rules! bench_intersection_wide_na {
             let descrim = b * b - c;
 
             let z = $wt::splat(0.0);
-            let desc_pos = descrim.0.gt(z.0);
+            let desc_pos = descrim.simd_gt(z);
 
-            let desc_sqrt = simba::simd::Simd(descrim.0.sqrt());
+            let desc_sqrt = descrim.simd_sqrt();
 
             let t1 = -b - desc_sqrt;
-            let t1_valid = t1.0.gt(z.0) & desc_pos;
+            let t1_valid = t1.simd_gt(z) & desc_pos;
 
             let t2 = -b + desc_sqrt;
-            let t2_valid = t2.0.gt(z.0) & desc_pos;
+            let t2_valid = t2.simd_gt(z) & desc_pos;
 
-            let t = t2_valid.select(t2.0, miss.0);
-            let t = t1_valid.select(t1.0, t);
+            let t = t2.select(t2_valid, miss);
+            let t = t1.select(t1_valid, t);
 
-            *result = simba::simd::Simd(t);
+            *result = t;
+        });
+        $crate::check::Check::check(&mut *$b, move |(ray_d,): &($t,)| {
+            let mut result = $wt::splat(0.0);
+            do_inner(&ray_d.normalize(), &mut result);
+            result
         });
         $b.iter(|| {
             for (ray_d, result) in data.ray_d.iter().zip(&mut data.result) {
@@ -114,12 +142,15 @@ macro_rules! bench_intersection_wide_na {
 
 macro_rules! bench_intersection_scalar {
     ($b: ident, $size:expr, ty => $t: ty, zero => $zero: expr, norm => $norm: ident, mag_sq => $mag_sq: ident, param => $param: tt) => {{
+        bench_intersection_scalar!($b, $size, ty => $t, scalar => f32, zero => $zero, norm => $norm, mag_sq => $mag_sq, param => $param)
+    }};
+    ($b: ident, $size:expr, ty => $t: ty, scalar => $s: ident, zero => $zero: expr, norm => $norm: ident, mag_sq => $mag_sq: ident, param => $param: tt) => {{
         struct TestData {
             ray_d: Vec<$t>,
-            result: Vec<f32>,
+            result: Vec<$s>,
         }
 
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut rng = mathbench::rng("ray sphere intersect");
         let ray_d = (0..*$size)
             .into_iter()
             .map(|_| <$t as mathbench::BenchValue>::random_value(&mut rng).$norm())
@@ -133,7 +164,7 @@ macro_rules! bench_intersection_scalar {
         let sphere_r_sq = 100.0;
         let ray_o = <$t>::new(0.0, 0.0, -11.0);
 
-        let do_inner = never_inline_closure!(|ray_d: &$t, result: &mut f32| {
+        let do_inner = never_inline_closure!(|ray_d: &$t, result: &mut $s| {
             let oc: $t = ray_o - sphere_o;
             let b = oc.dot($param!(ray_d));
             let c = oc.$mag_sq() - sphere_r_sq;
@@ -150,13 +181,18 @@ macro_rules! bench_intersection_scalar {
                     if t2 > 0.0 {
                         t2
                     } else {
-                        f32::MAX
+                        $s::MAX
                     }
                 }
             } else {
-                f32::MAX
+                $s::MAX
             };
         });
+        $crate::check::Check::check(&mut *$b, move |(ray_d,): &($t,)| {
+            let mut result = 0.0;
+            do_inner(&ray_d.$norm(), &mut result);
+            result
+        });
 
         $b.iter(|| {
             for (ray_d, result) in data.ray_d.iter().zip(&mut data.result) {
@@ -167,7 +203,7 @@ macro_rules! bench_intersection_scalar {
 }
 
 fn bench_ray_sphere_intersect_scalar(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar ray-sphere intersection");
+    let mut group = check::benchmark_group(c, "scalar ray-sphere intersection");
     for size in [10000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -199,7 +235,7 @@ fn bench_ray_sphere_intersect_scalar(c: &mut Criterion) {
 }
 
 fn bench_ray_sphere_intersect_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide ray-sphere intersection");
+    let mut group = check::benchmark_group(c, "wide ray-sphere intersection");
     for size in [80000].iter() {
         group.throughput(Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -214,7 +250,7 @@ fn bench_ray_sphere_intersect_wide(c: &mut Criterion) {
         });
         bench!("nalgebra_f32x4", group, size, |b, size| {
             use nalgebra::{zero, Vector3};
-            use simba::simd::{f32x4, SimdValue};
+            use simba::simd::f32x4;
             bench_intersection_wide_na!(b, &((*size as f32 / 4.0).ceil() as usize), ty => Vector3<f32x4>, wt => f32x4, zero_vec => zero(), max => f32::MAX);
         });
 
@@ -225,9 +261,57 @@ fn bench_ray_sphere_intersect_wide(c: &mut Criterion) {
         });
         bench!("nalgebra_f32x8", group, size, |b, size| {
             use nalgebra::{zero, Vector3};
-            use simba::simd::{f32x8, SimdValue};
+            use simba::simd::f32x8;
             bench_intersection_wide_na!(b, &((*size as f32 / 8.0).ceil() as usize), ty => Vector3<f32x8>, wt => f32x8, zero_vec => zero(), max => f32::MAX);
         });
+
+        // avx-512
+        bench!("nalgebra_f32x16", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::f32x16;
+            bench_intersection_wide_na!(b, &((*size as f32 / 16.0).ceil() as usize), ty => Vector3<f32x16>, wt => f32x16, zero_vec => zero(), max => f32::MAX);
+        });
+    }
+    group.finish();
+}
+
+fn bench_ray_sphere_intersect_wide_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "wide f64 ray-sphere intersection");
+    for size in [80000].iter() {
+        group.throughput(Throughput::Elements(*size as u64));
+        bench!("glam_f64x1", group, size, |b, size| {
+            use glam::DVec3;
+            bench_intersection_scalar!(b, size, ty => DVec3, scalar => f64, zero => DVec3::ZERO, norm => normalize, mag_sq => length_squared, param => by_value);
+        });
+
+        // sse
+        bench!("ultraviolet_f64x2", group, size, |b, size| {
+            use ultraviolet::{f64x2, DVec3x2};
+            bench_intersection_wide_uv!(b, &((*size as f32 / 2.0).ceil() as usize), ty => DVec3x2, wt => f64x2, zero_vec => DVec3x2::zero(), max => f64::MAX)
+        });
+        bench!("nalgebra_f64x2", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::f64x2;
+            bench_intersection_wide_na!(b, &((*size as f32 / 2.0).ceil() as usize), ty => Vector3<f64x2>, wt => f64x2, zero_vec => zero(), max => f64::MAX);
+        });
+
+        // avx
+        bench!("ultraviolet_f64x4", group, size, |b, size| {
+            use ultraviolet::{f64x4, DVec3x4};
+            bench_intersection_wide_uv!(b, &((*size as f32 / 4.0).ceil() as usize), ty => DVec3x4, wt => f64x4, zero_vec => DVec3x4::zero(), max => f64::MAX)
+        });
+        bench!("nalgebra_f64x4", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::f64x4;
+            bench_intersection_wide_na!(b, &((*size as f32 / 4.0).ceil() as usize), ty => Vector3<f64x4>, wt => f64x4, zero_vec => zero(), max => f64::MAX);
+        });
+
+        // avx-512
+        bench!("nalgebra_f64x8", group, size, |b, size| {
+            use nalgebra::{zero, Vector3};
+            use simba::simd::f64x8;
+            bench_intersection_wide_na!(b, &((*size as f32 / 8.0).ceil() as usize), ty => Vector3<f64x8>, wt => f64x8, zero_vec => zero(), max => f64::MAX);
+        });
     }
     group.finish();
 }
@@ -236,6 +320,7 @@ criterion_group!(
     rsi_benches,
     bench_ray_sphere_intersect_scalar,
     bench_ray_sphere_intersect_wide,
+    bench_ray_sphere_intersect_wide_f64,
 );
 
-criterion_main!(rsi_benches);
+criterion_main!(environment::record, rsi_benches);
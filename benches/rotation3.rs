@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -8,7 +12,7 @@ use std::ops::Mul;
 
 // returns self to check overhead of benchmark
 fn bench_rotation3_nop(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar rotation3 return self");
+    let mut group = check::benchmark_group(c, "scalar rotation3 return self");
     bench!("glam", group, |b| {
         use glam::Quat;
         bench_unop!(b, op => ret_self, ty => Quat)
@@ -35,7 +39,7 @@ fn bench_rotation3_nop(c: &mut Criterion) {
 
 fn bench_rotation3_nop_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide rotation3 return self");
+    let mut group = check::benchmark_group(c, "wide rotation3 return self");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Quat;
@@ -59,12 +63,17 @@ fn bench_rotation3_nop_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => ret_self, ty => UnitQuaternion<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::UnitQuaternion;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => ret_self, ty => UnitQuaternion<f32x16>)
+    });
     group.finish();
 }
 
 fn bench_rotation3_inverse(c: &mut Criterion) {
     // unit quaternion inverse is the conjugate
-    let mut group = c.benchmark_group("scalar rotation3 inverse");
+    let mut group = check::benchmark_group(c, "scalar rotation3 inverse");
     bench!("glam", group, |b| {
         use glam::Quat;
         bench_unop!(b, op => conjugate, ty => Quat)
@@ -94,9 +103,41 @@ fn bench_rotation3_inverse(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_rotation3_inverse_f64(c: &mut Criterion) {
+    // unit quaternion inverse is the conjugate
+    let mut group = check::benchmark_group(c, "scalar-f64 rotation3 inverse");
+    bench!("glam", group, |b| {
+        use glam::DQuat;
+        bench_unop!(b, op => conjugate, ty => DQuat)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::Quaternion;
+        bench_unop!(b, op => conjugate, ty => Quaternion<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DRotor3;
+        bench_unop!(b, op => reversed, ty => DRotor3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::UnitQuaternion;
+        bench_unop!(b, op => conjugate, ty => UnitQuaternion<f64>)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{Rotation3D, UnknownUnit};
+        // euclid inverse assumes normalized quaternion, so it's just a conjugate
+        bench_unop!(b, op => inverse, ty => Rotation3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Quaternion;
+        bench_unop!(b, op => conjugate, ty => Quaternion<f64>)
+    });
+    group.finish();
+}
+
 fn bench_rotation3_inverse_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide rotation3 inverse");
+    let mut group = check::benchmark_group(c, "wide rotation3 inverse");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Quat;
@@ -120,11 +161,16 @@ fn bench_rotation3_inverse_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => conjugate, ty => UnitQuaternion<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::UnitQuaternion;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => conjugate, ty => UnitQuaternion<f32x16>)
+    });
     group.finish();
 }
 
 fn bench_rotation3_mul_rotation3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar rotation3 mul rotation3");
+    let mut group = check::benchmark_group(c, "scalar rotation3 mul rotation3");
     bench!("glam", group, |b| {
         use glam::Quat;
         bench_binop!(b, op => mul, ty1 => Quat, ty2 => Quat)
@@ -144,7 +190,8 @@ fn bench_rotation3_mul_rotation3(c: &mut Criterion) {
 
     bench!("euclid", group, |b| {
         use euclid::{Rotation3D, UnknownUnit};
-        bench_binop!(b, op => then, ty => Rotation3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Rotation3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
     });
     bench!("vek", group, |b| {
         use vek::Quaternion;
@@ -153,9 +200,40 @@ fn bench_rotation3_mul_rotation3(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_rotation3_mul_rotation3_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 rotation3 mul rotation3");
+    bench!("glam", group, |b| {
+        use glam::DQuat;
+        bench_binop!(b, op => mul, ty1 => DQuat, ty2 => DQuat)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::Quaternion;
+        bench_binop!(b, op => mul, ty1 => Quaternion<f64>, ty2 => Quaternion<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DRotor3;
+        bench_binop!(b, op => mul, ty1 => DRotor3, ty2 => DRotor3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::UnitQuaternion;
+        bench_binop!(b, op => mul, ty1 => UnitQuaternion<f64>, ty2 => UnitQuaternion<f64>)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{Rotation3D, UnknownUnit};
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Rotation3D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    bench!("vek", group, |b| {
+        use vek::Quaternion;
+        bench_binop!(b, op => mul, ty1 => Quaternion<f64>, ty2 => Quaternion<f64>);
+    });
+    group.finish();
+}
+
 fn bench_rotation3_mul_rotation3_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide rotation3 mul rotation3");
+    let mut group = check::benchmark_group(c, "wide rotation3 mul rotation3");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Quat;
@@ -179,11 +257,16 @@ fn bench_rotation3_mul_rotation3_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_binop_wide!(b, size, width => 8, op => mul, ty1 => UnitQuaternion<f32x8>, ty2 => UnitQuaternion<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::UnitQuaternion;
+        use simba::simd::f32x16;
+        bench_binop_wide!(b, size, width => 16, op => mul, ty1 => UnitQuaternion<f32x16>, ty2 => UnitQuaternion<f32x16>)
+    });
     group.finish();
 }
 
 fn bench_rotation3_mul_vector3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar rotation3 mul vector3");
+    let mut group = check::benchmark_group(c, "scalar rotation3 mul vector3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -215,9 +298,42 @@ fn bench_rotation3_mul_vector3(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_rotation3_mul_vector3_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 rotation3 mul vector3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DQuat, DVec3};
+            bench_binop!(b, size, op => mul, ty1 => DQuat, ty2 => DVec3)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Quaternion, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => Quaternion<f64>, ty2 => Vector3<f64>)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DRotor3, DVec3};
+            bench_binop!(b, size, op => mul, ty1 => DRotor3, ty2 => DVec3)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{UnitQuaternion, Vector3};
+            bench_binop!(b, size, op => mul, ty1 => UnitQuaternion<f64>, ty2 => Vector3<f64>)
+        });
+
+        bench!("euclid", group, size, |b, size| {
+            use euclid::{Point3D, Rotation3D, UnknownUnit};
+            bench_binop!(b, size, op => transform_point3d, ty1 => Rotation3D<f64, UnknownUnit, UnknownUnit>, ty2 => Point3D<f64, UnknownUnit>)
+        });
+        bench!("vek", group, size, |b, size| {
+            use vek::{Quaternion, Vec3};
+            bench_binop!(b, size, op => mul, ty1 => Quaternion<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_rotation3_mul_vector3_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide rotation3 mul vector3");
+    let mut group = check::benchmark_group(c, "wide rotation3 mul vector3");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::{Quat, Vec3};
@@ -241,6 +357,11 @@ fn bench_rotation3_mul_vector3_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_binop_wide!(b, size, width => 8, op => mul, ty1 => UnitQuaternion<f32x8>, ty2 => Vector3<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::{UnitQuaternion, Vector3};
+        use simba::simd::f32x16;
+        bench_binop_wide!(b, size, width => 16, op => mul, ty1 => UnitQuaternion<f32x16>, ty2 => Vector3<f32x16>)
+    });
     group.finish();
 }
 
@@ -249,10 +370,13 @@ criterion_group!(
     bench_rotation3_nop,
     bench_rotation3_nop_wide,
     bench_rotation3_inverse,
+    bench_rotation3_inverse_f64,
     bench_rotation3_inverse_wide,
     bench_rotation3_mul_rotation3,
+    bench_rotation3_mul_rotation3_f64,
     bench_rotation3_mul_rotation3_wide,
     bench_rotation3_mul_vector3,
+    bench_rotation3_mul_vector3_f64,
     bench_rotation3_mul_vector3_wide,
 );
-criterion_main!(rotation3_benches);
+criterion_main!(environment::record, rotation3_benches);
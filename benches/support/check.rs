@@ -0,0 +1,287 @@
+//! Checks that the libraries in a benchmark group compute the same thing before
+//! their benchmarks are timed. Each group's reference is glam's operation,
+//! which `bench!` registers whether or not glam's benchmark is filtered out.
+//! Each library's operation is run once on inputs drawn from a stream named
+//! after the group, and its outputs are compared with the reference's for the
+//! same inputs, lane by lane for wide types. A mismatch panics, failing the
+//! benchmark run rather than publishing a comparison of different operations.
+
+// not every benchmark uses every kind of check
+#![allow(dead_code)]
+
+use criterion::{measurement::WallTime, Bencher, BenchmarkGroup, Criterion};
+use mathbench::{BenchValue, Components, FromComponents, InputDistribution, InputRng};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+/// The number of inputs each library's operation is checked on.
+const SAMPLES: usize = 256;
+
+/// The largest difference allowed between a library's components and the
+/// reference, relative to the largest reference component or 1 if that's
+/// smaller. This is loose enough for the rounding differences `f32`
+/// implementations have, which the `accuracy` bench measures, but not for a
+/// different operation.
+const TOLERANCE: f64 = 1e-2;
+
+/// The operands of an operation, `(a,)` or `(a, b)`.
+pub trait Operands {
+    fn random(rng: &mut InputRng) -> Self;
+
+    /// Returns the components of each operand.
+    fn components(&self) -> Vec<Vec<f64>>;
+}
+
+/// Operands of the types the reference operation takes.
+pub trait ReferenceOperands: Operands {
+    /// Returns the number of components of each operand.
+    fn sizes() -> Vec<usize>;
+
+    fn from_components(components: &[&[f64]]) -> Self;
+}
+
+impl<A: BenchValue + Components> Operands for (A,) {
+    fn random(rng: &mut InputRng) -> Self {
+        (A::random_value(rng),)
+    }
+
+    fn components(&self) -> Vec<Vec<f64>> {
+        vec![self.0.components()]
+    }
+}
+
+impl<A: BenchValue + Components, B: BenchValue + Components> Operands for (A, B) {
+    fn random(rng: &mut InputRng) -> Self {
+        let a = A::random_value(rng);
+        (a, B::random_value(rng))
+    }
+
+    fn components(&self) -> Vec<Vec<f64>> {
+        vec![self.0.components(), self.1.components()]
+    }
+}
+
+impl<A: BenchValue + FromComponents> ReferenceOperands for (A,) {
+    fn sizes() -> Vec<usize> {
+        vec![A::COMPONENTS]
+    }
+
+    fn from_components(components: &[&[f64]]) -> Self {
+        (A::from_components(components[0]),)
+    }
+}
+
+impl<A, B> ReferenceOperands for (A, B)
+where
+    A: BenchValue + FromComponents,
+    B: BenchValue + FromComponents,
+{
+    fn sizes() -> Vec<usize> {
+        vec![A::COMPONENTS, B::COMPONENTS]
+    }
+
+    fn from_components(components: &[&[f64]]) -> Self {
+        (
+            A::from_components(components[0]),
+            B::from_components(components[1]),
+        )
+    }
+}
+
+/// Checks an operation, called by the benchmark macros with their `Bencher`.
+/// Criterion's `Bencher` checks the operation against the group's reference
+/// and `Checker` registers it as the reference.
+pub trait Check<I, O> {
+    fn check<F: Fn(&I) -> O + 'static>(&mut self, op: F);
+}
+
+/// Runs a benchmark function without timing it, to register its operation as
+/// the group's reference.
+pub struct Checker;
+
+impl Checker {
+    pub fn iter<O, R: FnMut() -> O>(&mut self, _routine: R) {}
+}
+
+/// An operation that takes the components of each operand and returns the
+/// components of its output.
+type Op = Box<dyn Fn(&[&[f64]]) -> Vec<f64>>;
+
+/// The group's reference operation, which takes one lane of each operand.
+struct Reference {
+    library: &'static str,
+    sizes: Vec<usize>,
+    op: Op,
+}
+
+#[derive(Default)]
+struct State {
+    group: String,
+    library: &'static str,
+    references: HashMap<String, Reference>,
+    checked: HashSet<(String, &'static str)>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+/// Creates a benchmark group whose libraries are checked against glam.
+pub fn benchmark_group<'a>(c: &'a mut Criterion, name: &str) -> BenchmarkGroup<'a, WallTime> {
+    STATE.with(|state| state.borrow_mut().group = name.to_string());
+    c.benchmark_group(name)
+}
+
+/// Returns the input stream of the current benchmark group. Libraries call
+/// different methods for the same operation, so the stream is named after the
+/// group for every library to benchmark identical inputs.
+pub fn rng() -> InputRng {
+    STATE.with(|state| mathbench::rng(&state.borrow().group))
+}
+
+/// Sets the library whose benchmark is about to run, called by `bench!`.
+pub fn library(name: &'static str) {
+    STATE.with(|state| state.borrow_mut().library = name);
+}
+
+/// Registers the group's reference by running glam's benchmark function
+/// without timing it, called by `bench!`.
+pub fn reference(library: &'static str, bench: impl FnOnce(&mut Checker)) {
+    if needs_reference(library) {
+        bench(&mut Checker);
+    }
+}
+
+/// Registers the group's reference from a benchmark function with an input.
+pub fn reference_with_input<I: ?Sized>(
+    library: &'static str,
+    input: &I,
+    bench: impl FnOnce(&mut Checker, &I),
+) {
+    if needs_reference(library) {
+        bench(&mut Checker, input);
+    }
+}
+
+fn needs_reference(library: &'static str) -> bool {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.library = library;
+        !state.references.contains_key(&state.group)
+    })
+}
+
+impl<I, O> Check<I, O> for Checker
+where
+    I: ReferenceOperands + 'static,
+    O: Components + 'static,
+{
+    fn check<F: Fn(&I) -> O + 'static>(&mut self, op: F) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let reference = Reference {
+                library: state.library,
+                sizes: I::sizes(),
+                op: Box::new(move |components| op(&I::from_components(components)).components()),
+            };
+            let group = state.group.clone();
+            state.references.insert(group, reference);
+        });
+    }
+}
+
+impl<I: Operands, O: Components> Check<I, O> for Bencher<'_, WallTime> {
+    fn check<F: Fn(&I) -> O + 'static>(&mut self, op: F) {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let key = (state.group.clone(), state.library);
+            // Criterion runs the benchmark function for every sample it takes
+            if !state.checked.insert(key) {
+                return;
+            }
+            // this only runs for the benchmarks the filter selects
+            crate::environment::record_group(&state.group);
+            let state = &*state;
+            let reference = state
+                .references
+                .get(&state.group)
+                .unwrap_or_else(|| panic!("{}: glam didn't register a reference", state.group));
+            if reference.library == state.library {
+                return;
+            }
+            // uniform inputs, as this checks what is computed rather than how
+            // accurately, which some input distributions would affect
+            let mut rng = mathbench::rng_with(&state.group, InputDistribution::Uniform);
+            for index in 0..SAMPLES {
+                let inputs = I::random(&mut rng);
+                let operands = inputs.components();
+                let output = op(&inputs).components();
+                check_sample(state, reference, index, &operands, &output);
+            }
+        });
+    }
+}
+
+/// Returns whether `actual` is within `TOLERANCE` of `expected`.
+fn equivalent(expected: &[f64], actual: &[f64]) -> bool {
+    let magnitude = expected.iter().fold(1.0_f64, |max, e| max.max(e.abs()));
+    expected.len() == actual.len()
+        && expected
+            .iter()
+            .zip(actual)
+            .all(|(e, a)| (e - a).abs() <= TOLERANCE * magnitude)
+}
+
+/// Compares each lane of a library's output with the reference's output for
+/// that lane's operands.
+fn check_sample(
+    state: &State,
+    reference: &Reference,
+    index: usize,
+    operands: &[Vec<f64>],
+    output: &[f64],
+) {
+    let lanes = operands[0].len() / reference.sizes[0];
+    assert!(
+        lanes > 0
+            && operands.len() == reference.sizes.len()
+            && operands
+                .iter()
+                .zip(&reference.sizes)
+                .all(|(operand, size)| operand.len() == lanes * size)
+            && output.len().is_multiple_of(lanes),
+        "{}: {}'s inputs don't have the components of {}'s: {:?}",
+        state.group,
+        state.library,
+        reference.library,
+        operands,
+    );
+    let width = output.len() / lanes;
+    for lane in 0..lanes {
+        let inputs: Vec<&[f64]> = operands
+            .iter()
+            .zip(&reference.sizes)
+            .map(|(operand, size)| &operand[lane * size..(lane + 1) * size])
+            .collect();
+        let expected = (reference.op)(&inputs);
+        // the input is singular for the reference
+        if expected.iter().any(|e| !e.is_finite()) {
+            continue;
+        }
+        let actual = &output[lane * width..(lane + 1) * width];
+        assert!(
+            equivalent(&expected, actual),
+            "{}: {}'s output differs from {}'s for sample {} lane {}:\n{:?}\n{:?}\n{:?}",
+            state.group,
+            state.library,
+            reference.library,
+            index,
+            lane,
+            inputs,
+            actual,
+            expected,
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Records the machine and toolchain environment of a benchmark run in a
+//! sidecar file next to the Criterion output, so results can be published and
+//! compared with the context they were produced in. The build settings are
+//! captured at compile time by `build.rs`.
+
+use serde::Serialize;
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashSet},
+    env, fs,
+    path::PathBuf,
+    process::Command,
+};
+
+#[derive(Serialize)]
+struct Profile {
+    name: &'static str,
+    opt_level: &'static str,
+    debug: &'static str,
+}
+
+#[derive(Serialize)]
+struct Environment {
+    cpu: String,
+    target: &'static str,
+    target_features: Vec<&'static str>,
+    rustc: &'static str,
+    rustflags: &'static str,
+    profile: Profile,
+    features: Vec<&'static str>,
+    crates: BTreeMap<&'static str, &'static str>,
+    seed: u64,
+    inputs: &'static str,
+}
+
+fn split(list: &'static str) -> impl Iterator<Item = &'static str> {
+    list.split(',').filter(|item| !item.is_empty())
+}
+
+fn cpu_name() -> String {
+    let name = if cfg!(target_os = "macos") {
+        Command::new("sysctl")
+            .args(["-n", "machdep.cpu.brand_string"])
+            .output()
+            .ok()
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+    } else {
+        fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| {
+                cpuinfo
+                    .lines()
+                    .find(|line| line.starts_with("model name"))
+                    .and_then(|line| line.split(':').nth(1))
+                    .map(|name| name.to_string())
+            })
+    };
+    name.map(|name| name.trim().to_string())
+        .unwrap_or_else(|| env::consts::ARCH.to_string())
+}
+
+impl Environment {
+    fn new() -> Self {
+        Environment {
+            cpu: cpu_name(),
+            target: env!("MATHBENCH_TARGET"),
+            target_features: split(env!("MATHBENCH_TARGET_FEATURES")).collect(),
+            rustc: env!("MATHBENCH_RUSTC_VERSION"),
+            rustflags: env!("MATHBENCH_RUSTFLAGS"),
+            profile: Profile {
+                name: env!("MATHBENCH_PROFILE"),
+                opt_level: env!("MATHBENCH_OPT_LEVEL"),
+                debug: env!("MATHBENCH_DEBUG"),
+            },
+            features: split(env!("MATHBENCH_FEATURES")).collect(),
+            crates: split(env!("MATHBENCH_CRATES"))
+                .filter_map(|pair| {
+                    let mut parts = pair.splitn(2, '=');
+                    Some((parts.next()?, parts.next()?))
+                })
+                .collect(),
+            seed: mathbench::seed(),
+            inputs: mathbench::input_distribution().as_str(),
+        }
+    }
+}
+
+/// Returns the names of the Criterion baselines this run writes to, mirroring
+/// Criterion's `--save-baseline`, `--baseline` and `--discard-baseline` options.
+pub fn baselines(args: &[String]) -> Vec<String> {
+    let mut baselines = vec!["new".to_string()];
+    let mut save = Some("base".to_string());
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        match name {
+            "--save-baseline" | "-s" => {
+                save = value.or_else(|| args.next().cloned());
+            }
+            "--baseline" | "-b" | "--baseline-lenient" | "--discard-baseline" => save = None,
+            _ => {}
+        }
+    }
+    baselines.extend(save);
+    baselines
+}
+
+fn criterion_dir() -> PathBuf {
+    // the same search order as Criterion, other than asking `cargo metadata`
+    env::var_os("CRITERION_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("CARGO_TARGET_DIR").map(|dir| PathBuf::from(dir).join("criterion")))
+        .unwrap_or_else(|| PathBuf::from("target/criterion"))
+}
+
+/// Writes `<name>/<baseline>.json` to the Criterion output directory for each
+/// baseline written by this run.
+pub fn write_sidecar(name: &str, json: &str, args: &[String]) -> std::io::Result<()> {
+    let dir = criterion_dir().join(name);
+    fs::create_dir_all(&dir)?;
+    for baseline in baselines(args) {
+        fs::write(dir.join(format!("{}.json", baseline)), json)?;
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct State {
+    /// The arguments of a benchmark run, `None` if it isn't recorded.
+    args: Option<Vec<String>>,
+    recorded: HashSet<String>,
+}
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+/// Starts recording the environment of each benchmark group that runs. Pass
+/// this to `criterion_main!` before the benchmark groups.
+pub fn record() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    // `cargo test --benches` runs benchmarks once as tests, don't record those
+    if !args.iter().any(|arg| arg == "--bench") {
+        return;
+    }
+    STATE.with(|state| state.borrow_mut().args = Some(args));
+}
+
+/// Writes `mathbench-env/<group>/<baseline>.json` to the Criterion output
+/// directory for each baseline written by this run, the first time a group is
+/// benchmarked. Groups are recorded separately as different groups, or the
+/// same group filtered differently, may be run with different compilers and
+/// features.
+pub fn record_group(group: &str) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let args = match &state.args {
+            Some(args) => args.clone(),
+            None => return,
+        };
+        if !state.recorded.insert(group.to_string()) {
+            return;
+        }
+        let result = serde_json::to_string_pretty(&Environment::new())
+            .map_err(|e| e.into())
+            .and_then(|json| write_sidecar(&format!("mathbench-env/{}", group), &json, &args));
+        if let Err(e) = result {
+            eprintln!(
+                "Failed to record the benchmark environment of '{}': {}",
+                group, e
+            );
+        }
+    });
+}
@@ -22,43 +22,86 @@ pub const MIN_WIDE_BENCH_SIZE: u64 = 16;
 /// bench!("pathfinder_geometry" as "pathfinder", group, |b| { ... });
 /// bench!("pathfinder_geometry" as "pathfinder", group, size, |b, size| { ... });
 /// ```
+///
+/// glam's benchmark function is also run untimed, even when the benchmark is
+/// filtered out or its feature is disabled, to register its operation as the
+/// group's reference. The library's display name is passed to
+/// `check::library`, so the benchmark macros can check its operation against
+/// the reference.
 #[macro_export]
 macro_rules! bench {
     // glam (always compiled, no feature gate)
-    ("glam", $group:ident, $closure:expr) => {
+    ("glam", $group:ident, $closure:expr) => {{
+        bench!(@reference "glam", $group, $closure);
+        $crate::check::library("glam");
         $group.bench_function("glam", $closure)
-    };
-    ("glam", $group:ident, $size:expr, $closure:expr) => {
+    }};
+    ("glam", $group:ident, $size:expr, $closure:expr) => {{
+        bench!(@reference "glam", $group, $size, $closure);
+        $crate::check::library("glam");
         $group.bench_with_input(criterion::BenchmarkId::new("glam", $size), $size, $closure)
+    }};
+
+    // glam in wide groups (feature gated, but always the reference)
+    ("glam_f32x1", $($args:tt)+) => {{
+        bench!(@reference "glam_f32x1", $($args)+);
+        bench!("glam_f32x1" as "glam_f32x1", $($args)+);
+    }};
+    ("glam_f64x1", $($args:tt)+) => {{
+        bench!(@reference "glam_f64x1", $($args)+);
+        bench!("glam_f64x1" as "glam_f64x1", $($args)+);
+    }};
+    (@reference $lib:literal, $group:ident, $closure:expr) => {
+        $crate::check::reference($lib, $closure)
+    };
+    (@reference $lib:literal, $group:ident, $size:expr, $closure:expr) => {
+        $crate::check::reference_with_input($lib, $size, $closure)
     };
 
     // Gated libraries (feature name = display name)
     ($lib:literal, $group:ident, $closure:expr) => {
         #[cfg(feature = $lib)]
-        $group.bench_function($lib, $closure)
+        {
+            $crate::check::library($lib);
+            $group.bench_function($lib, $closure);
+        }
     };
     ($lib:literal, $group:ident, $size:expr, $closure:expr) => {
         #[cfg(feature = $lib)]
-        $group.bench_with_input(criterion::BenchmarkId::new($lib, $size), $size, $closure)
+        {
+            $crate::check::library($lib);
+            $group.bench_with_input(criterion::BenchmarkId::new($lib, $size), $size, $closure);
+        }
     };
 
     // Display-name override
     ($lib:literal as $display:literal, $group:ident, $closure:expr) => {
         #[cfg(feature = $lib)]
-        $group.bench_function($display, $closure)
+        {
+            $crate::check::library($display);
+            $group.bench_function($display, $closure);
+        }
     };
     ($lib:literal as $display:literal, $group:ident, $size:expr, $closure:expr) => {
         #[cfg(feature = $lib)]
-        $group.bench_with_input(criterion::BenchmarkId::new($display, $size), $size, $closure)
+        {
+            $crate::check::library($display);
+            $group.bench_with_input(
+                criterion::BenchmarkId::new($display, $size),
+                $size,
+                $closure,
+            );
+        }
     };
 }
 
 #[macro_export]
 macro_rules! bench_unop {
     ($b: ident, op => $unop: ident, ty => $t:ty) => {{
+        $crate::check::Check::check(&mut *$b, |(a,): &($t,)| a.$unop());
         let size: usize = 1 << 13;
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
-        let inputs = criterion::black_box(
+        let mut rng = $crate::check::rng();
+        let inputs = std::hint::black_box(
             (0..size)
                 .map(|_| <$t as mathbench::BenchValue>::random_value(&mut rng))
                 .collect::<Vec<_>>(),
@@ -72,20 +115,21 @@ macro_rules! bench_unop {
             unsafe { *outputs.get_unchecked_mut(i) = res }
             res
         });
-        criterion::black_box(outputs);
+        std::hint::black_box(outputs);
     }};
 }
 
 #[macro_export]
 macro_rules! bench_unop_wide {
     ($b: ident, $size: expr, width => $width: expr, op => $unop: ident, ty => $t:ty) => {{
+        $crate::check::Check::check(&mut *$b, |(a,): &($t,)| a.$unop());
         const SIZE: usize = 1 << 13;
         let size = *$size as f32;
         let batch_size = (size / $width as f32).ceil() as usize;
         let total_size = SIZE * batch_size;
 
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
-        let inputs = criterion::black_box(
+        let mut rng = $crate::check::rng();
+        let inputs = std::hint::black_box(
             (0..total_size)
                 .map(|_| <$t as mathbench::BenchValue>::random_value(&mut rng))
                 .collect::<Vec<_>>(),
@@ -101,13 +145,13 @@ macro_rules! bench_unop_wide {
             let end = start + batch_size;
             for j in start..end {
                 let res = unsafe { inputs.get_unchecked(j).$unop() };
-                criterion::black_box(res);
+                std::hint::black_box(res);
                 unsafe {
                     *outputs.get_unchecked_mut(j) = res;
                 }
             }
         });
-        criterion::black_box(outputs);
+        std::hint::black_box(outputs);
     }};
 }
 
@@ -128,16 +172,17 @@ macro_rules! by_ref {
 #[macro_export]
 macro_rules! bench_binop {
     ($b: ident, $size:expr, op => $binop: ident, ty1 => $t1:ty, ty2 => $t2:ty, param => $param:tt) => {{
+        $crate::check::Check::check(&mut *$b, |(a, b): &($t1, $t2)| a.$binop($param!(b)));
         const SIZE: usize = 1 << 13;
         let batch_size = SIZE * $size;
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut rng = $crate::check::rng();
         // generate input arrays
-        let inputs1 = criterion::black_box(
+        let inputs1 = std::hint::black_box(
             (0..batch_size)
                 .map(|_| <$t1 as mathbench::BenchValue>::random_value(&mut rng))
                 .collect::<Vec<_>>(),
         );
-        let inputs2 = criterion::black_box(
+        let inputs2 = std::hint::black_box(
             (0..batch_size)
                 .map(|_| <$t2 as mathbench::BenchValue>::random_value(&mut rng))
                 .collect::<Vec<_>>(),
@@ -187,20 +232,21 @@ macro_rules! bench_binop {
 #[macro_export]
 macro_rules! bench_binop_wide {
     ($b: ident, $size:expr, width => $width: expr, op => $binop: ident, ty1 => $t1:ty, ty2 => $t2:ty, param => $param:tt) => {{
+        $crate::check::Check::check(&mut *$b, |(a, b): &($t1, $t2)| a.$binop($param!(b)));
         assert!(*$size >= 16);
         let size = *$size as usize;
 
         let batch_size = (size as f32 / $width as f32).ceil() as usize;
         const SIZE: usize = 1 << 13;
         let total_size = SIZE * size;
-        let mut rng = rand_pcg::Pcg64Mcg::new(rand::random());
+        let mut rng = $crate::check::rng();
         // generate input arrays
-        let inputs1 = criterion::black_box(
+        let inputs1 = std::hint::black_box(
             (0..total_size)
                 .map(|_| <$t1 as mathbench::BenchValue>::random_value(&mut rng))
                 .collect::<Vec<_>>(),
         );
-        let inputs2 = criterion::black_box(
+        let inputs2 = std::hint::black_box(
             (0..total_size)
                 .map(|_| <$t2 as mathbench::BenchValue>::random_value(&mut rng))
                 .collect::<Vec<_>>(),
@@ -215,7 +261,7 @@ macro_rules! bench_binop_wide {
             let end = start + batch_size;
             for j in start..end {
                 let res = unsafe { inputs1.get_unchecked(j).$binop($param!(inputs2.get_unchecked(j))) };
-                criterion::black_box(res);
+                std::hint::black_box(res);
                 unsafe { *outputs.get_unchecked_mut(j) = res; }
             }
         })
@@ -231,13 +277,14 @@ macro_rules! never_inline_closure {
     (
         |$($arg:ident: $argt:ty),*| $body:tt
      ) => {{
+        // moves its captures so the closure can also be checked
         #[cfg(not(feature = "unstable"))]
-        let closure = |$($arg: $argt),*| {
+        let closure = move |$($arg: $argt),*| {
             $body
         };
 
         #[cfg(feature = "unstable")]
-        let closure = #[inline(never)] |$($arg: $argt),*| {
+        let closure = #[inline(never)] move |$($arg: $argt),*| {
             $body
         };
 
@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -5,7 +9,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_transform2_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("scalar transform2 return self");
+    let mut group = check::benchmark_group(c, "scalar transform2 return self");
     bench!("glam", group, |b| {
         use glam::Affine2;
         bench_unop!(b, op => ret_self, ty => Affine2)
@@ -26,7 +30,7 @@ fn bench_transform2_ret_self(c: &mut Criterion) {
 }
 
 fn bench_transform2_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar transform2 inverse");
+    let mut group = check::benchmark_group(c, "scalar transform2 inverse");
     bench!("glam", group, |b| {
         use glam::Affine2;
         bench_unop!(b, op => inverse, ty => Affine2)
@@ -46,9 +50,26 @@ fn bench_transform2_inverse(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform2_inverse_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 transform2 inverse");
+    bench!("glam", group, |b| {
+        use glam::DAffine2;
+        bench_unop!(b, op => inverse, ty => DAffine2)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Transform2;
+        bench_unop!(b, op => try_inverse, ty => Transform2<f64>)
+    });
+    bench!("euclid", group, |b| {
+        use euclid::{Transform2D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Transform2D<f64, UnknownUnit, UnknownUnit>)
+    });
+    group.finish();
+}
+
 fn bench_transform2_mul_transform2(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("scalar transform2 mul transform2");
+    let mut group = check::benchmark_group(c, "scalar transform2 mul transform2");
     bench!("glam", group, |b| {
         use glam::Affine2;
         bench_binop!(b, op => mul, ty1 => Affine2, ty2 => Affine2)
@@ -59,7 +80,8 @@ fn bench_transform2_mul_transform2(c: &mut Criterion) {
     });
     bench!("euclid", group, |b| {
         use euclid::{Transform2D, UnknownUnit};
-        bench_binop!(b, op => then, ty => Transform2D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Transform2D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
     });
     bench!("pathfinder_geometry" as "pathfinder", group, |b| {
         use pathfinder_geometry::transform2d::Transform2F;
@@ -69,10 +91,31 @@ fn bench_transform2_mul_transform2(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform2_mul_transform2_f64(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = check::benchmark_group(c, "scalar-f64 transform2 mul transform2");
+    bench!("glam", group, |b| {
+        use glam::DAffine2;
+        bench_binop!(b, op => mul, ty1 => DAffine2, ty2 => DAffine2)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Transform2;
+        bench_binop!(b, op => mul, ty1 => Transform2<f64>, ty2 => Transform2<f64>, param => by_ref)
+    });
+    bench!("euclid", group, |b| {
+        use euclid::{Transform2D, UnknownUnit};
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Transform2D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    group.finish();
+}
+
 criterion_group!(
     transform2d_benches,
     bench_transform2_ret_self,
     bench_transform2_inverse,
+    bench_transform2_inverse_f64,
     bench_transform2_mul_transform2,
+    bench_transform2_mul_transform2_f64,
 );
-criterion_main!(transform2d_benches);
+criterion_main!(environment::record, transform2d_benches);
@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -6,7 +10,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 // returns self to check overhead of benchmark
 fn bench_transform3_ret_self(c: &mut Criterion) {
     use mathbench::BenchValue;
-    let mut group = c.benchmark_group("scalar transform3 return self");
+    let mut group = check::benchmark_group(c, "scalar transform3 return self");
     bench!("glam", group, |b| {
         use glam::Affine3A;
         bench_unop!(b, op => ret_self, ty => Affine3A)
@@ -27,7 +31,7 @@ fn bench_transform3_ret_self(c: &mut Criterion) {
 }
 
 fn bench_transform3_inverse(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar transform3 inverse");
+    let mut group = check::benchmark_group(c, "scalar transform3 inverse");
     bench!("glam", group, |b| {
         use glam::Affine3A;
         bench_unop!(b, op => inverse, ty => Affine3A)
@@ -47,9 +51,26 @@ fn bench_transform3_inverse(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform3_inverse_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 transform3 inverse");
+    bench!("glam", group, |b| {
+        use glam::DAffine3;
+        bench_unop!(b, op => inverse, ty => DAffine3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Transform3;
+        bench_unop!(b, op => try_inverse, ty => Transform3<f64>)
+    });
+    bench!("euclid", group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        bench_unop!(b, op => inverse, ty => Transform3D<f64, UnknownUnit, UnknownUnit>)
+    });
+    group.finish();
+}
+
 fn bench_transform3_mul_transform3(c: &mut Criterion) {
     use std::ops::Mul;
-    let mut group = c.benchmark_group("scalar transform3 mul transform3d");
+    let mut group = check::benchmark_group(c, "scalar transform3 mul transform3d");
     bench!("glam", group, |b| {
         use glam::Affine3A;
         bench_binop!(b, op => mul, ty1 => Affine3A, ty2 => Affine3A)
@@ -60,7 +81,8 @@ fn bench_transform3_mul_transform3(c: &mut Criterion) {
     });
     bench!("euclid", group, |b| {
         use euclid::{Transform3D, UnknownUnit};
-        bench_binop!(b, op => then, ty => Transform3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Transform3D<f32, UnknownUnit, UnknownUnit>, param => by_ref)
     });
     bench!("pathfinder_geometry" as "pathfinder", group, |b| {
         use pathfinder_geometry::transform3d::Transform4F;
@@ -69,10 +91,31 @@ fn bench_transform3_mul_transform3(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform3_mul_transform3_f64(c: &mut Criterion) {
+    use std::ops::Mul;
+    let mut group = check::benchmark_group(c, "scalar-f64 transform3 mul transform3d");
+    bench!("glam", group, |b| {
+        use glam::DAffine3;
+        bench_binop!(b, op => mul, ty1 => DAffine3, ty2 => DAffine3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Transform3;
+        bench_binop!(b, op => mul, ty1 => Transform3<f64>, ty2 => Transform3<f64>, param => by_ref)
+    });
+    bench!("euclid", group, |b| {
+        use euclid::{Transform3D, UnknownUnit};
+        use mathbench::euclid_support::PreThen;
+        bench_binop!(b, op => pre_then, ty => Transform3D<f64, UnknownUnit, UnknownUnit>, param => by_ref)
+    });
+    group.finish();
+}
+
 criterion_group!(
     transform3d_benches,
     bench_transform3_ret_self,
     bench_transform3_inverse,
+    bench_transform3_inverse_f64,
     bench_transform3_mul_transform3,
+    bench_transform3_mul_transform3_f64,
 );
-criterion_main!(transform3d_benches);
+criterion_main!(environment::record, transform3d_benches);
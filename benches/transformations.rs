@@ -1,10 +1,14 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
 use criterion::{criterion_group, criterion_main, Criterion};
 
 fn bench_transform_vector3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar transform vector3");
+    let mut group = check::benchmark_group(c, "scalar transform vector3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -35,8 +39,40 @@ fn bench_transform_vector3(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform_vector3_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 transform vector3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DAffine3, DVec3};
+            bench_binop!(b, size, op => transform_vector3, ty1 => DAffine3, ty2 => DVec3)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat4, DVec3};
+            bench_binop!(b, size, op => transform_vec3, ty1 => DMat4, ty2 => DVec3)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Matrix4, Transform, Vector3};
+            bench_binop!(b, size, op => transform_vector, ty1 => Matrix4<f64>, ty2 => Vector3<f64>)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Transform3, Vector3};
+            bench_binop!(b, size, op => transform_vector, ty1 => Transform3<f64>, ty2 => Vector3<f64>, param => by_ref)
+        });
+        bench!("euclid", group, size, |b, size| {
+            use euclid::{Transform3D, UnknownUnit, Vector3D};
+            bench_binop!(b, size, op => transform_vector3d, ty1 => Transform3D<f64, UnknownUnit, UnknownUnit>, ty2 => Vector3D<f64, UnknownUnit>)
+        });
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat4, Vec3};
+            bench_binop!(b, size, op => mul_direction, ty1 => Mat4<f64>, ty2 => Vec3<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_transform_vector3_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide transform vector3");
+    let mut group = check::benchmark_group(c, "wide transform vector3");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -56,7 +92,7 @@ fn bench_transform_vector3_wide(c: &mut Criterion) {
 }
 
 fn bench_transform_point3(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar transform point3");
+    let mut group = check::benchmark_group(c, "scalar transform point3");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -83,18 +119,44 @@ fn bench_transform_point3(c: &mut Criterion) {
             use vek::{Mat4, Vec3};
             bench_binop!(b, size, op => mul_point, ty1 => Mat4<f32>, ty2 => Vec3<f32>)
         });
-        bench!("pathfinder_geometry" as "pathfinder", group, size, |b, size| {
-            // pathfinder doesn't have a point3 or vector3 type
-            use pathfinder_geometry::{transform3d::Transform4F, vector::Vector4F};
-            use std::ops::Mul;
-            bench_binop!(b, size, op => mul, ty1 => Transform4F, ty2 => Vector4F)
+    }
+    group.finish();
+}
+
+fn bench_transform_point3_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 transform point3");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DAffine3, DVec3};
+            bench_binop!(b, size, op => transform_point3, ty1 => DAffine3, ty2 => DVec3)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat4, DVec3};
+            bench_binop!(b, size, op => transform_point3, ty1 => DMat4, ty2 => DVec3)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Matrix4, Point3, Transform};
+            bench_binop!(b, size, op => transform_point, ty1 => Matrix4<f64>, ty2 => Point3<f64>)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Point3, Transform3};
+            bench_binop!(b, size, op => transform_point, ty1 => Transform3<f64>, ty2 => Point3<f64>, param => by_ref)
+        });
+        bench!("euclid", group, size, |b, size| {
+            use euclid::{Point3D, Transform3D, UnknownUnit};
+            bench_binop!(b, size, op => transform_point3d, ty1 => Transform3D<f64, UnknownUnit, UnknownUnit>, ty2 => Point3D<f64, UnknownUnit>)
+        });
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat4, Vec3};
+            bench_binop!(b, size, op => mul_point, ty1 => Mat4<f64>, ty2 => Vec3<f64>)
         });
     }
     group.finish();
 }
 
 fn bench_transform_point3_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide transform point3");
+    let mut group = check::benchmark_group(c, "wide transform point3");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -114,7 +176,7 @@ fn bench_transform_point3_wide(c: &mut Criterion) {
 }
 
 fn bench_transform_point2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar transform point2");
+    let mut group = check::benchmark_group(c, "scalar transform point2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -151,8 +213,40 @@ fn bench_transform_point2(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform_point2_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 transform point2");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DAffine2, DVec2};
+            bench_binop!(b, size, op => transform_point2, ty1 => DAffine2, ty2 => DVec2)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat3, DVec2};
+            bench_binop!(b, size, op => transform_point2, ty1 => DMat3, ty2 => DVec2)
+        });
+        bench!("cgmath", group, size, |b, size| {
+            use cgmath::{Matrix3, Point2, Transform};
+            bench_binop!(b, size, op => transform_point, ty1 => Matrix3<f64>, ty2 => Point2<f64>)
+        });
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Point2, Transform2};
+            bench_binop!(b, size, op => transform_point, ty1 => Transform2<f64>, ty2 => Point2<f64>, param => by_ref)
+        });
+        bench!("euclid", group, size, |b, size| {
+            use euclid::{Point2D, Transform2D, UnknownUnit};
+            bench_binop!(b, size, op => transform_point, ty1 => Transform2D<f64, UnknownUnit, UnknownUnit>, ty2 => Point2D<f64, UnknownUnit>)
+        });
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat3, Vec2};
+            bench_binop!(b, size, op => mul_point_2d, ty1 => Mat3<f64>, ty2 => Vec2<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_transform_point2_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide transform point2");
+    let mut group = check::benchmark_group(c, "wide transform point2");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -172,7 +266,7 @@ fn bench_transform_point2_wide(c: &mut Criterion) {
 }
 
 fn bench_transform_vector2(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar transform vector2");
+    let mut group = check::benchmark_group(c, "scalar transform vector2");
     for size in [1, 100].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam", group, size, |b, size| {
@@ -201,8 +295,38 @@ fn bench_transform_vector2(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_transform_vector2_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 transform vector2");
+    for size in [1, 100].iter() {
+        group.throughput(criterion::Throughput::Elements(*size as u64));
+        bench!("glam", group, size, |b, size| {
+            use glam::{DAffine2, DVec2};
+            bench_binop!(b, size, op => transform_vector2, ty1 => DAffine2, ty2 => DVec2)
+        });
+        bench!("ultraviolet", group, size, |b, size| {
+            use ultraviolet::{DMat3, DVec2};
+            bench_binop!(b, size, op => transform_vec2, ty1 => DMat3, ty2 => DVec2)
+        });
+        // cgmath's transform_vector for Matrix3/Vector2 doesn't compile here;
+        // would need a macro change to handle the return type
+        bench!("nalgebra", group, size, |b, size| {
+            use nalgebra::{Transform2, Vector2};
+            bench_binop!(b, size, op => transform_vector, ty1 => Transform2<f64>, ty2 => Vector2<f64>, param => by_ref)
+        });
+        bench!("euclid", group, size, |b, size| {
+            use euclid::{Transform2D, UnknownUnit, Vector2D};
+            bench_binop!(b, size, op => transform_vector, ty1 => Transform2D<f64, UnknownUnit, UnknownUnit>, ty2 => Vector2D<f64, UnknownUnit>)
+        });
+        bench!("vek", group, size, |b, size| {
+            use vek::{Mat3, Vec2};
+            bench_binop!(b, size, op => mul_direction_2d, ty1 => Mat3<f64>, ty2 => Vec2<f64>)
+        });
+    }
+    group.finish();
+}
+
 fn bench_transform_vector2_wide(c: &mut Criterion) {
-    let mut group = c.benchmark_group("wide transform vector2");
+    let mut group = check::benchmark_group(c, "wide transform vector2");
     for size in [16, 256].iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
         bench!("glam_f32x1", group, size, |b, size| {
@@ -224,12 +348,16 @@ fn bench_transform_vector2_wide(c: &mut Criterion) {
 criterion_group!(
     transformation_benches,
     bench_transform_vector2,
+    bench_transform_vector2_f64,
     bench_transform_vector2_wide,
     bench_transform_point2,
+    bench_transform_point2_f64,
     bench_transform_point2_wide,
     bench_transform_vector3,
+    bench_transform_vector3_f64,
     bench_transform_vector3_wide,
     bench_transform_point3,
+    bench_transform_point3_f64,
     bench_transform_point3_wide,
 );
-criterion_main!(transformation_benches);
+criterion_main!(environment::record, transformation_benches);
@@ -1,3 +1,7 @@
+#[path = "support/check.rs"]
+mod check;
+#[path = "support/environment.rs"]
+mod environment;
 #[path = "support/macros.rs"]
 #[macro_use]
 mod macros;
@@ -7,7 +11,7 @@ use mathbench::BenchValue;
 
 // returns self to check overhead of benchmark
 fn bench_vector3_ret_self(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar vector3 return self");
+    let mut group = check::benchmark_group(c, "scalar vector3 return self");
     bench!("glam", group, |b| {
         use glam::Vec3A;
         bench_unop!(b, op => ret_self, ty => Vec3A)
@@ -34,7 +38,7 @@ fn bench_vector3_ret_self(c: &mut Criterion) {
 
 fn bench_vector3_ret_self_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide vector3 return self");
+    let mut group = check::benchmark_group(c, "wide vector3 return self");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Vec3A;
@@ -58,11 +62,50 @@ fn bench_vector3_ret_self_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Vector3<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => ret_self, ty => Vector3<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_vector3_ret_self_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 vector3 return self");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DVec3;
+        bench_unop_wide!(b, size, width => 1, op => ret_self, ty => DVec3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DVec3x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => DVec3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => ret_self, ty => Vector3<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DVec3x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => DVec3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => ret_self, ty => Vector3<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => ret_self, ty => Vector3<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_vector3_length(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar vector3 length");
+    let mut group = check::benchmark_group(c, "scalar vector3 length");
     bench!("glam", group, |b| {
         use glam::Vec3;
         bench_unop!(b, op => length, ty => Vec3)
@@ -91,9 +134,39 @@ fn bench_vector3_length(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_vector3_length_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 vector3 length");
+    bench!("glam", group, |b| {
+        use glam::DVec3;
+        bench_unop!(b, op => length, ty => DVec3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{InnerSpace, Vector3};
+        bench_unop!(b, op => magnitude, ty => Vector3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DVec3;
+        bench_unop!(b, op => mag, ty => DVec3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Vector3;
+        bench_unop!(b, op => magnitude, ty => Vector3<f64>)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_unop!(b, op => length, ty => Vector3D<f64, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Vec3;
+        bench_unop!(b, op => magnitude, ty => Vec3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_vector3_length_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide vector3 length");
+    let mut group = check::benchmark_group(c, "wide vector3 length");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Vec3A;
@@ -117,11 +190,50 @@ fn bench_vector3_length_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => norm, ty => Vector3<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => norm, ty => Vector3<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_vector3_length_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 vector3 length");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DVec3;
+        bench_unop_wide!(b, size, width => 1, op => length, ty => DVec3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DVec3x2;
+        bench_unop_wide!(b, size, width => 2, op => mag, ty => DVec3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => norm, ty => Vector3<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DVec3x4;
+        bench_unop_wide!(b, size, width => 4, op => mag, ty => DVec3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => norm, ty => Vector3<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => norm, ty => Vector3<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_vector3_normalize(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar vector3 normalize");
+    let mut group = check::benchmark_group(c, "scalar vector3 normalize");
     bench!("glam", group, |b| {
         use glam::Vec3A;
         bench_unop!(b, op => normalize, ty => Vec3A)
@@ -149,9 +261,38 @@ fn bench_vector3_normalize(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_vector3_normalize_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 vector3 normalize");
+    bench!("glam", group, |b| {
+        use glam::DVec3;
+        bench_unop!(b, op => normalize, ty => DVec3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{InnerSpace, Vector3};
+        bench_unop!(b, op => normalize, ty => Vector3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DVec3;
+        bench_unop!(b, op => normalized, ty => DVec3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Vector3;
+        bench_unop!(b, op => normalize, ty => Vector3<f64>)
+    });
+    bench!("euclid", group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_unop!(b, op => normalize, ty => Vector3D<f64, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Vec3;
+        bench_unop!(b, op => normalized, ty => Vec3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_vector3_normalize_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide vector3 normalize");
+    let mut group = check::benchmark_group(c, "wide vector3 normalize");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Vec3A;
@@ -175,11 +316,50 @@ fn bench_vector3_normalize_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_unop_wide!(b, size, width => 8, op => normalize, ty => Vector3<f32x8>)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f32x16;
+        bench_unop_wide!(b, size, width => 16, op => normalize, ty => Vector3<f32x16>)
+    });
+    group.finish();
+}
+
+fn bench_vector3_normalize_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 vector3 normalize");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DVec3;
+        bench_unop_wide!(b, size, width => 1, op => normalize, ty => DVec3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DVec3x2;
+        bench_unop_wide!(b, size, width => 2, op => normalized, ty => DVec3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x2;
+        bench_unop_wide!(b, size, width => 2, op => normalize, ty => Vector3<f64x2>)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DVec3x4;
+        bench_unop_wide!(b, size, width => 4, op => normalized, ty => DVec3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x4;
+        bench_unop_wide!(b, size, width => 4, op => normalize, ty => Vector3<f64x4>)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x8;
+        bench_unop_wide!(b, size, width => 8, op => normalize, ty => Vector3<f64x8>)
+    });
     group.finish();
 }
 
 fn bench_vector3_dot(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar vector3 dot");
+    let mut group = check::benchmark_group(c, "scalar vector3 dot");
     bench!("glam", group, |b| {
         use glam::Vec3A;
         bench_binop!(b, op => dot, ty1 => Vec3A, ty2 => Vec3A)
@@ -208,9 +388,39 @@ fn bench_vector3_dot(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_vector3_dot_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 vector3 dot");
+    bench!("glam", group, |b| {
+        use glam::DVec3;
+        bench_binop!(b, op => dot, ty1 => DVec3, ty2 => DVec3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::{InnerSpace, Vector3};
+        bench_binop!(b, op => dot, ty1 => Vector3<f64>, ty2 => Vector3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DVec3;
+        bench_binop!(b, op => dot, ty1 => DVec3, ty2 => DVec3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Vector3;
+        bench_binop!(b, op => dot, ty1 => Vector3<f64>, ty2 => Vector3<f64>, param => by_ref)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_binop!(b, op => dot, ty1 => Vector3D<f64, UnknownUnit>, ty2 => Vector3D<f64, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Vec3;
+        bench_binop!(b, op => dot, ty1 => Vec3<f64>, ty2 => Vec3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_vector3_dot_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide vector3 dot");
+    let mut group = check::benchmark_group(c, "wide vector3 dot");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Vec3A;
@@ -234,11 +444,50 @@ fn bench_vector3_dot_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_binop_wide!(b, size, width => 8, op => dot, ty1 => Vector3<f32x8>, ty2 => Vector3<f32x8>, param => by_ref)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f32x16;
+        bench_binop_wide!(b, size, width => 16, op => dot, ty1 => Vector3<f32x16>, ty2 => Vector3<f32x16>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_vector3_dot_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 vector3 dot");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DVec3;
+        bench_binop_wide!(b, size, width => 1, op => dot, ty1 => DVec3, ty2 => DVec3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DVec3x2;
+        bench_binop_wide!(b, size, width => 2, op => dot, ty1 => DVec3x2, ty2 => DVec3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x2;
+        bench_binop_wide!(b, size, width => 2, op => dot, ty1 => Vector3<f64x2>, ty2 => Vector3<f64x2>, param => by_ref)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DVec3x4;
+        bench_binop_wide!(b, size, width => 4, op => dot, ty1 => DVec3x4, ty2 => DVec3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x4;
+        bench_binop_wide!(b, size, width => 4, op => dot, ty1 => Vector3<f64x4>, ty2 => Vector3<f64x4>, param => by_ref)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x8;
+        bench_binop_wide!(b, size, width => 8, op => dot, ty1 => Vector3<f64x8>, ty2 => Vector3<f64x8>, param => by_ref)
+    });
     group.finish();
 }
 
 fn bench_vector3_cross(c: &mut Criterion) {
-    let mut group = c.benchmark_group("scalar vector3 cross");
+    let mut group = check::benchmark_group(c, "scalar vector3 cross");
     bench!("glam", group, |b| {
         use glam::Vec3A;
         bench_binop!(b, op => cross, ty1 => Vec3A, ty2 => Vec3A)
@@ -267,9 +516,39 @@ fn bench_vector3_cross(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_vector3_cross_f64(c: &mut Criterion) {
+    let mut group = check::benchmark_group(c, "scalar-f64 vector3 cross");
+    bench!("glam", group, |b| {
+        use glam::DVec3;
+        bench_binop!(b, op => cross, ty1 => DVec3, ty2 => DVec3)
+    });
+    bench!("cgmath", group, |b| {
+        use cgmath::Vector3;
+        bench_binop!(b, op => cross, ty1 => Vector3<f64>, ty2 => Vector3<f64>)
+    });
+    bench!("ultraviolet", group, |b| {
+        use ultraviolet::DVec3;
+        bench_binop!(b, op => cross, ty1 => DVec3, ty2 => DVec3)
+    });
+    bench!("nalgebra", group, |b| {
+        use nalgebra::Vector3;
+        bench_binop!(b, op => cross, ty1 => Vector3<f64>, ty2 => Vector3<f64>, param => by_ref)
+    });
+
+    bench!("euclid", group, |b| {
+        use euclid::{UnknownUnit, Vector3D};
+        bench_binop!(b, op => cross, ty1 => Vector3D<f64, UnknownUnit>, ty2 => Vector3D<f64, UnknownUnit>)
+    });
+    bench!("vek", group, |b| {
+        use vek::Vec3;
+        bench_binop!(b, op => cross, ty1 => Vec3<f64>, ty2 => Vec3<f64>)
+    });
+    group.finish();
+}
+
 fn bench_vector3_cross_wide(c: &mut Criterion) {
     let size = &MIN_WIDE_BENCH_SIZE;
-    let mut group = c.benchmark_group("wide vector3 cross");
+    let mut group = check::benchmark_group(c, "wide vector3 cross");
     group.throughput(criterion::Throughput::Elements(*size));
     bench!("glam_f32x1", group, size, |b, size| {
         use glam::Vec3A;
@@ -293,6 +572,45 @@ fn bench_vector3_cross_wide(c: &mut Criterion) {
         use simba::simd::f32x8;
         bench_binop_wide!(b, size, width => 8, op => cross, ty1 => Vector3<f32x8>, ty2 => Vector3<f32x8>, param => by_ref)
     });
+    bench!("nalgebra_f32x16", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f32x16;
+        bench_binop_wide!(b, size, width => 16, op => cross, ty1 => Vector3<f32x16>, ty2 => Vector3<f32x16>, param => by_ref)
+    });
+    group.finish();
+}
+
+fn bench_vector3_cross_wide_f64(c: &mut Criterion) {
+    let size = &MIN_WIDE_BENCH_SIZE;
+    let mut group = check::benchmark_group(c, "wide f64 vector3 cross");
+    group.throughput(criterion::Throughput::Elements(*size));
+    bench!("glam_f64x1", group, size, |b, size| {
+        use glam::DVec3;
+        bench_binop_wide!(b, size, width => 1, op => cross, ty1 => DVec3, ty2 => DVec3)
+    });
+    bench!("ultraviolet_f64x2", group, size, |b, size| {
+        use ultraviolet::DVec3x2;
+        bench_binop_wide!(b, size, width => 2, op => cross, ty1 => DVec3x2, ty2 => DVec3x2)
+    });
+    bench!("nalgebra_f64x2", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x2;
+        bench_binop_wide!(b, size, width => 2, op => cross, ty1 => Vector3<f64x2>, ty2 => Vector3<f64x2>, param => by_ref)
+    });
+    bench!("ultraviolet_f64x4", group, size, |b, size| {
+        use ultraviolet::DVec3x4;
+        bench_binop_wide!(b, size, width => 4, op => cross, ty1 => DVec3x4, ty2 => DVec3x4)
+    });
+    bench!("nalgebra_f64x4", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x4;
+        bench_binop_wide!(b, size, width => 4, op => cross, ty1 => Vector3<f64x4>, ty2 => Vector3<f64x4>, param => by_ref)
+    });
+    bench!("nalgebra_f64x8", group, size, |b, size| {
+        use nalgebra::Vector3;
+        use simba::simd::f64x8;
+        bench_binop_wide!(b, size, width => 8, op => cross, ty1 => Vector3<f64x8>, ty2 => Vector3<f64x8>, param => by_ref)
+    });
     group.finish();
 }
 
@@ -300,13 +618,22 @@ criterion_group!(
     vector3_benches,
     bench_vector3_ret_self,
     bench_vector3_ret_self_wide,
+    bench_vector3_ret_self_wide_f64,
     bench_vector3_length,
+    bench_vector3_length_f64,
     bench_vector3_length_wide,
+    bench_vector3_length_wide_f64,
     bench_vector3_normalize,
+    bench_vector3_normalize_f64,
     bench_vector3_normalize_wide,
+    bench_vector3_normalize_wide_f64,
     bench_vector3_dot,
+    bench_vector3_dot_f64,
     bench_vector3_dot_wide,
+    bench_vector3_dot_wide_f64,
     bench_vector3_cross,
+    bench_vector3_cross_f64,
     bench_vector3_cross_wide,
+    bench_vector3_cross_wide_f64,
 );
-criterion_main!(vector3_benches);
+criterion_main!(environment::record, vector3_benches);
@@ -0,0 +1,100 @@
+//! Captures the toolchain and build settings at compile time so benchmark runs
+//! can record the environment they were built in, see
+//! `benches/support/environment.rs`.
+
+use std::{env, fs, process::Command};
+
+/// Returns the versions of `mathbench`'s direct dependencies from `Cargo.lock`
+/// as `name=version` pairs.
+fn crate_versions(lockfile: &str) -> Vec<String> {
+    // parse the `[[package]]` entries of the lock file as (name, version, dependencies)
+    let mut packages: Vec<(String, String, Vec<String>)> = Vec::new();
+    let mut in_dependencies = false;
+    for line in lockfile.lines() {
+        let line = line.trim();
+        if line == "[[package]]" {
+            packages.push(Default::default());
+            in_dependencies = false;
+        } else if let Some(package) = packages.last_mut() {
+            if in_dependencies {
+                if line == "]" {
+                    in_dependencies = false;
+                } else {
+                    package
+                        .2
+                        .push(line.trim_matches(|c| c == '"' || c == ',').to_string());
+                }
+            } else if let Some(name) = line.strip_prefix("name = ") {
+                package.0 = name.trim_matches('"').to_string();
+            } else if let Some(version) = line.strip_prefix("version = ") {
+                package.1 = version.trim_matches('"').to_string();
+            } else if line == "dependencies = [" {
+                in_dependencies = true;
+            }
+        }
+    }
+
+    let mathbench = match packages.iter().find(|(name, _, _)| name == "mathbench") {
+        Some(mathbench) => mathbench,
+        None => return Vec::new(),
+    };
+    mathbench
+        .2
+        .iter()
+        .filter_map(|dependency| {
+            // the version is only included when the lock file has several versions of a crate
+            let mut parts = dependency.split(' ');
+            let name = parts.next()?;
+            let version = match parts.next() {
+                Some(version) => version,
+                None => &packages.iter().find(|(n, _, _)| n == name)?.1,
+            };
+            Some(format!("{}={}", name, version))
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = Command::new(rustc)
+        .arg("-vV")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|stdout| stdout.lines().next().map(|line| line.to_string()))
+        .unwrap_or_default();
+
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .map(|feature| feature.to_lowercase())
+        })
+        .collect();
+    features.sort();
+
+    let crates = fs::read_to_string("Cargo.lock")
+        .map(|lockfile| crate_versions(&lockfile))
+        .unwrap_or_default();
+
+    let var = |key: &str| env::var(key).unwrap_or_default();
+    let vars = [
+        ("MATHBENCH_RUSTC_VERSION", rustc_version),
+        ("MATHBENCH_TARGET", var("TARGET")),
+        ("MATHBENCH_TARGET_FEATURES", var("CARGO_CFG_TARGET_FEATURE")),
+        (
+            "MATHBENCH_RUSTFLAGS",
+            var("CARGO_ENCODED_RUSTFLAGS").replace('\x1f', " "),
+        ),
+        ("MATHBENCH_PROFILE", var("PROFILE")),
+        ("MATHBENCH_OPT_LEVEL", var("OPT_LEVEL")),
+        ("MATHBENCH_DEBUG", var("DEBUG")),
+        ("MATHBENCH_FEATURES", features.join(",")),
+        ("MATHBENCH_CRATES", crates.join(",")),
+    ];
+    for (key, value) in &vars {
+        println!("cargo:rustc-env={}={}", key, value);
+    }
+}
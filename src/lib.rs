@@ -1,7 +1,162 @@
-use rand::RngExt;
+use rand::{RngExt, SeedableRng};
+
+/// The seed used for benchmark inputs when `MATHBENCH_SEED` isn't set.
+pub const DEFAULT_SEED: u64 = 0x6d61_7468_6265_6e63;
+
+/// Returns the seed for benchmark inputs, read from the `MATHBENCH_SEED`
+/// environment variable or `DEFAULT_SEED` if it isn't set.
+///
+/// # Panics
+///
+/// Panics if `MATHBENCH_SEED` isn't a `u64`.
+pub fn seed() -> u64 {
+    match std::env::var("MATHBENCH_SEED") {
+        Ok(seed) => seed
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("MATHBENCH_SEED must be a u64, found \"{}\"", seed)),
+        Err(_) => DEFAULT_SEED,
+    }
+}
+
+/// The distribution benchmark inputs are drawn from. Some operations, for
+/// example matrix inverses that pivot, perform differently depending on the
+/// input data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputDistribution {
+    /// Components in `[0, 1)`.
+    Uniform,
+    /// Components up to `1e5` in magnitude, like world space coordinates, and
+    /// scales spanning several orders of magnitude.
+    WideRange,
+    /// Nearly singular matrices and vectors with components of very different
+    /// magnitudes.
+    IllConditioned,
+    /// Matrices and rotations that are small perturbations of the identity.
+    NearIdentity,
+    /// Roughly half of all components are subnormal.
+    Subnormal,
+}
+
+impl InputDistribution {
+    pub const ALL: [InputDistribution; 5] = [
+        InputDistribution::Uniform,
+        InputDistribution::WideRange,
+        InputDistribution::IllConditioned,
+        InputDistribution::NearIdentity,
+        InputDistribution::Subnormal,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InputDistribution::Uniform => "uniform",
+            InputDistribution::WideRange => "wide-range",
+            InputDistribution::IllConditioned => "ill-conditioned",
+            InputDistribution::NearIdentity => "near-identity",
+            InputDistribution::Subnormal => "subnormal",
+        }
+    }
+}
+
+impl std::convert::TryFrom<&str> for InputDistribution {
+    type Error = String;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        InputDistribution::ALL
+            .iter()
+            .copied()
+            .find(|distribution| distribution.as_str() == value)
+            .ok_or_else(|| format!("unknown input distribution \"{}\"", value))
+    }
+}
+
+/// Returns the distribution of benchmark inputs, read from the
+/// `MATHBENCH_INPUTS` environment variable or `InputDistribution::Uniform` if
+/// it isn't set.
+///
+/// # Panics
+///
+/// Panics if `MATHBENCH_INPUTS` isn't one of the `InputDistribution` names.
+pub fn input_distribution() -> InputDistribution {
+    use std::convert::TryFrom;
+    match std::env::var("MATHBENCH_INPUTS") {
+        Ok(inputs) => InputDistribution::try_from(inputs.trim()).unwrap_or_else(|e| {
+            let names: Vec<&str> = InputDistribution::ALL.iter().map(|d| d.as_str()).collect();
+            panic!(
+                "MATHBENCH_INPUTS: {}, expected one of {}",
+                e,
+                names.join(", ")
+            )
+        }),
+        Err(_) => InputDistribution::Uniform,
+    }
+}
+
+/// A random number generator for benchmark inputs, which also determines the
+/// distribution `BenchValue`s are drawn from. Plain generators draw uniform
+/// inputs.
+pub trait BenchRng: RngExt {
+    fn distribution(&self) -> InputDistribution {
+        InputDistribution::Uniform
+    }
+}
+
+impl BenchRng for rand_pcg::Pcg64Mcg {}
+
+/// The random number generator returned by `rng`, drawing inputs from a chosen
+/// `InputDistribution`.
+#[derive(Clone, Debug)]
+pub struct InputRng {
+    rng: rand_pcg::Pcg64Mcg,
+    distribution: InputDistribution,
+}
+
+impl rand::TryRng for InputRng {
+    type Error = std::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.rng.try_next_u32()
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.rng.try_next_u64()
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        self.rng.try_fill_bytes(dst)
+    }
+}
+
+impl BenchRng for InputRng {
+    fn distribution(&self) -> InputDistribution {
+        self.distribution
+    }
+}
+
+/// Returns a random number generator for the input stream `stream`, derived
+/// from `seed()` and drawing from `input_distribution()`. Benchmarks of every
+/// library that use the same stream name and input types get identical input
+/// values.
+pub fn rng(stream: &str) -> InputRng {
+    rng_with(stream, input_distribution())
+}
+
+/// Returns a random number generator for the input stream `stream` that draws
+/// inputs from `distribution`.
+pub fn rng_with(stream: &str, distribution: InputDistribution) -> InputRng {
+    // FNV-1a, as the std hashers aren't guaranteed to be stable between releases
+    let hash = stream
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    InputRng {
+        rng: rand_pcg::Pcg64Mcg::seed_from_u64(seed() ^ hash),
+        distribution,
+    }
+}
 
 pub trait BenchValue {
-    fn random_value<R: RngExt>(rng: &mut R) -> Self;
+    fn random_value<R: BenchRng>(rng: &mut R) -> Self;
     // Return self to test overhead of benches
     fn ret_self(&self) -> Self
     where
@@ -12,124 +167,434 @@ pub trait BenchValue {
 }
 
 impl BenchValue for f32 {
-    fn random_value<R: RngExt>(rng: &mut R) -> Self {
-        rng.random()
+    fn random_value<R: BenchRng>(rng: &mut R) -> Self {
+        <f32 as mint_support::BenchScalar>::random(rng)
     }
 }
 
 macro_rules! impl_bench_value {
     ($t:ty, $f:expr) => {
         impl BenchValue for $t {
-            fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+            fn random_value<R: $crate::BenchRng>(rng: &mut R) -> Self {
                 $f(rng).into()
             }
         }
     };
 }
 
+/// Flattens a benchmark value to its components so that the values computed by
+/// different libraries can be compared. Vectors and points are `x, y, z, w`,
+/// quaternions and rotors are `x, y, z, w` and matrices and transforms are the
+/// equivalent column vector matrix in column major order. A value that reports
+/// there is no result, for example `None` from a singular matrix inverse, has
+/// no components.
+pub trait Components {
+    fn components(&self) -> Vec<f64>;
+}
+
+impl Components for f32 {
+    fn components(&self) -> Vec<f64> {
+        vec![f64::from(*self)]
+    }
+}
+
+impl Components for f64 {
+    fn components(&self) -> Vec<f64> {
+        vec![*self]
+    }
+}
+
+impl<T: Components> Components for Option<T> {
+    fn components(&self) -> Vec<f64> {
+        self.as_ref().map_or_else(Vec::new, T::components)
+    }
+}
+
+macro_rules! impl_mint_components {
+    ($($mint:ident, $n:literal);+) => {
+        $(impl<T: Copy + Into<f64>> Components for mint::$mint<T> {
+            fn components(&self) -> Vec<f64> {
+                <[T; $n]>::from(*self).iter().map(|&c| c.into()).collect()
+            }
+        })+
+    };
+}
+
+impl_mint_components!(
+    Vector2, 2; Vector3, 3; Vector4, 4; Point2, 2; Point3, 3; Quaternion, 4;
+    ColumnMatrix2, 4; ColumnMatrix3, 9; ColumnMatrix4, 16
+);
+
+// implements `Components` by converting to the equivalent mint type
+macro_rules! impl_components {
+    ($mint:ty => $($t:ty),+) => {
+        $(impl $crate::Components for $t {
+            fn components(&self) -> Vec<f64> {
+                let mint: $mint = (*self).into();
+                $crate::Components::components(&mint)
+            }
+        })+
+    };
+}
+
+/// Builds a benchmark value from the components `Components` returns, so an
+/// operation can be run on the inputs of another library. Wide values have the
+/// components of each lane in turn.
+pub trait FromComponents: Components {
+    /// The number of components.
+    const COMPONENTS: usize;
+
+    fn from_components(components: &[f64]) -> Self;
+}
+
+impl FromComponents for f32 {
+    const COMPONENTS: usize = 1;
+
+    fn from_components(components: &[f64]) -> Self {
+        components[0] as f32
+    }
+}
+
+impl FromComponents for f64 {
+    const COMPONENTS: usize = 1;
+
+    fn from_components(components: &[f64]) -> Self {
+        components[0]
+    }
+}
+
+// implements `FromComponents` by converting from the equivalent mint type
+macro_rules! impl_from_components {
+    ($mint:ident<$s:ty>, $n:literal => $($t:ty),+) => {
+        $(impl $crate::FromComponents for $t {
+            const COMPONENTS: usize = $n;
+
+            fn from_components(components: &[f64]) -> Self {
+                let array: [$s; $n] = std::array::from_fn(|i| components[i] as $s);
+                mint::$mint::from(array).into()
+            }
+        })+
+    };
+}
+
+// implements `Components` for wide values, which have the components of each
+// lane in turn
+#[allow(unused_macros)]
+macro_rules! impl_wide_components {
+    ($($t:ty => $lanes:expr),+) => {
+        $(impl $crate::Components for $t {
+            fn components(&self) -> Vec<f64> {
+                let lanes = $lanes;
+                lanes(self).iter().flat_map($crate::Components::components).collect()
+            }
+        })+
+    };
+}
+
 pub mod mint_support {
-    use super::glam_support::*;
-    use rand::RngExt;
+    use super::{BenchRng, InputDistribution};
+
+    /// A scalar type that benchmark inputs can be generated for, used to make
+    /// the mint random functions generic over `f32` and `f64`. Values are drawn
+    /// from the `InputDistribution` of the random number generator.
+    pub trait BenchScalar: Copy {
+        fn random<R: BenchRng>(rng: &mut R) -> Self;
+        fn random_nonzero<R: BenchRng>(rng: &mut R) -> Self;
+        fn random_angle<R: BenchRng>(rng: &mut R) -> Self;
+        fn random_quat<R: BenchRng>(rng: &mut R) -> mint::Quaternion<Self>;
+        fn random_mat2<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix2<Self>;
+        fn random_mat3<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix3<Self>;
+        fn random_mat4<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix4<Self>;
+        fn random_invertible_mat2<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix2<Self>;
+        fn random_homogeneous_mat3<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix3<Self>;
+        fn random_homogeneous_mat4<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix4<Self>;
+    }
+
+    // the largest perturbation of `InputDistribution::NearIdentity` inputs
+    const NEAR_IDENTITY: f32 = 1e-3;
+
+    // glam is used to construct rotations and invertible matrices of either precision
+    macro_rules! impl_bench_scalar {
+        ($t:ident, $vec2:ty, $vec3:ty, $vec4:ty, $quat:ty, $mat2:ty, $mat3:ty, $mat4:ty) => {
+            impl BenchScalar for $t {
+                fn random<R: BenchRng>(rng: &mut R) -> Self {
+                    match rng.distribution() {
+                        InputDistribution::Uniform => rng.random(),
+                        InputDistribution::WideRange => rng.random_range(-1e5..1e5),
+                        InputDistribution::IllConditioned => {
+                            let magnitude = <$t>::powf(10.0, rng.random_range(-6.0..6.0));
+                            if rng.random() {
+                                magnitude
+                            } else {
+                                -magnitude
+                            }
+                        }
+                        InputDistribution::NearIdentity => {
+                            let max = NEAR_IDENTITY as $t;
+                            rng.random_range(-max..max)
+                        }
+                        InputDistribution::Subnormal => {
+                            let value: $t = rng.random();
+                            if rng.random() {
+                                value * $t::MIN_POSITIVE
+                            } else {
+                                value
+                            }
+                        }
+                    }
+                }
+
+                fn random_nonzero<R: BenchRng>(rng: &mut R) -> Self {
+                    match rng.distribution() {
+                        InputDistribution::WideRange => {
+                            <$t>::powf(10.0, rng.random_range(-3.0..3.0))
+                        }
+                        InputDistribution::NearIdentity => {
+                            let max = NEAR_IDENTITY as $t;
+                            1.0 + rng.random_range(-max..max)
+                        }
+                        _ => rng.random_range(0.1..1.0),
+                    }
+                }
+
+                fn random_angle<R: BenchRng>(rng: &mut R) -> Self {
+                    match rng.distribution() {
+                        InputDistribution::NearIdentity => {
+                            let max = NEAR_IDENTITY as $t;
+                            rng.random_range(-max..max)
+                        }
+                        _ => rng.random_range(-std::$t::consts::PI..std::$t::consts::PI),
+                    }
+                }
+
+                fn random_quat<R: BenchRng>(rng: &mut R) -> mint::Quaternion<Self> {
+                    let yaw = Self::random_angle(rng);
+                    let pitch = Self::random_angle(rng);
+                    let roll = Self::random_angle(rng);
+                    <$quat>::from_euler(glam::EulerRot::YXZ, yaw, pitch, roll).into()
+                }
+
+                fn random_mat2<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix2<Self> {
+                    match rng.distribution() {
+                        InputDistribution::IllConditioned => {
+                            // nearly parallel columns, losing about half the precision
+                            let x = <$vec2>::from(rng.random::<[$t; 2]>());
+                            let y = <$vec2>::from(rng.random::<[$t; 2]>());
+                            <$mat2>::from_cols(x, x + y * $t::EPSILON.sqrt()).into()
+                        }
+                        InputDistribution::NearIdentity => {
+                            let m = <$mat2>::from_cols_array(&random_array(rng));
+                            (<$mat2>::IDENTITY + m).into()
+                        }
+                        _ => <$mat2>::from_cols_array(&random_array(rng)).into(),
+                    }
+                }
+
+                fn random_mat3<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix3<Self> {
+                    match rng.distribution() {
+                        InputDistribution::IllConditioned => {
+                            let x = <$vec3>::from(rng.random::<[$t; 3]>());
+                            let y = <$vec3>::from(rng.random::<[$t; 3]>());
+                            let z = <$vec3>::from(rng.random::<[$t; 3]>());
+                            <$mat3>::from_cols(x, y, x + z * $t::EPSILON.sqrt()).into()
+                        }
+                        InputDistribution::NearIdentity => {
+                            let m = <$mat3>::from_cols_array(&random_array(rng));
+                            (<$mat3>::IDENTITY + m).into()
+                        }
+                        _ => <$mat3>::from_cols_array(&random_array(rng)).into(),
+                    }
+                }
+
+                fn random_mat4<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix4<Self> {
+                    match rng.distribution() {
+                        InputDistribution::IllConditioned => {
+                            let x = <$vec4>::from(rng.random::<[$t; 4]>());
+                            let y = <$vec4>::from(rng.random::<[$t; 4]>());
+                            let z = <$vec4>::from(rng.random::<[$t; 4]>());
+                            let w = <$vec4>::from(rng.random::<[$t; 4]>());
+                            <$mat4>::from_cols(x, y, z, x + w * $t::EPSILON.sqrt()).into()
+                        }
+                        InputDistribution::NearIdentity => {
+                            let m = <$mat4>::from_cols_array(&random_array(rng));
+                            (<$mat4>::IDENTITY + m).into()
+                        }
+                        _ => <$mat4>::from_cols_array(&random_array(rng)).into(),
+                    }
+                }
+
+                fn random_invertible_mat2<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix2<Self> {
+                    loop {
+                        let m = <$mat2>::from(Self::random_mat2(rng));
+                        if m.determinant().abs() > $t::EPSILON {
+                            return m.into();
+                        }
+                    }
+                }
+
+                fn random_homogeneous_mat3<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix3<Self> {
+                    loop {
+                        let mut scale =
+                            <$vec2>::new(Self::random_nonzero(rng), Self::random_nonzero(rng));
+                        if rng.distribution() == InputDistribution::IllConditioned {
+                            scale.y *= $t::EPSILON.sqrt();
+                        }
+                        let m = <$mat3>::from_scale_angle_translation(
+                            scale,
+                            Self::random_angle(rng),
+                            <$vec2>::from(random_array(rng)),
+                        );
+                        if m.determinant().abs() > $t::EPSILON {
+                            return m.into();
+                        }
+                    }
+                }
+
+                fn random_homogeneous_mat4<R: BenchRng>(rng: &mut R) -> mint::ColumnMatrix4<Self> {
+                    loop {
+                        let mut scale = <$vec3>::new(
+                            Self::random_nonzero(rng),
+                            Self::random_nonzero(rng),
+                            Self::random_nonzero(rng),
+                        );
+                        if rng.distribution() == InputDistribution::IllConditioned {
+                            scale.z *= $t::EPSILON.sqrt();
+                        }
+                        let m = <$mat4>::from_scale_rotation_translation(
+                            scale,
+                            Self::random_quat(rng).into(),
+                            <$vec3>::from(random_array(rng)),
+                        );
+                        if m.determinant().abs() > $t::EPSILON {
+                            return m.into();
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    impl_bench_scalar!(
+        f32,
+        glam::Vec2,
+        glam::Vec3,
+        glam::Vec4,
+        glam::Quat,
+        glam::Mat2,
+        glam::Mat3,
+        glam::Mat4
+    );
+    impl_bench_scalar!(
+        f64,
+        glam::DVec2,
+        glam::DVec3,
+        glam::DVec4,
+        glam::DQuat,
+        glam::DMat2,
+        glam::DMat3,
+        glam::DMat4
+    );
+
+    /// Returns `N` random scalars, for example the lanes of a wide type.
+    pub fn random_array<T, R, const N: usize>(rng: &mut R) -> [T; N]
+    where
+        T: BenchScalar,
+        R: BenchRng,
+    {
+        std::array::from_fn(|_| T::random(rng))
+    }
 
     // mint random functions  -----------------------------------------------------
-    pub fn random_mint_quat<R>(rng: &mut R) -> mint::Quaternion<f32>
+    pub fn random_mint_quat<T, R>(rng: &mut R) -> mint::Quaternion<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        random_glam_quat(rng).into()
+        T::random_quat(rng)
     }
 
-    pub fn random_mint_vec2<R>(rng: &mut R) -> mint::Vector2<f32>
+    pub fn random_mint_vec2<T, R>(rng: &mut R) -> mint::Vector2<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        rng.random::<[f32; 2]>().into()
+        [T::random(rng), T::random(rng)].into()
     }
 
-    pub fn random_mint_vec3<R>(rng: &mut R) -> mint::Vector3<f32>
+    pub fn random_mint_vec3<T, R>(rng: &mut R) -> mint::Vector3<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        rng.random::<[f32; 3]>().into()
+        [T::random(rng), T::random(rng), T::random(rng)].into()
     }
 
-    pub fn random_mint_vec4<R>(rng: &mut R) -> mint::Vector4<f32>
+    pub fn random_mint_vec4<T, R>(rng: &mut R) -> mint::Vector4<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        rng.random::<[f32; 4]>().into()
+        [
+            T::random(rng),
+            T::random(rng),
+            T::random(rng),
+            T::random(rng),
+        ]
+        .into()
     }
 
-    pub fn random_mint_mat2<R>(rng: &mut R) -> mint::ColumnMatrix2<f32>
+    pub fn random_mint_mat2<T, R>(rng: &mut R) -> mint::ColumnMatrix2<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        rng.random::<[f32; 4]>().into()
+        T::random_mat2(rng)
     }
 
-    pub fn random_mint_mat3<R>(rng: &mut R) -> mint::ColumnMatrix3<f32>
+    pub fn random_mint_mat3<T, R>(rng: &mut R) -> mint::ColumnMatrix3<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        rng.random::<[f32; 9]>().into()
+        T::random_mat3(rng)
     }
 
-    pub fn random_mint_mat4<R>(rng: &mut R) -> mint::ColumnMatrix4<f32>
+    pub fn random_mint_mat4<T, R>(rng: &mut R) -> mint::ColumnMatrix4<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        rng.random::<[f32; 16]>().into()
+        T::random_mat4(rng)
     }
 
-    pub fn random_mint_invertible_mat2<R>(rng: &mut R) -> mint::ColumnMatrix2<f32>
+    pub fn random_mint_invertible_mat2<T, R>(rng: &mut R) -> mint::ColumnMatrix2<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        loop {
-            let m = glam::Mat2::from_cols_array(&rng.random::<[f32; 4]>());
-            if m.determinant().abs() > std::f32::EPSILON {
-                return m.into();
-            }
-        }
+        T::random_invertible_mat2(rng)
     }
 
-    pub fn random_mint_homogeneous_mat3<R>(rng: &mut R) -> mint::ColumnMatrix3<f32>
+    pub fn random_mint_homogeneous_mat3<T, R>(rng: &mut R) -> mint::ColumnMatrix3<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        loop {
-            let m = glam::Mat3::from_scale_angle_translation(
-                random_nonzero_glam_vec2(rng),
-                random_angle_radians(rng),
-                random_glam_vec2(rng),
-            );
-            if m.determinant().abs() > std::f32::EPSILON {
-                return m.into();
-            }
-        }
+        T::random_homogeneous_mat3(rng)
     }
 
-    pub fn random_mint_homogeneous_mat4<R>(rng: &mut R) -> mint::ColumnMatrix4<f32>
+    pub fn random_mint_homogeneous_mat4<T, R>(rng: &mut R) -> mint::ColumnMatrix4<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        loop {
-            let m = glam::Mat4::from_scale_rotation_translation(
-                random_glam_nonzero_vec3(rng),
-                random_glam_quat(rng),
-                random_glam_vec3(rng),
-            );
-            if m.determinant().abs() > std::f32::EPSILON {
-                return m.into();
-            }
-        }
+        T::random_homogeneous_mat4(rng)
     }
 }
 
 pub mod glam_support {
     use super::mint_support::*;
-    use super::BenchValue;
-    use rand::RngExt;
+    use super::{BenchRng, BenchValue, Components, FromComponents};
     impl_bench_value!(glam::Mat2, random_mint_invertible_mat2);
     impl_bench_value!(glam::Mat3, random_mint_homogeneous_mat3);
     impl_bench_value!(glam::Mat4, random_mint_homogeneous_mat4);
@@ -140,47 +605,143 @@ pub mod glam_support {
     impl_bench_value!(glam::Vec4, random_mint_vec4);
     impl_bench_value!(glam::Affine2, random_glam_affine2);
     impl_bench_value!(glam::Affine3A, random_glam_affine3a);
+    impl_bench_value!(glam::DMat2, random_mint_invertible_mat2);
+    impl_bench_value!(glam::DMat3, random_mint_homogeneous_mat3);
+    impl_bench_value!(glam::DMat4, random_mint_homogeneous_mat4);
+    impl_bench_value!(glam::DQuat, random_mint_quat);
+    impl_bench_value!(glam::DVec2, random_mint_vec2);
+    impl_bench_value!(glam::DVec3, random_mint_vec3);
+    impl_bench_value!(glam::DVec4, random_mint_vec4);
+    impl_bench_value!(glam::DAffine2, random_glam_daffine2);
+    impl_bench_value!(glam::DAffine3, random_glam_daffine3);
+
+    impl_components!(mint::Vector2<f32> => glam::Vec2);
+    impl_components!(mint::Vector3<f32> => glam::Vec3, glam::Vec3A);
+    impl_components!(mint::Vector4<f32> => glam::Vec4);
+    impl_components!(mint::Quaternion<f32> => glam::Quat);
+    impl_components!(mint::ColumnMatrix2<f32> => glam::Mat2);
+    impl_components!(mint::ColumnMatrix3<f32> => glam::Mat3);
+    impl_components!(mint::ColumnMatrix4<f32> => glam::Mat4);
+    impl_components!(mint::Vector2<f64> => glam::DVec2);
+    impl_components!(mint::Vector3<f64> => glam::DVec3);
+    impl_components!(mint::Vector4<f64> => glam::DVec4);
+    impl_components!(mint::Quaternion<f64> => glam::DQuat);
+    impl_components!(mint::ColumnMatrix2<f64> => glam::DMat2);
+    impl_components!(mint::ColumnMatrix3<f64> => glam::DMat3);
+    impl_components!(mint::ColumnMatrix4<f64> => glam::DMat4);
+
+    // affine transforms are compared as the equivalent homogeneous matrix
+    impl Components for glam::Affine2 {
+        fn components(&self) -> Vec<f64> {
+            glam::Mat3::from(*self).components()
+        }
+    }
+
+    impl Components for glam::Affine3A {
+        fn components(&self) -> Vec<f64> {
+            glam::Mat4::from(*self).components()
+        }
+    }
+
+    impl Components for glam::DAffine2 {
+        fn components(&self) -> Vec<f64> {
+            glam::DMat3::from(*self).components()
+        }
+    }
+
+    impl Components for glam::DAffine3 {
+        fn components(&self) -> Vec<f64> {
+            glam::DMat4::from(*self).components()
+        }
+    }
+
+    impl_from_components!(Vector2<f32>, 2 => glam::Vec2);
+    impl_from_components!(Vector3<f32>, 3 => glam::Vec3, glam::Vec3A);
+    impl_from_components!(Vector4<f32>, 4 => glam::Vec4);
+    impl_from_components!(Quaternion<f32>, 4 => glam::Quat);
+    impl_from_components!(ColumnMatrix2<f32>, 4 => glam::Mat2);
+    impl_from_components!(ColumnMatrix3<f32>, 9 => glam::Mat3);
+    impl_from_components!(ColumnMatrix4<f32>, 16 => glam::Mat4);
+    impl_from_components!(Vector2<f64>, 2 => glam::DVec2);
+    impl_from_components!(Vector3<f64>, 3 => glam::DVec3);
+    impl_from_components!(Vector4<f64>, 4 => glam::DVec4);
+    impl_from_components!(Quaternion<f64>, 4 => glam::DQuat);
+    impl_from_components!(ColumnMatrix2<f64>, 4 => glam::DMat2);
+    impl_from_components!(ColumnMatrix3<f64>, 9 => glam::DMat3);
+    impl_from_components!(ColumnMatrix4<f64>, 16 => glam::DMat4);
+
+    impl FromComponents for glam::Affine2 {
+        const COMPONENTS: usize = 9;
+
+        fn from_components(components: &[f64]) -> Self {
+            glam::Affine2::from_mat3(glam::Mat3::from_components(components))
+        }
+    }
+
+    impl FromComponents for glam::Affine3A {
+        const COMPONENTS: usize = 16;
+
+        fn from_components(components: &[f64]) -> Self {
+            glam::Affine3A::from_mat4(glam::Mat4::from_components(components))
+        }
+    }
+
+    impl FromComponents for glam::DAffine2 {
+        const COMPONENTS: usize = 9;
+
+        fn from_components(components: &[f64]) -> Self {
+            glam::DAffine2::from_mat3(glam::DMat3::from_components(components))
+        }
+    }
+
+    impl FromComponents for glam::DAffine3 {
+        const COMPONENTS: usize = 16;
+
+        fn from_components(components: &[f64]) -> Self {
+            glam::DAffine3::from_mat4(glam::DMat4::from_components(components))
+        }
+    }
 
     // f32 random functions  ------------------------------------------------------
     fn random_nonzero_f32<R>(rng: &mut R) -> f32
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        rng.random_range(0.1..1.0)
+        f32::random_nonzero(rng)
     }
 
     pub fn random_angle_radians<R>(rng: &mut R) -> f32
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        rng.random_range(-std::f32::consts::PI..std::f32::consts::PI)
+        f32::random_angle(rng)
     }
 
     // glam random functions ------------------------------------------------------
     pub fn random_glam_vec2<R>(rng: &mut R) -> glam::Vec2
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        rng.random::<[f32; 2]>().into()
+        random_array::<f32, _, 2>(rng).into()
     }
 
     pub fn random_glam_vec3<R>(rng: &mut R) -> glam::Vec3
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        rng.random::<[f32; 3]>().into()
+        random_array::<f32, _, 3>(rng).into()
     }
 
     pub fn random_nonzero_glam_vec2<R>(rng: &mut R) -> glam::Vec2
     where
-        R: RngExt,
+        R: BenchRng,
     {
         glam::Vec2::new(random_nonzero_f32(rng), random_nonzero_f32(rng))
     }
 
     pub fn random_glam_nonzero_vec3<R>(rng: &mut R) -> glam::Vec3
     where
-        R: RngExt,
+        R: BenchRng,
     {
         glam::Vec3::new(
             random_nonzero_f32(rng),
@@ -191,7 +752,7 @@ pub mod glam_support {
 
     pub fn random_glam_quat<R>(rng: &mut R) -> glam::Quat
     where
-        R: RngExt,
+        R: BenchRng,
     {
         let yaw = random_angle_radians(rng);
         let pitch = random_angle_radians(rng);
@@ -201,24 +762,30 @@ pub mod glam_support {
 
     pub fn random_glam_affine2<R>(rng: &mut R) -> glam::Affine2
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        glam::Affine2::from_scale_angle_translation(
-            random_nonzero_glam_vec2(rng),
-            random_angle_radians(rng),
-            random_glam_vec2(rng),
-        )
+        glam::Affine2::from_mat3(random_mint_homogeneous_mat3(rng).into())
     }
 
     pub fn random_glam_affine3a<R>(rng: &mut R) -> glam::Affine3A
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        glam::Affine3A::from_scale_rotation_translation(
-            random_glam_nonzero_vec3(rng),
-            random_glam_quat(rng),
-            random_glam_vec3(rng),
-        )
+        glam::Affine3A::from_mat4(random_mint_homogeneous_mat4(rng).into())
+    }
+
+    pub fn random_glam_daffine2<R>(rng: &mut R) -> glam::DAffine2
+    where
+        R: BenchRng,
+    {
+        glam::DAffine2::from_mat3(random_mint_homogeneous_mat3(rng).into())
+    }
+
+    pub fn random_glam_daffine3<R>(rng: &mut R) -> glam::DAffine3
+    where
+        R: BenchRng,
+    {
+        glam::DAffine3::from_mat4(random_mint_homogeneous_mat4(rng).into())
     }
 
     // public non-inlined functions for cargo asm
@@ -247,8 +814,7 @@ pub mod glam_support {
 #[cfg(feature = "cgmath")]
 pub mod cgmath_support {
     use super::mint_support::*;
-    use super::BenchValue;
-    use rand::RngExt;
+    use super::{BenchRng, BenchValue};
     impl_bench_value!(
         cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
         random_cgmath_decomposed3
@@ -262,32 +828,67 @@ pub mod cgmath_support {
     impl_bench_value!(cgmath::Vector2<f32>, random_mint_vec2);
     impl_bench_value!(cgmath::Vector3<f32>, random_mint_vec3);
     impl_bench_value!(cgmath::Vector4<f32>, random_mint_vec4);
+    impl_bench_value!(
+        cgmath::Decomposed<cgmath::Vector3<f64>, cgmath::Quaternion<f64>>,
+        random_cgmath_decomposed3
+    );
+    impl_bench_value!(cgmath::Matrix2<f64>, random_mint_invertible_mat2);
+    impl_bench_value!(cgmath::Matrix3<f64>, random_mint_homogeneous_mat3);
+    impl_bench_value!(cgmath::Matrix4<f64>, random_mint_homogeneous_mat4);
+    impl_bench_value!(cgmath::Point2<f64>, random_cgmath_point2);
+    impl_bench_value!(cgmath::Point3<f64>, random_cgmath_point3);
+    impl_bench_value!(cgmath::Quaternion<f64>, random_mint_quat);
+    impl_bench_value!(cgmath::Vector2<f64>, random_mint_vec2);
+    impl_bench_value!(cgmath::Vector3<f64>, random_mint_vec3);
+    impl_bench_value!(cgmath::Vector4<f64>, random_mint_vec4);
+
+    impl_components!(mint::Vector2<f32> => cgmath::Vector2<f32>);
+    impl_components!(mint::Vector3<f32> => cgmath::Vector3<f32>);
+    impl_components!(mint::Vector4<f32> => cgmath::Vector4<f32>);
+    impl_components!(mint::Point2<f32> => cgmath::Point2<f32>);
+    impl_components!(mint::Point3<f32> => cgmath::Point3<f32>);
+    impl_components!(mint::Quaternion<f32> => cgmath::Quaternion<f32>);
+    impl_components!(mint::ColumnMatrix2<f32> => cgmath::Matrix2<f32>);
+    impl_components!(mint::ColumnMatrix3<f32> => cgmath::Matrix3<f32>);
+    impl_components!(mint::ColumnMatrix4<f32> => cgmath::Matrix4<f32>);
+    impl_components!(mint::Vector2<f64> => cgmath::Vector2<f64>);
+    impl_components!(mint::Vector3<f64> => cgmath::Vector3<f64>);
+    impl_components!(mint::Vector4<f64> => cgmath::Vector4<f64>);
+    impl_components!(mint::Point2<f64> => cgmath::Point2<f64>);
+    impl_components!(mint::Point3<f64> => cgmath::Point3<f64>);
+    impl_components!(mint::Quaternion<f64> => cgmath::Quaternion<f64>);
+    impl_components!(mint::ColumnMatrix2<f64> => cgmath::Matrix2<f64>);
+    impl_components!(mint::ColumnMatrix3<f64> => cgmath::Matrix3<f64>);
+    impl_components!(mint::ColumnMatrix4<f64> => cgmath::Matrix4<f64>);
 
     // cgmath random functions ----------------------------------------------------
-    fn random_cgmath_decomposed3<R>(
+    fn random_cgmath_decomposed3<T, R>(
         rng: &mut R,
-    ) -> cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>
+    ) -> cgmath::Decomposed<cgmath::Vector3<T>, cgmath::Quaternion<T>>
     where
-        R: RngExt,
+        T: BenchScalar + cgmath::BaseFloat,
+        R: BenchRng,
     {
         cgmath::Decomposed {
-            scale: rng.random_range(0.1..1.0),
+            scale: T::random_nonzero(rng),
             rot: random_mint_quat(rng).into(),
             disp: random_mint_vec3(rng).into(),
         }
     }
 
-    fn random_cgmath_point2<R>(rng: &mut R) -> cgmath::Point2<f32>
+    fn random_cgmath_point2<T, R>(rng: &mut R) -> cgmath::Point2<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         let v = random_mint_vec2(rng);
         cgmath::Point2::new(v.x, v.y)
     }
 
-    fn random_cgmath_point3<R>(rng: &mut R) -> cgmath::Point3<f32>
+    fn random_cgmath_point3<T, R>(rng: &mut R) -> cgmath::Point3<T>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         let v = random_mint_vec3(rng);
         cgmath::Point3::new(v.x, v.y, v.z)
@@ -320,8 +921,7 @@ pub mod cgmath_support {
 #[cfg(feature = "nalgebra")]
 pub mod nalgebra_support {
     use super::mint_support::*;
-    use super::BenchValue;
-    use rand::RngExt;
+    use super::{BenchRng, BenchValue, Components};
     impl_bench_value!(nalgebra::Matrix2<f32>, random_mint_invertible_mat2);
     impl_bench_value!(nalgebra::Matrix3<f32>, random_mint_homogeneous_mat3);
     impl_bench_value!(nalgebra::Matrix4<f32>, random_mint_homogeneous_mat4);
@@ -336,60 +936,121 @@ pub mod nalgebra_support {
     impl_bench_value!(nalgebra::Vector4<f32>, random_na_vec4);
     impl_bench_value!(nalgebra::Isometry2<f32>, random_na_iso2);
     impl_bench_value!(nalgebra::Isometry3<f32>, random_na_iso3);
-    impl_bench_value!(nalgebra::Vector3<f64>, random_na_dvec3);
+    impl_bench_value!(nalgebra::Matrix2<f64>, random_mint_invertible_mat2);
+    impl_bench_value!(nalgebra::Matrix3<f64>, random_mint_homogeneous_mat3);
+    impl_bench_value!(nalgebra::Matrix4<f64>, random_mint_homogeneous_mat4);
+    impl_bench_value!(nalgebra::Point2<f64>, random_na_point2);
+    impl_bench_value!(nalgebra::Point3<f64>, random_na_point3);
+    impl_bench_value!(nalgebra::Transform2<f64>, random_na_transform2);
+    impl_bench_value!(nalgebra::Transform3<f64>, random_na_transform3);
+    impl_bench_value!(nalgebra::UnitQuaternion<f64>, random_na_quat);
+    impl_bench_value!(nalgebra::UnitComplex<f64>, random_na_cplx);
+    impl_bench_value!(nalgebra::Vector2<f64>, random_na_vec2);
+    impl_bench_value!(nalgebra::Vector3<f64>, random_na_vec3);
+    impl_bench_value!(nalgebra::Vector4<f64>, random_na_vec4);
+    impl_bench_value!(nalgebra::Isometry2<f64>, random_na_iso2);
+    impl_bench_value!(nalgebra::Isometry3<f64>, random_na_iso3);
+
+    impl_components!(mint::Vector2<f32> => nalgebra::Vector2<f32>);
+    impl_components!(mint::Vector3<f32> => nalgebra::Vector3<f32>);
+    impl_components!(mint::Vector4<f32> => nalgebra::Vector4<f32>);
+    impl_components!(mint::Point2<f32> => nalgebra::Point2<f32>);
+    impl_components!(mint::Point3<f32> => nalgebra::Point3<f32>);
+    impl_components!(mint::Quaternion<f32> => nalgebra::UnitQuaternion<f32>);
+    impl_components!(mint::ColumnMatrix2<f32> => nalgebra::Matrix2<f32>);
+    impl_components!(mint::ColumnMatrix3<f32> => nalgebra::Matrix3<f32>);
+    impl_components!(mint::ColumnMatrix4<f32> => nalgebra::Matrix4<f32>);
+    impl_components!(mint::Vector2<f64> => nalgebra::Vector2<f64>);
+    impl_components!(mint::Vector3<f64> => nalgebra::Vector3<f64>);
+    impl_components!(mint::Vector4<f64> => nalgebra::Vector4<f64>);
+    impl_components!(mint::Point2<f64> => nalgebra::Point2<f64>);
+    impl_components!(mint::Point3<f64> => nalgebra::Point3<f64>);
+    impl_components!(mint::Quaternion<f64> => nalgebra::UnitQuaternion<f64>);
+    impl_components!(mint::ColumnMatrix2<f64> => nalgebra::Matrix2<f64>);
+    impl_components!(mint::ColumnMatrix3<f64> => nalgebra::Matrix3<f64>);
+    impl_components!(mint::ColumnMatrix4<f64> => nalgebra::Matrix4<f64>);
+
+    impl<T: nalgebra::RealField + Copy + Into<f64>> Components for nalgebra::Transform2<T> {
+        fn components(&self) -> Vec<f64> {
+            self.matrix().iter().map(|&c| c.into()).collect()
+        }
+    }
+
+    impl<T: nalgebra::RealField + Copy + Into<f64>> Components for nalgebra::Transform3<T> {
+        fn components(&self) -> Vec<f64> {
+            self.matrix().iter().map(|&c| c.into()).collect()
+        }
+    }
 
     // nalgebra random functions --------------------------------------------------
-    fn random_na_cplx<R: RngExt>(rng: &mut R) -> nalgebra::UnitComplex<f32> {
-        let angle = crate::glam_support::random_angle_radians(rng);
-        nalgebra::UnitComplex::new(angle)
+    fn random_na_cplx<T: BenchScalar + nalgebra::RealField, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::UnitComplex<T> {
+        nalgebra::UnitComplex::new(T::random_angle(rng))
     }
 
-    fn random_na_quat<R: RngExt>(rng: &mut R) -> nalgebra::UnitQuaternion<f32> {
+    fn random_na_quat<T: BenchScalar + nalgebra::RealField, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::UnitQuaternion<T> {
         nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into())
     }
 
-    fn random_na_iso2<R: RngExt>(rng: &mut R) -> nalgebra::Isometry2<f32> {
-        let rot = nalgebra::UnitComplex::random_value(rng);
-        let tra = nalgebra::Vector2::random_value(rng);
+    fn random_na_iso2<T: BenchScalar + nalgebra::RealField, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Isometry2<T> {
+        let rot = random_na_cplx(rng);
+        let tra = random_na_vec2(rng);
         nalgebra::Isometry2::from_parts(tra.into(), rot)
     }
 
-    fn random_na_iso3<R: RngExt>(rng: &mut R) -> nalgebra::Isometry3<f32> {
-        let rot = nalgebra::UnitQuaternion::random_value(rng);
-        let tra = nalgebra::Vector3::random_value(rng);
+    fn random_na_iso3<T: BenchScalar + nalgebra::RealField, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Isometry3<T> {
+        let rot = random_na_quat(rng);
+        let tra = random_na_vec3(rng);
         nalgebra::Isometry3::from_parts(tra.into(), rot)
     }
 
-    fn random_na_transform2<R: RngExt>(rng: &mut R) -> nalgebra::Transform2<f32> {
+    fn random_na_transform2<T: BenchScalar + nalgebra::RealField, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Transform2<T> {
         nalgebra::Transform2::from_matrix_unchecked(random_mint_homogeneous_mat3(rng).into())
     }
 
-    fn random_na_transform3<R: RngExt>(rng: &mut R) -> nalgebra::Transform3<f32> {
+    fn random_na_transform3<T: BenchScalar + nalgebra::RealField, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Transform3<T> {
         nalgebra::Transform3::from_matrix_unchecked(random_mint_homogeneous_mat4(rng).into())
     }
 
-    fn random_na_point2<R: RngExt>(rng: &mut R) -> nalgebra::Point2<f32> {
-        rng.random::<[f32; 2]>().into()
-    }
-
-    fn random_na_point3<R: RngExt>(rng: &mut R) -> nalgebra::Point3<f32> {
-        rng.random::<[f32; 3]>().into()
+    fn random_na_point2<T: BenchScalar + nalgebra::Scalar, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Point2<T> {
+        random_na_vec2(rng).into()
     }
 
-    fn random_na_vec2<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f32> {
-        rng.random::<[f32; 2]>().into()
+    fn random_na_point3<T: BenchScalar + nalgebra::Scalar, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Point3<T> {
+        random_na_vec3(rng).into()
     }
 
-    fn random_na_vec3<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f32> {
-        rng.random::<[f32; 3]>().into()
+    fn random_na_vec2<T: BenchScalar + nalgebra::Scalar, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Vector2<T> {
+        random_mint_vec2(rng).into()
     }
 
-    fn random_na_vec4<R: RngExt>(rng: &mut R) -> nalgebra::Vector4<f32> {
-        rng.random::<[f32; 4]>().into()
+    fn random_na_vec3<T: BenchScalar + nalgebra::Scalar, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Vector3<T> {
+        random_mint_vec3(rng).into()
     }
 
-    fn random_na_dvec3<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f64> {
-        rng.random::<[f64; 3]>().into()
+    fn random_na_vec4<T: BenchScalar + nalgebra::Scalar, R: BenchRng>(
+        rng: &mut R,
+    ) -> nalgebra::Vector4<T> {
+        random_mint_vec4(rng).into()
     }
 
     pub fn nalgebra_mat4_det(m: &nalgebra::Matrix4<f32>) -> f32 {
@@ -415,9 +1076,8 @@ pub mod nalgebra_support {
 #[cfg(feature = "simba")]
 pub mod nalgebra_support_wide {
     use super::mint_support::*;
-    use super::BenchValue;
-    use rand::RngExt;
-    use simba::simd::{f32x16, f32x4, f32x8, f64x2, f64x4, f64x8};
+    use super::{BenchRng, BenchValue, Components};
+    use simba::simd::{f32x16, f32x4, f32x8, f64x2, f64x4, f64x8, SimdValue};
     impl_bench_value!(nalgebra::Point2<f32x4>, random_na_point2x4);
     impl_bench_value!(nalgebra::Point3<f32x4>, random_na_point3x4);
     impl_bench_value!(nalgebra::Point2<f32x8>, random_na_point2x8);
@@ -445,6 +1105,9 @@ pub mod nalgebra_support_wide {
     impl_bench_value!(nalgebra::Vector3<f64x2>, random_na_dvec3x2);
     impl_bench_value!(nalgebra::Vector3<f64x4>, random_na_dvec3x4);
     impl_bench_value!(nalgebra::Vector3<f64x8>, random_na_dvec3x8);
+    impl_bench_value!(nalgebra::Vector4<f64x2>, random_na_dvec4x2);
+    impl_bench_value!(nalgebra::Vector4<f64x4>, random_na_dvec4x4);
+    impl_bench_value!(nalgebra::Vector4<f64x8>, random_na_dvec4x8);
     impl_bench_value!(nalgebra::Matrix2<f32x4>, random_na_mat2x4);
     impl_bench_value!(nalgebra::Matrix3<f32x4>, random_na_mat3x4);
     impl_bench_value!(nalgebra::Matrix4<f32x4>, random_na_mat4x4);
@@ -454,6 +1117,15 @@ pub mod nalgebra_support_wide {
     impl_bench_value!(nalgebra::Matrix2<f32x16>, random_na_mat2x16);
     impl_bench_value!(nalgebra::Matrix3<f32x16>, random_na_mat3x16);
     impl_bench_value!(nalgebra::Matrix4<f32x16>, random_na_mat4x16);
+    impl_bench_value!(nalgebra::Matrix2<f64x2>, random_na_dmat2x2);
+    impl_bench_value!(nalgebra::Matrix3<f64x2>, random_na_dmat3x2);
+    impl_bench_value!(nalgebra::Matrix4<f64x2>, random_na_dmat4x2);
+    impl_bench_value!(nalgebra::Matrix2<f64x4>, random_na_dmat2x4);
+    impl_bench_value!(nalgebra::Matrix3<f64x4>, random_na_dmat3x4);
+    impl_bench_value!(nalgebra::Matrix4<f64x4>, random_na_dmat4x4);
+    impl_bench_value!(nalgebra::Matrix2<f64x8>, random_na_dmat2x8);
+    impl_bench_value!(nalgebra::Matrix3<f64x8>, random_na_dmat3x8);
+    impl_bench_value!(nalgebra::Matrix4<f64x8>, random_na_dmat4x8);
     impl_bench_value!(nalgebra::Isometry2<f32x4>, random_na_iso2x4);
     impl_bench_value!(nalgebra::Isometry3<f32x4>, random_na_iso3x4);
     impl_bench_value!(nalgebra::Isometry2<f32x8>, random_na_iso2x8);
@@ -462,7 +1134,7 @@ pub mod nalgebra_support_wide {
     impl_bench_value!(nalgebra::Isometry3<f32x16>, random_na_iso3x16);
 
     // nalgebra random functions --------------------------------------------------
-    fn random_na_cplx4<R: RngExt>(rng: &mut R) -> nalgebra::UnitComplex<f32x4> {
+    fn random_na_cplx4<R: BenchRng>(rng: &mut R) -> nalgebra::UnitComplex<f32x4> {
         [
             nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
             nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
@@ -472,7 +1144,7 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_cplx8<R: RngExt>(rng: &mut R) -> nalgebra::UnitComplex<f32x8> {
+    fn random_na_cplx8<R: BenchRng>(rng: &mut R) -> nalgebra::UnitComplex<f32x8> {
         [
             nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
             nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
@@ -486,7 +1158,7 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_cplx16<R: RngExt>(rng: &mut R) -> nalgebra::UnitComplex<f32x16> {
+    fn random_na_cplx16<R: BenchRng>(rng: &mut R) -> nalgebra::UnitComplex<f32x16> {
         [
             nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
             nalgebra::UnitComplex::new(crate::glam_support::random_angle_radians(rng)),
@@ -508,7 +1180,7 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_quat4<R: RngExt>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x4> {
+    fn random_na_quat4<R: BenchRng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x4> {
         [
             nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
             nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
@@ -518,7 +1190,7 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_quat8<R: RngExt>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x8> {
+    fn random_na_quat8<R: BenchRng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x8> {
         [
             nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
             nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
@@ -532,7 +1204,7 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_quat16<R: RngExt>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x16> {
+    fn random_na_quat16<R: BenchRng>(rng: &mut R) -> nalgebra::UnitQuaternion<f32x16> {
         [
             nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
             nalgebra::UnitQuaternion::from_quaternion(random_mint_quat(rng).into()),
@@ -554,13 +1226,13 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_iso2x4<R: RngExt>(rng: &mut R) -> nalgebra::Isometry2<f32x4> {
+    fn random_na_iso2x4<R: BenchRng>(rng: &mut R) -> nalgebra::Isometry2<f32x4> {
         let rot = nalgebra::UnitComplex::random_value(rng);
         let tra = nalgebra::Vector2::random_value(rng);
         nalgebra::Isometry2::from_parts(tra.into(), rot)
     }
 
-    fn random_na_iso2x8<R: RngExt>(rng: &mut R) -> nalgebra::Isometry2<f32x8> {
+    fn random_na_iso2x8<R: BenchRng>(rng: &mut R) -> nalgebra::Isometry2<f32x8> {
         let rot = nalgebra::UnitComplex::random_value(rng);
         let tra = nalgebra::Vector2::random_value(rng);
         nalgebra::Isometry2::from_parts(tra.into(), rot)
@@ -568,64 +1240,64 @@ pub mod nalgebra_support_wide {
 
     fn random_na_iso2x16<R>(rng: &mut R) -> nalgebra::Isometry2<f32x16>
     where
-        R: RngExt,
+        R: BenchRng,
     {
         let rot = nalgebra::UnitComplex::random_value(rng);
         let tra = nalgebra::Vector2::random_value(rng);
         nalgebra::Isometry2::from_parts(tra.into(), rot)
     }
 
-    fn random_na_iso3x4<R: RngExt>(rng: &mut R) -> nalgebra::Isometry3<f32x4> {
+    fn random_na_iso3x4<R: BenchRng>(rng: &mut R) -> nalgebra::Isometry3<f32x4> {
         let rot = nalgebra::UnitQuaternion::random_value(rng);
         let tra = nalgebra::Vector3::random_value(rng);
         nalgebra::Isometry3::from_parts(tra.into(), rot)
     }
 
-    fn random_na_iso3x8<R: RngExt>(rng: &mut R) -> nalgebra::Isometry3<f32x8> {
+    fn random_na_iso3x8<R: BenchRng>(rng: &mut R) -> nalgebra::Isometry3<f32x8> {
         let rot = nalgebra::UnitQuaternion::random_value(rng);
         let tra = nalgebra::Vector3::random_value(rng);
         nalgebra::Isometry3::from_parts(tra.into(), rot)
     }
 
-    fn random_na_iso3x16<R: RngExt>(rng: &mut R) -> nalgebra::Isometry3<f32x16> {
+    fn random_na_iso3x16<R: BenchRng>(rng: &mut R) -> nalgebra::Isometry3<f32x16> {
         let rot = nalgebra::UnitQuaternion::random_value(rng);
         let tra = nalgebra::Vector3::random_value(rng);
         nalgebra::Isometry3::from_parts(tra.into(), rot)
     }
 
-    fn random_na_point2x4<R: RngExt>(rng: &mut R) -> nalgebra::Point2<f32x4> {
+    fn random_na_point2x4<R: BenchRng>(rng: &mut R) -> nalgebra::Point2<f32x4> {
         random_na_vec2x4(rng).into()
     }
 
-    fn random_na_point3x4<R: RngExt>(rng: &mut R) -> nalgebra::Point3<f32x4> {
+    fn random_na_point3x4<R: BenchRng>(rng: &mut R) -> nalgebra::Point3<f32x4> {
         random_na_vec3x4(rng).into()
     }
 
-    fn random_na_point2x8<R: RngExt>(rng: &mut R) -> nalgebra::Point2<f32x8> {
+    fn random_na_point2x8<R: BenchRng>(rng: &mut R) -> nalgebra::Point2<f32x8> {
         random_na_vec2x8(rng).into()
     }
 
-    fn random_na_point3x8<R: RngExt>(rng: &mut R) -> nalgebra::Point3<f32x8> {
+    fn random_na_point3x8<R: BenchRng>(rng: &mut R) -> nalgebra::Point3<f32x8> {
         random_na_vec3x8(rng).into()
     }
 
-    fn random_na_point2x16<R: RngExt>(rng: &mut R) -> nalgebra::Point2<f32x16> {
+    fn random_na_point2x16<R: BenchRng>(rng: &mut R) -> nalgebra::Point2<f32x16> {
         random_na_vec2x16(rng).into()
     }
 
-    fn random_na_point3x16<R: RngExt>(rng: &mut R) -> nalgebra::Point3<f32x16> {
+    fn random_na_point3x16<R: BenchRng>(rng: &mut R) -> nalgebra::Point3<f32x16> {
         random_na_vec3x16(rng).into()
     }
 
-    fn random_na_vec2x4<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f32x4> {
+    fn random_na_vec2x4<R: BenchRng>(rng: &mut R) -> nalgebra::Vector2<f32x4> {
         [random_f32x4(rng), random_f32x4(rng)].into()
     }
 
-    fn random_na_vec3x4<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f32x4> {
+    fn random_na_vec3x4<R: BenchRng>(rng: &mut R) -> nalgebra::Vector3<f32x4> {
         [random_f32x4(rng), random_f32x4(rng), random_f32x4(rng)].into()
     }
 
-    fn random_na_vec4x4<R: RngExt>(rng: &mut R) -> nalgebra::Vector4<f32x4> {
+    fn random_na_vec4x4<R: BenchRng>(rng: &mut R) -> nalgebra::Vector4<f32x4> {
         [
             random_f32x4(rng),
             random_f32x4(rng),
@@ -635,15 +1307,15 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_vec2x8<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f32x8> {
+    fn random_na_vec2x8<R: BenchRng>(rng: &mut R) -> nalgebra::Vector2<f32x8> {
         [random_f32x8(rng), random_f32x8(rng)].into()
     }
 
-    fn random_na_vec3x8<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f32x8> {
+    fn random_na_vec3x8<R: BenchRng>(rng: &mut R) -> nalgebra::Vector3<f32x8> {
         [random_f32x8(rng), random_f32x8(rng), random_f32x8(rng)].into()
     }
 
-    fn random_na_vec4x8<R: RngExt>(rng: &mut R) -> nalgebra::Vector4<f32x8> {
+    fn random_na_vec4x8<R: BenchRng>(rng: &mut R) -> nalgebra::Vector4<f32x8> {
         [
             random_f32x8(rng),
             random_f32x8(rng),
@@ -653,15 +1325,15 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_vec2x16<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f32x16> {
+    fn random_na_vec2x16<R: BenchRng>(rng: &mut R) -> nalgebra::Vector2<f32x16> {
         [random_f32x16(rng), random_f32x16(rng)].into()
     }
 
-    fn random_na_vec3x16<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f32x16> {
+    fn random_na_vec3x16<R: BenchRng>(rng: &mut R) -> nalgebra::Vector3<f32x16> {
         [random_f32x16(rng), random_f32x16(rng), random_f32x16(rng)].into()
     }
 
-    fn random_na_vec4x16<R: RngExt>(rng: &mut R) -> nalgebra::Vector4<f32x16> {
+    fn random_na_vec4x16<R: BenchRng>(rng: &mut R) -> nalgebra::Vector4<f32x16> {
         [
             random_f32x16(rng),
             random_f32x16(rng),
@@ -671,212 +1343,493 @@ pub mod nalgebra_support_wide {
         .into()
     }
 
-    fn random_na_dvec2x2<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f64x2> {
+    fn random_na_dvec2x2<R: BenchRng>(rng: &mut R) -> nalgebra::Vector2<f64x2> {
         [random_f64x2(rng), random_f64x2(rng)].into()
     }
 
-    fn random_na_dvec2x4<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f64x4> {
+    fn random_na_dvec2x4<R: BenchRng>(rng: &mut R) -> nalgebra::Vector2<f64x4> {
         [random_f64x4(rng), random_f64x4(rng)].into()
     }
 
-    fn random_na_dvec2x8<R: RngExt>(rng: &mut R) -> nalgebra::Vector2<f64x8> {
+    fn random_na_dvec2x8<R: BenchRng>(rng: &mut R) -> nalgebra::Vector2<f64x8> {
         [random_f64x8(rng), random_f64x8(rng)].into()
     }
 
-    fn random_na_dvec3x2<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f64x2> {
+    fn random_na_dvec3x2<R: BenchRng>(rng: &mut R) -> nalgebra::Vector3<f64x2> {
         [random_f64x2(rng), random_f64x2(rng), random_f64x2(rng)].into()
     }
 
-    fn random_na_dvec3x4<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f64x4> {
+    fn random_na_dvec3x4<R: BenchRng>(rng: &mut R) -> nalgebra::Vector3<f64x4> {
         [random_f64x4(rng), random_f64x4(rng), random_f64x4(rng)].into()
     }
 
-    fn random_na_dvec3x8<R: RngExt>(rng: &mut R) -> nalgebra::Vector3<f64x8> {
+    fn random_na_dvec3x8<R: BenchRng>(rng: &mut R) -> nalgebra::Vector3<f64x8> {
         [random_f64x8(rng), random_f64x8(rng), random_f64x8(rng)].into()
     }
 
-    fn random_na_mat2x4<R: RngExt>(rng: &mut R) -> nalgebra::Matrix2<f32x4> {
-        nalgebra::Matrix2::from_fn(|_, _| random_f32x4(rng))
+    fn random_na_dvec4x2<R: BenchRng>(rng: &mut R) -> nalgebra::Vector4<f64x2> {
+        [
+            random_f64x2(rng),
+            random_f64x2(rng),
+            random_f64x2(rng),
+            random_f64x2(rng),
+        ]
+        .into()
+    }
+
+    fn random_na_dvec4x4<R: BenchRng>(rng: &mut R) -> nalgebra::Vector4<f64x4> {
+        [
+            random_f64x4(rng),
+            random_f64x4(rng),
+            random_f64x4(rng),
+            random_f64x4(rng),
+        ]
+        .into()
+    }
+
+    fn random_na_dvec4x8<R: BenchRng>(rng: &mut R) -> nalgebra::Vector4<f64x8> {
+        [
+            random_f64x8(rng),
+            random_f64x8(rng),
+            random_f64x8(rng),
+            random_f64x8(rng),
+        ]
+        .into()
+    }
+
+    fn random_na_mat2x4<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix2<f32x4> {
+        let lanes: [nalgebra::Matrix2<f32>; 4] =
+            std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+        lanes.into()
+    }
+
+    fn random_na_mat3x4<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix3<f32x4> {
+        let lanes: [nalgebra::Matrix3<f32>; 4] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+        lanes.into()
+    }
+
+    fn random_na_mat4x4<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix4<f32x4> {
+        let lanes: [nalgebra::Matrix4<f32>; 4] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+        lanes.into()
+    }
+
+    fn random_na_mat2x8<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix2<f32x8> {
+        let lanes: [nalgebra::Matrix2<f32>; 8] =
+            std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+        lanes.into()
+    }
+
+    fn random_na_mat3x8<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix3<f32x8> {
+        let lanes: [nalgebra::Matrix3<f32>; 8] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat3x4<R: RngExt>(rng: &mut R) -> nalgebra::Matrix3<f32x4> {
-        nalgebra::Matrix3::from_fn(|_, _| random_f32x4(rng))
+    fn random_na_mat4x8<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix4<f32x8> {
+        let lanes: [nalgebra::Matrix4<f32>; 8] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat4x4<R: RngExt>(rng: &mut R) -> nalgebra::Matrix4<f32x4> {
-        nalgebra::Matrix4::from_fn(|_, _| random_f32x4(rng))
+    fn random_na_mat2x16<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix2<f32x16> {
+        let lanes: [nalgebra::Matrix2<f32>; 16] =
+            std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat2x8<R: RngExt>(rng: &mut R) -> nalgebra::Matrix2<f32x8> {
-        nalgebra::Matrix2::from_fn(|_, _| random_f32x8(rng))
+    fn random_na_mat3x16<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix3<f32x16> {
+        let lanes: [nalgebra::Matrix3<f32>; 16] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat3x8<R: RngExt>(rng: &mut R) -> nalgebra::Matrix3<f32x8> {
-        nalgebra::Matrix3::from_fn(|_, _| random_f32x8(rng))
+    fn random_na_mat4x16<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix4<f32x16> {
+        let lanes: [nalgebra::Matrix4<f32>; 16] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat4x8<R: RngExt>(rng: &mut R) -> nalgebra::Matrix4<f32x8> {
-        nalgebra::Matrix4::from_fn(|_, _| random_f32x8(rng))
+    fn random_na_dmat2x2<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix2<f64x2> {
+        let lanes: [nalgebra::Matrix2<f64>; 2] =
+            std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat2x16<R: RngExt>(rng: &mut R) -> nalgebra::Matrix2<f32x16> {
-        nalgebra::Matrix2::from_fn(|_, _| random_f32x16(rng))
+    fn random_na_dmat3x2<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix3<f64x2> {
+        let lanes: [nalgebra::Matrix3<f64>; 2] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat3x16<R: RngExt>(rng: &mut R) -> nalgebra::Matrix3<f32x16> {
-        nalgebra::Matrix3::from_fn(|_, _| random_f32x16(rng))
+    fn random_na_dmat4x2<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix4<f64x2> {
+        let lanes: [nalgebra::Matrix4<f64>; 2] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+        lanes.into()
     }
 
-    fn random_na_mat4x16<R: RngExt>(rng: &mut R) -> nalgebra::Matrix4<f32x16> {
-        nalgebra::Matrix4::from_fn(|_, _| random_f32x16(rng))
+    fn random_na_dmat2x4<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix2<f64x4> {
+        let lanes: [nalgebra::Matrix2<f64>; 4] =
+            std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+        lanes.into()
     }
 
-    fn random_f32x4<R: RngExt>(rng: &mut R) -> f32x4 {
-        rng.random::<[f32; 4]>().into()
+    fn random_na_dmat3x4<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix3<f64x4> {
+        let lanes: [nalgebra::Matrix3<f64>; 4] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+        lanes.into()
     }
 
-    fn random_f32x8<R: RngExt>(rng: &mut R) -> f32x8 {
-        rng.random::<[f32; 8]>().into()
+    fn random_na_dmat4x4<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix4<f64x4> {
+        let lanes: [nalgebra::Matrix4<f64>; 4] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+        lanes.into()
     }
 
-    fn random_f32x16<R: RngExt>(rng: &mut R) -> f32x16 {
-        rng.random::<[f32; 16]>().into()
+    fn random_na_dmat2x8<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix2<f64x8> {
+        let lanes: [nalgebra::Matrix2<f64>; 8] =
+            std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+        lanes.into()
     }
 
-    fn random_f64x2<R: RngExt>(rng: &mut R) -> f64x2 {
-        rng.random::<[f64; 2]>().into()
+    fn random_na_dmat3x8<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix3<f64x8> {
+        let lanes: [nalgebra::Matrix3<f64>; 8] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+        lanes.into()
     }
 
-    fn random_f64x4<R: RngExt>(rng: &mut R) -> f64x4 {
-        rng.random::<[f64; 4]>().into()
+    fn random_na_dmat4x8<R: BenchRng>(rng: &mut R) -> nalgebra::Matrix4<f64x8> {
+        let lanes: [nalgebra::Matrix4<f64>; 8] =
+            std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+        lanes.into()
     }
 
-    fn random_f64x8<R: RngExt>(rng: &mut R) -> f64x8 {
-        rng.random::<[f64; 8]>().into()
+    fn random_f32x4<R: BenchRng>(rng: &mut R) -> f32x4 {
+        random_array::<f32, _, 4>(rng).into()
     }
+
+    fn random_f32x8<R: BenchRng>(rng: &mut R) -> f32x8 {
+        random_array::<f32, _, 8>(rng).into()
+    }
+
+    fn random_f32x16<R: BenchRng>(rng: &mut R) -> f32x16 {
+        random_array::<f32, _, 16>(rng).into()
+    }
+
+    fn random_f64x2<R: BenchRng>(rng: &mut R) -> f64x2 {
+        random_array::<f64, _, 2>(rng).into()
+    }
+
+    fn random_f64x4<R: BenchRng>(rng: &mut R) -> f64x4 {
+        random_array::<f64, _, 4>(rng).into()
+    }
+
+    fn random_f64x8<R: BenchRng>(rng: &mut R) -> f64x8 {
+        random_array::<f64, _, 8>(rng).into()
+    }
+
+    macro_rules! impl_nalgebra_wide_components {
+        ($($n:literal, $fxn:ident => $f:ident);+) => {
+            $(impl_wide_components!(
+                $fxn => |v: &$fxn| std::array::from_fn::<$f, $n, _>(|i| v.extract(i)),
+                nalgebra::UnitQuaternion<$fxn> => |q: &nalgebra::UnitQuaternion<$fxn>| {
+                    std::array::from_fn::<_, $n, _>(|i| {
+                        let coords = q.coords.map(|c| c.extract(i));
+                        nalgebra::UnitQuaternion::new_unchecked(nalgebra::Quaternion::from(coords))
+                    })
+                }
+            );
+
+            impl<const R: usize, const C: usize> Components for nalgebra::SMatrix<$fxn, R, C>
+            where
+                nalgebra::SMatrix<$f, R, C>: Components,
+            {
+                fn components(&self) -> Vec<f64> {
+                    (0..$n)
+                        .flat_map(|i| self.map(|c| c.extract(i)).components())
+                        .collect()
+                }
+            })+
+        };
+    }
+
+    impl_nalgebra_wide_components!(
+        4, f32x4 => f32; 8, f32x8 => f32; 16, f32x16 => f32;
+        2, f64x2 => f64; 4, f64x4 => f64; 8, f64x8 => f64
+    );
 }
 
 #[cfg(feature = "ultraviolet")]
 pub mod ultraviolet_support {
+    use super::mint_support::*;
     use super::BenchValue;
     use ultraviolet::*;
 
     impl BenchValue for Vec2x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             Vec2x4::new(
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
             )
         }
     }
 
     impl BenchValue for Vec2x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             Vec2x8::new(
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
             )
         }
     }
 
     impl BenchValue for Vec3x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             Vec3x4::new(
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
             )
         }
     }
 
     impl BenchValue for Vec3x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             Vec3x8::new(
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
             )
         }
     }
 
     impl BenchValue for Vec4x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             Vec4x4::new(
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
-                ultraviolet::f32x4::from(rng.random::<[f32; 4]>()),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
+                ultraviolet::f32x4::from(random_array::<f32, _, 4>(rng)),
             )
         }
     }
 
     impl BenchValue for Vec4x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             Vec4x8::new(
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
-                ultraviolet::f32x8::from(rng.random::<[f32; 8]>()),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
+                ultraviolet::f32x8::from(random_array::<f32, _, 8>(rng)),
             )
         }
     }
 
     impl BenchValue for Mat2x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Mat2x4::new(Vec2x4::random_value(rng), Vec2x4::random_value(rng))
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [Mat2; 4] = std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+            Mat2x4::new(
+                Vec2x4::from(lanes.map(|m| m.cols[0])),
+                Vec2x4::from(lanes.map(|m| m.cols[1])),
+            )
         }
     }
 
     impl BenchValue for Mat2x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Mat2x8::new(Vec2x8::random_value(rng), Vec2x8::random_value(rng))
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [Mat2; 8] = std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+            Mat2x8::new(
+                Vec2x8::from(lanes.map(|m| m.cols[0])),
+                Vec2x8::from(lanes.map(|m| m.cols[1])),
+            )
         }
     }
 
     impl BenchValue for Mat3x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [Mat3; 4] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
             Mat3x4::new(
-                Vec3x4::random_value(rng),
-                Vec3x4::random_value(rng),
-                Vec3x4::random_value(rng),
+                Vec3x4::from(lanes.map(|m| m.cols[0])),
+                Vec3x4::from(lanes.map(|m| m.cols[1])),
+                Vec3x4::from(lanes.map(|m| m.cols[2])),
             )
         }
     }
 
     impl BenchValue for Mat3x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [Mat3; 8] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
             Mat3x8::new(
-                Vec3x8::random_value(rng),
-                Vec3x8::random_value(rng),
-                Vec3x8::random_value(rng),
+                Vec3x8::from(lanes.map(|m| m.cols[0])),
+                Vec3x8::from(lanes.map(|m| m.cols[1])),
+                Vec3x8::from(lanes.map(|m| m.cols[2])),
             )
         }
     }
 
     impl BenchValue for Mat4x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [Mat4; 4] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
             Mat4x4::new(
-                Vec4x4::random_value(rng),
-                Vec4x4::random_value(rng),
-                Vec4x4::random_value(rng),
-                Vec4x4::random_value(rng),
+                Vec4x4::from(lanes.map(|m| m.cols[0])),
+                Vec4x4::from(lanes.map(|m| m.cols[1])),
+                Vec4x4::from(lanes.map(|m| m.cols[2])),
+                Vec4x4::from(lanes.map(|m| m.cols[3])),
             )
         }
     }
 
     impl BenchValue for Mat4x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [Mat4; 8] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
             Mat4x8::new(
-                Vec4x8::random_value(rng),
-                Vec4x8::random_value(rng),
-                Vec4x8::random_value(rng),
-                Vec4x8::random_value(rng),
+                Vec4x8::from(lanes.map(|m| m.cols[0])),
+                Vec4x8::from(lanes.map(|m| m.cols[1])),
+                Vec4x8::from(lanes.map(|m| m.cols[2])),
+                Vec4x8::from(lanes.map(|m| m.cols[3])),
+            )
+        }
+    }
+
+    impl BenchValue for DVec2x2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            DVec2x2::new(
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+            )
+        }
+    }
+
+    impl BenchValue for DVec2x4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            DVec2x4::new(
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+            )
+        }
+    }
+
+    impl BenchValue for DVec3x2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            DVec3x2::new(
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+            )
+        }
+    }
+
+    impl BenchValue for DVec3x4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            DVec3x4::new(
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+            )
+        }
+    }
+
+    impl BenchValue for DVec4x2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            DVec4x2::new(
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+                ultraviolet::f64x2::from(random_array::<f64, _, 2>(rng)),
+            )
+        }
+    }
+
+    impl BenchValue for DVec4x4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            DVec4x4::new(
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+                ultraviolet::f64x4::from(random_array::<f64, _, 4>(rng)),
+            )
+        }
+    }
+
+    impl BenchValue for DMat2x2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [DMat2; 2] =
+                std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+            DMat2x2::new(
+                DVec2x2::from(lanes.map(|m| m.cols[0])),
+                DVec2x2::from(lanes.map(|m| m.cols[1])),
+            )
+        }
+    }
+
+    impl BenchValue for DMat2x4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [DMat2; 4] =
+                std::array::from_fn(|_| random_mint_invertible_mat2(rng).into());
+            DMat2x4::new(
+                DVec2x4::from(lanes.map(|m| m.cols[0])),
+                DVec2x4::from(lanes.map(|m| m.cols[1])),
+            )
+        }
+    }
+
+    impl BenchValue for DMat3x2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [DMat3; 2] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+            DMat3x2::new(
+                DVec3x2::from(lanes.map(|m| m.cols[0])),
+                DVec3x2::from(lanes.map(|m| m.cols[1])),
+                DVec3x2::from(lanes.map(|m| m.cols[2])),
+            )
+        }
+    }
+
+    impl BenchValue for DMat3x4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [DMat3; 4] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat3(rng).into());
+            DMat3x4::new(
+                DVec3x4::from(lanes.map(|m| m.cols[0])),
+                DVec3x4::from(lanes.map(|m| m.cols[1])),
+                DVec3x4::from(lanes.map(|m| m.cols[2])),
+            )
+        }
+    }
+
+    impl BenchValue for DMat4x2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [DMat4; 2] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+            DMat4x2::new(
+                DVec4x2::from(lanes.map(|m| m.cols[0])),
+                DVec4x2::from(lanes.map(|m| m.cols[1])),
+                DVec4x2::from(lanes.map(|m| m.cols[2])),
+                DVec4x2::from(lanes.map(|m| m.cols[3])),
+            )
+        }
+    }
+
+    impl BenchValue for DMat4x4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let lanes: [DMat4; 4] =
+                std::array::from_fn(|_| random_mint_homogeneous_mat4(rng).into());
+            DMat4x4::new(
+                DVec4x4::from(lanes.map(|m| m.cols[0])),
+                DVec4x4::from(lanes.map(|m| m.cols[1])),
+                DVec4x4::from(lanes.map(|m| m.cols[2])),
+                DVec4x4::from(lanes.map(|m| m.cols[3])),
             )
         }
     }
 
     impl BenchValue for Rotor2x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let angle = f32x4::from([
                 crate::glam_support::random_angle_radians(rng),
                 crate::glam_support::random_angle_radians(rng),
@@ -888,7 +1841,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Rotor2x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let angle = f32x8::from([
                 crate::glam_support::random_angle_radians(rng),
                 crate::glam_support::random_angle_radians(rng),
@@ -904,7 +1857,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Rotor3x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let yaw = f32x4::from([
                 crate::glam_support::random_angle_radians(rng),
                 crate::glam_support::random_angle_radians(rng),
@@ -928,7 +1881,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Rotor3x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let yaw = f32x8::from([
                 crate::glam_support::random_angle_radians(rng),
                 crate::glam_support::random_angle_radians(rng),
@@ -964,7 +1917,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Isometry2x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let tra = Vec2x4::random_value(rng);
             let rot = Rotor2x4::random_value(rng);
             Isometry2x4::new(tra, rot)
@@ -972,7 +1925,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Isometry2x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let tra = Vec2x8::random_value(rng);
             let rot = Rotor2x8::random_value(rng);
             Isometry2x8::new(tra, rot)
@@ -980,7 +1933,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Isometry3x4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let tra = Vec3x4::random_value(rng);
             let rot = Rotor3x4::random_value(rng);
             Isometry3x4::new(tra, rot)
@@ -988,7 +1941,7 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Isometry3x8 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let tra = Vec3x8::random_value(rng);
             let rot = Rotor3x8::random_value(rng);
             Isometry3x8::new(tra, rot)
@@ -996,68 +1949,56 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Vec2 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Vec2::new(rng.random(), rng.random())
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_vec2(rng).into()
         }
     }
 
     impl BenchValue for Vec3 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Vec3::new(rng.random(), rng.random(), rng.random())
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_vec3(rng).into()
         }
     }
 
     impl BenchValue for Vec4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Vec4::new(rng.random::<f32>(), rng.random(), rng.random(), rng.random())
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_vec4(rng).into()
         }
     }
 
     impl BenchValue for Mat2 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Mat2::new(Vec2::random_value(rng), Vec2::random_value(rng))
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_invertible_mat2(rng).into()
         }
     }
 
     impl BenchValue for Mat3 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Mat3::new(
-                Vec3::random_value(rng),
-                Vec3::random_value(rng),
-                Vec3::random_value(rng),
-            )
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_homogeneous_mat3(rng).into()
         }
     }
 
     impl BenchValue for Mat4 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            Mat4::new(
-                Vec4::random_value(rng),
-                Vec4::random_value(rng),
-                Vec4::random_value(rng),
-                Vec4::random_value(rng),
-            )
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_homogeneous_mat4(rng).into()
         }
     }
 
     impl BenchValue for Rotor2 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let angle = crate::glam_support::random_angle_radians(rng);
             Rotor2::from_angle(angle)
         }
     }
 
     impl BenchValue for Rotor3 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
-            let yaw = crate::glam_support::random_angle_radians(rng);
-            let pitch = crate::glam_support::random_angle_radians(rng);
-            let roll = crate::glam_support::random_angle_radians(rng);
-            Rotor3::from_euler_angles(yaw, pitch, roll)
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_quat(rng).into()
         }
     }
 
     impl BenchValue for Isometry2 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let tra = Vec2::random_value(rng);
             let rot = Rotor2::random_value(rng);
             Isometry2::new(tra, rot)
@@ -1065,19 +2006,146 @@ pub mod ultraviolet_support {
     }
 
     impl BenchValue for Isometry3 {
-        fn random_value<R: rand::RngExt>(rng: &mut R) -> Self {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
             let tra = Vec3::random_value(rng);
             let rot = Rotor3::random_value(rng);
             Isometry3::new(tra, rot)
         }
     }
+
+    impl BenchValue for DVec2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_vec2(rng).into()
+        }
+    }
+
+    impl BenchValue for DVec3 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_vec3(rng).into()
+        }
+    }
+
+    impl BenchValue for DVec4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_vec4(rng).into()
+        }
+    }
+
+    impl BenchValue for DMat2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_invertible_mat2(rng).into()
+        }
+    }
+
+    impl BenchValue for DMat3 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_homogeneous_mat3(rng).into()
+        }
+    }
+
+    impl BenchValue for DMat4 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_homogeneous_mat4(rng).into()
+        }
+    }
+
+    impl BenchValue for DRotor2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let angle = f64::random_angle(rng);
+            DRotor2::from_angle(angle)
+        }
+    }
+
+    impl BenchValue for DRotor3 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            random_mint_quat(rng).into()
+        }
+    }
+
+    impl BenchValue for DIsometry2 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let tra = DVec2::random_value(rng);
+            let rot = DRotor2::random_value(rng);
+            DIsometry2::new(tra, rot)
+        }
+    }
+
+    impl BenchValue for DIsometry3 {
+        fn random_value<R: crate::BenchRng>(rng: &mut R) -> Self {
+            let tra = DVec3::random_value(rng);
+            let rot = DRotor3::random_value(rng);
+            DIsometry3::new(tra, rot)
+        }
+    }
+
+    impl_components!(mint::Vector2<f32> => Vec2);
+    impl_components!(mint::Vector3<f32> => Vec3);
+    impl_components!(mint::Vector4<f32> => Vec4);
+    impl_components!(mint::Quaternion<f32> => Rotor3);
+    impl_components!(mint::ColumnMatrix2<f32> => Mat2);
+    impl_components!(mint::ColumnMatrix3<f32> => Mat3);
+    impl_components!(mint::ColumnMatrix4<f32> => Mat4);
+    impl_components!(mint::Vector2<f64> => DVec2);
+    impl_components!(mint::Vector3<f64> => DVec3);
+    impl_components!(mint::Vector4<f64> => DVec4);
+    impl_components!(mint::Quaternion<f64> => DRotor3);
+    impl_components!(mint::ColumnMatrix2<f64> => DMat2);
+    impl_components!(mint::ColumnMatrix3<f64> => DMat3);
+    impl_components!(mint::ColumnMatrix4<f64> => DMat4);
+
+    macro_rules! impl_ultraviolet_wide_components {
+        ($n:literal, $fxn:ident, $vec2:ident => $v2:ident, $vec3:ident => $v3:ident,
+         $vec4:ident => $v4:ident, $mat2:ident => $m2:ident, $mat3:ident => $m3:ident,
+         $mat4:ident => $m4:ident, $rotor3:ident => $r3:ident) => {
+            impl_wide_components!(
+                $fxn => |v: &$fxn| v.to_array(),
+                $vec2 => |v: &$vec2| <[$v2; $n]>::from(*v),
+                $vec3 => |v: &$vec3| <[$v3; $n]>::from(*v),
+                $vec4 => |v: &$vec4| <[$v4; $n]>::from(*v),
+                $mat2 => |m: &$mat2| {
+                    let [x, y] = m.cols.map(<[$v2; $n]>::from);
+                    std::array::from_fn::<_, $n, _>(|i| $m2::new(x[i], y[i]))
+                },
+                $mat3 => |m: &$mat3| {
+                    let [x, y, z] = m.cols.map(<[$v3; $n]>::from);
+                    std::array::from_fn::<_, $n, _>(|i| $m3::new(x[i], y[i], z[i]))
+                },
+                $mat4 => |m: &$mat4| {
+                    let [x, y, z, w] = m.cols.map(<[$v4; $n]>::from);
+                    std::array::from_fn::<_, $n, _>(|i| $m4::new(x[i], y[i], z[i], w[i]))
+                },
+                $rotor3 => |r: &$rotor3| {
+                    let [x, y, z, w] = r.into_quaternion_array().map($fxn::to_array);
+                    std::array::from_fn::<_, $n, _>(|i| {
+                        $r3::from_quaternion_array([x[i], y[i], z[i], w[i]])
+                    })
+                }
+            );
+        };
+    }
+
+    impl_ultraviolet_wide_components!(
+        4, f32x4, Vec2x4 => Vec2, Vec3x4 => Vec3, Vec4x4 => Vec4, Mat2x4 => Mat2,
+        Mat3x4 => Mat3, Mat4x4 => Mat4, Rotor3x4 => Rotor3
+    );
+    impl_ultraviolet_wide_components!(
+        8, f32x8, Vec2x8 => Vec2, Vec3x8 => Vec3, Vec4x8 => Vec4, Mat2x8 => Mat2,
+        Mat3x8 => Mat3, Mat4x8 => Mat4, Rotor3x8 => Rotor3
+    );
+    impl_ultraviolet_wide_components!(
+        2, f64x2, DVec2x2 => DVec2, DVec3x2 => DVec3, DVec4x2 => DVec4, DMat2x2 => DMat2,
+        DMat3x2 => DMat3, DMat4x2 => DMat4, DRotor3x2 => DRotor3
+    );
+    impl_ultraviolet_wide_components!(
+        4, f64x4, DVec2x4 => DVec2, DVec3x4 => DVec3, DVec4x4 => DVec4, DMat2x4 => DMat2,
+        DMat3x4 => DMat3, DMat4x4 => DMat4, DRotor3x4 => DRotor3
+    );
 }
 
 #[cfg(feature = "euclid")]
 pub mod euclid_support {
     use super::mint_support::*;
-    use super::BenchValue;
-    use rand::RngExt;
+    use super::{BenchRng, BenchValue, Components};
 
     impl_bench_value!(euclid::Point2D<f32, euclid::UnknownUnit>, random_euclid_point2);
     impl_bench_value!(euclid::Point3D<f32, euclid::UnknownUnit>, random_euclid_point3);
@@ -1086,63 +2154,149 @@ pub mod euclid_support {
     impl_bench_value!(euclid::Transform3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_mat4);
     impl_bench_value!(euclid::Vector2D<f32, euclid::UnknownUnit>, random_euclid_vec2);
     impl_bench_value!(euclid::Vector3D<f32, euclid::UnknownUnit>, random_euclid_vec3);
+    impl_bench_value!(euclid::Point2D<f64, euclid::UnknownUnit>, random_euclid_point2);
+    impl_bench_value!(euclid::Point3D<f64, euclid::UnknownUnit>, random_euclid_point3);
+    impl_bench_value!(euclid::Rotation3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_quat);
+    impl_bench_value!(euclid::Transform2D<f64, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_mat3);
+    impl_bench_value!(euclid::Transform3D<f64, euclid::UnknownUnit, euclid::UnknownUnit>, random_euclid_mat4);
+    impl_bench_value!(euclid::Vector2D<f64, euclid::UnknownUnit>, random_euclid_vec2);
+    impl_bench_value!(euclid::Vector3D<f64, euclid::UnknownUnit>, random_euclid_vec3);
+
+    impl<T: Copy + Into<f64>, U> Components for euclid::Vector2D<T, U> {
+        fn components(&self) -> Vec<f64> {
+            vec![self.x.into(), self.y.into()]
+        }
+    }
+
+    impl<T: Copy + Into<f64>, U> Components for euclid::Vector3D<T, U> {
+        fn components(&self) -> Vec<f64> {
+            vec![self.x.into(), self.y.into(), self.z.into()]
+        }
+    }
+
+    impl<T: Copy + Into<f64>, U> Components for euclid::Point2D<T, U> {
+        fn components(&self) -> Vec<f64> {
+            vec![self.x.into(), self.y.into()]
+        }
+    }
+
+    impl<T: Copy + Into<f64>, U> Components for euclid::Point3D<T, U> {
+        fn components(&self) -> Vec<f64> {
+            vec![self.x.into(), self.y.into(), self.z.into()]
+        }
+    }
+
+    impl<T: Copy + Into<f64>, Src, Dst> Components for euclid::Rotation3D<T, Src, Dst> {
+        fn components(&self) -> Vec<f64> {
+            vec![self.i.into(), self.j.into(), self.k.into(), self.r.into()]
+        }
+    }
+
+    // euclid uses row vectors, so its rows are the column vector matrix columns
+    impl<T: Copy + Into<f64>, Src, Dst> Components for euclid::Transform2D<T, Src, Dst> {
+        fn components(&self) -> Vec<f64> {
+            let m = self.to_array().map(Into::into);
+            vec![m[0], m[1], 0.0, m[2], m[3], 0.0, m[4], m[5], 1.0]
+        }
+    }
+
+    impl<T: Copy + Into<f64>, Src, Dst> Components for euclid::Transform3D<T, Src, Dst> {
+        fn components(&self) -> Vec<f64> {
+            self.to_array().iter().map(|&c| c.into()).collect()
+        }
+    }
+
+    /// Composes euclid transforms in the same order as multiplying column vector
+    /// matrices. euclid's `a.then(&b)` applies `a` first, which is `b * a`, so
+    /// `a.pre_then(&b)` is `a * b`.
+    pub trait PreThen {
+        fn pre_then(&self, other: &Self) -> Self;
+    }
+
+    macro_rules! impl_pre_then {
+        ($($t:ty),+) => {
+            $(impl PreThen for $t {
+                #[inline]
+                fn pre_then(&self, other: &Self) -> Self {
+                    other.then(self)
+                }
+            })+
+        };
+    }
+
+    impl_pre_then!(
+        euclid::default::Rotation3D<f32>,
+        euclid::default::Rotation3D<f64>,
+        euclid::default::Transform2D<f32>,
+        euclid::default::Transform2D<f64>,
+        euclid::default::Transform3D<f32>,
+        euclid::default::Transform3D<f64>
+    );
 
     // euclid random functions ----------------------------------------------------
-    fn random_euclid_vec2<R>(rng: &mut R) -> euclid::Vector2D<f32, euclid::UnknownUnit>
+    fn random_euclid_vec2<T, R>(rng: &mut R) -> euclid::Vector2D<T, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        let (x, y) = rng.random::<(f32, f32)>().into();
-        euclid::vec2(x, y)
+        let v = random_mint_vec2(rng);
+        euclid::vec2(v.x, v.y)
     }
 
-    fn random_euclid_point2<R>(rng: &mut R) -> euclid::Point2D<f32, euclid::UnknownUnit>
+    fn random_euclid_point2<T, R>(rng: &mut R) -> euclid::Point2D<T, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         random_euclid_vec2(rng).to_point()
     }
 
-    fn random_euclid_vec3<R>(rng: &mut R) -> euclid::Vector3D<f32, euclid::UnknownUnit>
+    fn random_euclid_vec3<T, R>(rng: &mut R) -> euclid::Vector3D<T, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
-        let (x, y, z) = rng.random::<(f32, f32, f32)>().into();
-        euclid::vec3(x, y, z)
+        let v = random_mint_vec3(rng);
+        euclid::vec3(v.x, v.y, v.z)
     }
 
-    fn random_euclid_point3<R>(rng: &mut R) -> euclid::Point3D<f32, euclid::UnknownUnit>
+    fn random_euclid_point3<T, R>(rng: &mut R) -> euclid::Point3D<T, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         random_euclid_vec3(rng).to_point()
     }
 
-    fn random_euclid_quat<R>(
+    fn random_euclid_quat<T, R>(
         rng: &mut R,
-    ) -> euclid::Rotation3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>
+    ) -> euclid::Rotation3D<T, euclid::UnknownUnit, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         let mq = random_mint_quat(rng);
         euclid::Rotation3D::quaternion(mq.v.x, mq.v.y, mq.v.z, mq.s)
     }
 
-    fn random_euclid_mat3<R>(
+    fn random_euclid_mat3<T, R>(
         rng: &mut R,
-    ) -> euclid::Transform2D<f32, euclid::UnknownUnit, euclid::UnknownUnit>
+    ) -> euclid::Transform2D<T, euclid::UnknownUnit, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         let m = random_mint_homogeneous_mat3(rng);
-        euclid::Transform2D::new(m.x.x, m.x.y, m.x.z, m.y.x, m.y.y, m.y.z)
+        // euclid uses row vectors, so the rows are the mint matrix columns
+        euclid::Transform2D::new(m.x.x, m.x.y, m.y.x, m.y.y, m.z.x, m.z.y)
     }
 
-    fn random_euclid_mat4<R>(
+    fn random_euclid_mat4<T, R>(
         rng: &mut R,
-    ) -> euclid::Transform3D<f32, euclid::UnknownUnit, euclid::UnknownUnit>
+    ) -> euclid::Transform3D<T, euclid::UnknownUnit, euclid::UnknownUnit>
     where
-        R: RngExt,
+        T: BenchScalar,
+        R: BenchRng,
     {
         let m = random_mint_homogeneous_mat4(rng);
         euclid::Transform3D::new(
@@ -1164,10 +2318,32 @@ pub mod vek_support {
     impl_bench_value!(vek::Vec2<f32>, random_mint_vec2);
     impl_bench_value!(vek::Vec3<f32>, random_mint_vec3);
     impl_bench_value!(vek::Vec4<f32>, random_mint_vec4);
+    impl_bench_value!(vek::Mat2<f64>, random_mint_invertible_mat2);
+    impl_bench_value!(vek::Mat3<f64>, random_mint_homogeneous_mat3);
+    impl_bench_value!(vek::Mat4<f64>, random_mint_homogeneous_mat4);
+    impl_bench_value!(vek::Quaternion<f64>, random_mint_quat);
+    impl_bench_value!(vek::Vec2<f64>, random_mint_vec2);
+    impl_bench_value!(vek::Vec3<f64>, random_mint_vec3);
+    impl_bench_value!(vek::Vec4<f64>, random_mint_vec4);
+
+    impl_components!(mint::Vector2<f32> => vek::Vec2<f32>);
+    impl_components!(mint::Vector3<f32> => vek::Vec3<f32>);
+    impl_components!(mint::Vector4<f32> => vek::Vec4<f32>);
+    impl_components!(mint::Quaternion<f32> => vek::Quaternion<f32>);
+    impl_components!(mint::ColumnMatrix2<f32> => vek::Mat2<f32>);
+    impl_components!(mint::ColumnMatrix3<f32> => vek::Mat3<f32>);
+    impl_components!(mint::ColumnMatrix4<f32> => vek::Mat4<f32>);
+    impl_components!(mint::Vector2<f64> => vek::Vec2<f64>);
+    impl_components!(mint::Vector3<f64> => vek::Vec3<f64>);
+    impl_components!(mint::Vector4<f64> => vek::Vec4<f64>);
+    impl_components!(mint::Quaternion<f64> => vek::Quaternion<f64>);
+    impl_components!(mint::ColumnMatrix2<f64> => vek::Mat2<f64>);
+    impl_components!(mint::ColumnMatrix3<f64> => vek::Mat3<f64>);
+    impl_components!(mint::ColumnMatrix4<f64> => vek::Mat4<f64>);
 
     // fn random_vek_invertible_mat4<R>(rng: &mut R) -> vek::mat::repr_simd::column_major::Mat4<f32>
     // where
-    //     R: RngExt,
+    //     R: BenchRng,
     // {
     //     let mm = random_homogeneous_mat4(rng);
     //     vek::mat::repr_simd::column_major::Mat4::from_col_array(mm.into())
@@ -1175,7 +2351,7 @@ pub mod vek_support {
 
     // fn random_vek_vec3<R>(rng: &mut R) -> vek::vec::repr_simd::Vec3<f32>
     // where
-    //     R: RngExt,
+    //     R: BenchRng,
     // {
     //     let v: [f32; 3] = rng.random::<glam::Vec3>().into();
     //     v.into()
@@ -1183,7 +2359,7 @@ pub mod vek_support {
 
     // fn random_vek_vec4<R>(rng: &mut R) -> vek::vec::repr_simd::Vec4<f32>
     // where
-    //     R: RngExt,
+    //     R: BenchRng,
     // {
     //     let v: [f32; 4] = rng.random::<glam::Vec4>().into();
     //     v.into()
@@ -1201,10 +2377,15 @@ pub mod vek_support {
 #[cfg(feature = "pathfinder_geometry")]
 pub mod pathfinder_support {
     use super::mint_support::*;
-    use super::BenchValue;
-    use rand::RngExt;
+    use super::{BenchRng, BenchValue, Components};
+    use pathfinder_geometry::{
+        transform2d::{Matrix2x2F, Transform2F},
+        transform3d::Transform4F,
+        vector::{Vector2F, Vector3F, Vector4F},
+    };
 
     impl_bench_value!(pathfinder_geometry::vector::Vector2F, random_pf_vec2);
+    impl_bench_value!(pathfinder_geometry::vector::Vector3F, random_pf_vec3);
     impl_bench_value!(pathfinder_geometry::vector::Vector4F, random_pf_vec4);
     impl_bench_value!(pathfinder_geometry::transform2d::Matrix2x2F, random_pf_mat2);
     impl_bench_value!(
@@ -1216,23 +2397,82 @@ pub mod pathfinder_support {
         random_pf_mat4
     );
 
+    impl Components for Vector2F {
+        fn components(&self) -> Vec<f64> {
+            vec![self.x().into(), self.y().into()]
+        }
+    }
+
+    impl Components for Vector3F {
+        fn components(&self) -> Vec<f64> {
+            vec![self.x().into(), self.y().into(), self.z().into()]
+        }
+    }
+
+    impl Components for Vector4F {
+        fn components(&self) -> Vec<f64> {
+            vec![
+                self.x().into(),
+                self.y().into(),
+                self.z().into(),
+                self.w().into(),
+            ]
+        }
+    }
+
+    // stored column major
+    impl Components for Matrix2x2F {
+        fn components(&self) -> Vec<f64> {
+            (0..4).map(|i| self.0[i].into()).collect()
+        }
+    }
+
+    impl Components for Transform2F {
+        fn components(&self) -> Vec<f64> {
+            let (m, v) = (self.matrix.0, self.vector);
+            [m[0], m[1], 0.0, m[2], m[3], 0.0, v.x(), v.y(), 1.0]
+                .iter()
+                .map(|&c| c.into())
+                .collect()
+        }
+    }
+
+    impl Components for Transform4F {
+        fn components(&self) -> Vec<f64> {
+            self.to_columns()
+                .iter()
+                .flat_map(|col| (0..4).map(move |i| col[i].into()))
+                .collect()
+        }
+    }
+
     pub fn random_pf_vec2<R>(rng: &mut R) -> pathfinder_geometry::vector::Vector2F
     where
-        R: RngExt,
+        R: BenchRng,
+    {
+        let v = random_mint_vec2(rng);
+        pathfinder_geometry::vector::Vector2F::new(v.x, v.y)
+    }
+
+    pub fn random_pf_vec3<R>(rng: &mut R) -> pathfinder_geometry::vector::Vector3F
+    where
+        R: BenchRng,
     {
-        pathfinder_geometry::vector::Vector2F::new(rng.random(), rng.random())
+        let v = random_mint_vec3(rng);
+        pathfinder_geometry::vector::Vector3F::new(v.x, v.y, v.z)
     }
 
     pub fn random_pf_vec4<R>(rng: &mut R) -> pathfinder_geometry::vector::Vector4F
     where
-        R: RngExt,
+        R: BenchRng,
     {
-        pathfinder_geometry::vector::Vector4F::new(rng.random(), rng.random(), rng.random(), rng.random())
+        let v = random_mint_vec4(rng);
+        pathfinder_geometry::vector::Vector4F::new(v.x, v.y, v.z, v.w)
     }
 
     pub fn random_pf_mat2<R>(rng: &mut R) -> pathfinder_geometry::transform2d::Matrix2x2F
     where
-        R: RngExt,
+        R: BenchRng,
     {
         let mat = random_mint_invertible_mat2(rng);
         pathfinder_geometry::transform2d::Matrix2x2F::row_major(mat.x.x, mat.y.x, mat.x.y, mat.y.y)
@@ -1240,17 +2480,17 @@ pub mod pathfinder_support {
 
     pub fn random_pf_mat3<R>(rng: &mut R) -> pathfinder_geometry::transform2d::Transform2F
     where
-        R: RngExt,
+        R: BenchRng,
     {
         let mat = random_mint_homogeneous_mat3(rng);
         pathfinder_geometry::transform2d::Transform2F::row_major(
-            mat.x.x, mat.y.x, mat.x.y, mat.y.y, mat.x.z, mat.y.z,
+            mat.x.x, mat.y.x, mat.x.y, mat.y.y, mat.z.x, mat.z.y,
         )
     }
 
     pub fn random_pf_mat4<R>(rng: &mut R) -> pathfinder_geometry::transform3d::Transform4F
     where
-        R: RngExt,
+        R: BenchRng,
     {
         let mat = random_mint_homogeneous_mat4(rng);
         pathfinder_geometry::transform3d::Transform4F::row_major(
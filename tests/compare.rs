@@ -0,0 +1,98 @@
+mod support;
+use support::{ulps_distance, FloatCompare};
+
+#[test]
+fn test_ulps_distance() {
+    assert_eq!(ulps_distance(1.0, 1.0), 0);
+    assert_eq!(ulps_distance(1.0, 1.0_f32.next_up()), 1);
+    assert_eq!(ulps_distance(1.0_f32.next_down(), 1.0_f32.next_up()), 2);
+    assert_eq!(ulps_distance(0.0, -0.0), 0);
+    // the smallest subnormals either side of zero
+    assert_eq!(ulps_distance(-f32::from_bits(1), f32::from_bits(1)), 2);
+    assert_eq!(ulps_distance(f32::MAX, f32::INFINITY), 1);
+    assert_eq!(ulps_distance(f32::NAN, 1.0), u64::MAX);
+}
+
+#[test]
+fn test_ulps_eq_scales_with_magnitude() {
+    // 1.0 apart, but adjacent floats at this magnitude
+    let (a, b) = (16_777_216.0_f32, 16_777_218.0_f32);
+    assert!(!a.approx_eq(&b, f32::EPSILON));
+    assert!(a.ulps_eq(&b, 1));
+    assert!(a.relative_eq(&b, f32::EPSILON));
+
+    // well within f32::EPSILON, but far apart relative to their magnitude
+    let (a, b) = (1e-10_f32, 2e-10_f32);
+    assert!(a.approx_eq(&b, f32::EPSILON));
+    assert!(!a.ulps_eq(&b, 4));
+    assert!(!a.relative_eq(&b, 4.0 * f32::EPSILON));
+}
+
+#[test]
+fn test_compare_modes_check_every_component() {
+    let a = glam::Mat4::from_cols_array(&[
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0,
+    ]);
+    let mut b = a;
+    b.z_axis.y = b.z_axis.y.next_up().next_up();
+
+    assert!(a.ulps_eq(&b, 2));
+    assert!(!a.ulps_eq(&b, 1));
+    let worst = a.worst_component(&b, |a, b| ulps_distance(a, b) as f64);
+    assert_eq!(
+        (worst.index, worst.left, worst.right),
+        (9, 10.0, b.z_axis.y)
+    );
+
+    let (ma, mb): (mint::ColumnMatrix4<f32>, mint::ColumnMatrix4<f32>) = (a.into(), b.into());
+    assert!(ma.ulps_eq(&mb, 2) && !ma.ulps_eq(&mb, 1));
+    let (ca, cb): (cgmath::Matrix4<f32>, cgmath::Matrix4<f32>) = (ma.into(), mb.into());
+    assert!(ca.ulps_eq(&cb, 2) && !ca.ulps_eq(&cb, 1));
+    let (na, nb): (nalgebra::Matrix4<f32>, nalgebra::Matrix4<f32>) = (ma.into(), mb.into());
+    assert!(na.ulps_eq(&nb, 2) && !na.ulps_eq(&nb, 1));
+}
+
+#[test]
+fn test_quaternion_components_are_xyzw() {
+    let q = glam::Quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+    let mq: mint::Quaternion<f32> = q.into();
+    let cq: cgmath::Quaternion<f32> = mq.into();
+    let nq: nalgebra::Quaternion<f32> = mq.into();
+    assert_eq!(q.components(), vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(mq.components(), q.components());
+    assert_eq!(cq.components(), q.components());
+    assert_eq!(nq.components(), q.components());
+}
+
+#[test]
+fn test_assert_ulps_eq() {
+    let v = glam::Vec3::new(1e6, 2e-6, 3.0);
+    assert_ulps_eq!(v, v * (1.0 + f32::EPSILON), max_ulps = 2);
+    assert_relative_eq!(v, v * (1.0 + f32::EPSILON));
+}
+
+#[test]
+#[should_panic(expected = "worst component 1: left = 2e-6, right = 0.0, 906377149 ulps apart")]
+fn test_assert_ulps_eq_reports_worst_component() {
+    let v = glam::Vec3::new(1e6, 2e-6, 3.0);
+    assert_ulps_eq!(v, glam::Vec3::new(1e6, 0.0, 3.0));
+}
+
+#[test]
+#[should_panic(expected = "compared values have different numbers of components")]
+fn test_compare_modes_require_matching_components() {
+    // a wide value with a lane missing from the reference
+    struct TwoLanes([f32; 2]);
+    impl FloatCompare<[f32; 1]> for TwoLanes {
+        fn approx_eq(&self, other: &[f32; 1], max_abs_diff: f32) -> bool {
+            self.0[0].approx_eq(&other[0], max_abs_diff)
+        }
+        fn abs_diff(&self, other: &[f32; 1]) -> [f32; 1] {
+            [self.0[0].abs_diff(&other[0])]
+        }
+        fn components(&self) -> Vec<f32> {
+            self.0.to_vec()
+        }
+    }
+    TwoLanes([1.0, 2.0]).ulps_eq(&[1.0], 0);
+}
@@ -0,0 +1,33 @@
+// the benchmarks' environment recording, which isn't otherwise run by tests
+#[allow(dead_code)]
+#[path = "../benches/support/environment.rs"]
+mod environment;
+
+fn baselines(args: &[&str]) -> Vec<String> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    environment::baselines(&args)
+}
+
+#[test]
+fn baselines_mirror_criterion_options() {
+    assert_eq!(baselines(&["--bench"]), ["new", "base"]);
+    assert_eq!(
+        baselines(&["--bench", "--save-baseline", "main"]),
+        ["new", "main"]
+    );
+    assert_eq!(
+        baselines(&["--bench", "--save-baseline=main"]),
+        ["new", "main"]
+    );
+    assert_eq!(
+        baselines(&["--bench", "-s", "main", "inverse"]),
+        ["new", "main"]
+    );
+    // comparing with or discarding a baseline doesn't save one
+    assert_eq!(baselines(&["--bench", "--baseline", "main"]), ["new"]);
+    assert_eq!(baselines(&["--bench", "-b", "main"]), ["new"]);
+    assert_eq!(baselines(&["--bench", "--baseline-lenient=main"]), ["new"]);
+    assert_eq!(baselines(&["--bench", "--discard-baseline"]), ["new"]);
+    // the filter isn't mistaken for a baseline name
+    assert_eq!(baselines(&["--bench", "main"]), ["new", "base"]);
+}
@@ -0,0 +1,104 @@
+use mathbench::{BenchValue, InputDistribution};
+use std::convert::TryFrom;
+
+#[test]
+fn input_distribution_names_round_trip() {
+    for distribution in InputDistribution::ALL.iter().copied() {
+        assert_eq!(
+            InputDistribution::try_from(distribution.as_str()),
+            Ok(distribution)
+        );
+    }
+    assert!(InputDistribution::try_from("gaussian").is_err());
+}
+
+#[test]
+fn libraries_get_identical_inputs_for_each_distribution() {
+    for distribution in InputDistribution::ALL.iter().copied() {
+        let mut glam_rng = mathbench::rng_with("inverse", distribution);
+        let mut cgmath_rng = mathbench::rng_with("inverse", distribution);
+        let mut nalgebra_rng = mathbench::rng_with("inverse", distribution);
+        for _ in 0..64 {
+            let glam_m: mint::ColumnMatrix4<f32> = glam::Mat4::random_value(&mut glam_rng).into();
+            let cgmath_m: mint::ColumnMatrix4<f32> =
+                cgmath::Matrix4::<f32>::random_value(&mut cgmath_rng).into();
+            let nalgebra_m: mint::ColumnMatrix4<f32> =
+                nalgebra::Matrix4::<f32>::random_value(&mut nalgebra_rng).into();
+            assert_eq!(glam_m, cgmath_m);
+            assert_eq!(glam_m, nalgebra_m);
+        }
+    }
+}
+
+#[test]
+fn uniform_inputs_are_in_unit_range() {
+    use rand::SeedableRng;
+    let mut input_rng = mathbench::rng_with("vec", InputDistribution::Uniform);
+    // plain generators always draw uniform inputs
+    let mut plain_rng = rand_pcg::Pcg64Mcg::seed_from_u64(0);
+    for _ in 0..64 {
+        for v in [
+            glam::Vec4::random_value(&mut input_rng),
+            glam::Vec4::random_value(&mut plain_rng),
+        ] {
+            assert!(v.cmpge(glam::Vec4::ZERO).all() && v.cmplt(glam::Vec4::ONE).all());
+        }
+    }
+}
+
+#[test]
+fn wide_range_inputs_are_large() {
+    let mut rng = mathbench::rng_with("vec", InputDistribution::WideRange);
+    let max = (0..64)
+        .map(|_| glam::Vec3::random_value(&mut rng).abs().max_element())
+        .fold(0.0, f32::max);
+    assert!(max > 1e4, "max component {}", max);
+}
+
+#[test]
+fn ill_conditioned_matrices_are_nearly_singular() {
+    let mut rng = mathbench::rng_with("inverse", InputDistribution::IllConditioned);
+    let count = 256;
+    let ill_conditioned = (0..count)
+        .filter(|_| {
+            let m = nalgebra::Matrix3::<f64>::random_value(&mut rng);
+            let singular_values = m.singular_values();
+            singular_values.max() / singular_values.min() > 1e4
+        })
+        .count();
+    assert!(
+        ill_conditioned * 10 > count * 9,
+        "{} of {}",
+        ill_conditioned,
+        count
+    );
+}
+
+#[test]
+fn near_identity_matrices_are_close_to_identity() {
+    let mut rng = mathbench::rng_with("inverse", InputDistribution::NearIdentity);
+    for _ in 0..64 {
+        let m = glam::Mat4::random_value(&mut rng);
+        assert!(m.abs_diff_eq(glam::Mat4::IDENTITY, 0.01), "{:?}", m);
+        let q = glam::Quat::random_value(&mut rng);
+        assert!(q.abs_diff_eq(glam::Quat::IDENTITY, 0.01), "{:?}", q);
+    }
+}
+
+#[test]
+fn subnormal_inputs_contain_subnormals() {
+    let mut rng = mathbench::rng_with("vec", InputDistribution::Subnormal);
+    let components: Vec<f32> = (0..64)
+        .flat_map(|_| glam::Vec4::random_value(&mut rng).to_array())
+        .collect();
+    let subnormals = components
+        .iter()
+        .filter(|component| component.is_subnormal())
+        .count();
+    assert!(
+        subnormals > components.len() / 4,
+        "{} subnormals",
+        subnormals
+    );
+    assert!(subnormals < components.len(), "{} subnormals", subnormals);
+}
@@ -1,9 +1,8 @@
 mod support;
-use cgmath;
-use glam;
 use mathbench::mint_support::*;
-use nalgebra;
 use rand_pcg::Pcg64Mcg;
+#[cfg(any(feature = "euclid", feature = "pathfinder_geometry"))]
+use support::FromMint;
 
 const NUM_ITERS: usize = 1024;
 
@@ -40,12 +39,25 @@ fn mat2_mul_vec2_compare() {
     let cv: cgmath::Vector2<f32> = mv.into();
     let cmv = cm * cv;
 
+    #[cfg(feature = "vek")]
+    let vmv = vek::Mat2::<f32>::from(mm) * vek::Vec2::<f32>::from(mv);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    let pmv = {
+        use pathfinder_geometry::{transform2d::Matrix2x2F, vector::Vector2F};
+        Matrix2x2F::from_mint(mm) * Vector2F::from_mint(mv)
+    };
+
     // use nalgebra as assumed correct answer
     let mmv: mint::Vector2<f32> = nmv.into();
 
-    assert_ulps_eq!(cmv, mmv.into());
-    assert_ulps_eq!(gmv, mmv.into());
-    assert_ulps_eq!(umv, mmv, epsilon = 0.0005);
+    assert_abs_diff_eq!(cmv, mmv.into());
+    assert_abs_diff_eq!(gmv, mmv.into());
+    assert_abs_diff_eq!(umv, mmv, epsilon = 0.0005);
+    #[cfg(feature = "vek")]
+    assert_ulps_eq!(vmv, mmv.into());
+    #[cfg(feature = "pathfinder_geometry")]
+    assert_abs_diff_eq!(pmv, FromMint::from_mint(mmv));
 }
 
 fn mat3_mul_vec3_compare() {
@@ -69,12 +81,17 @@ fn mat3_mul_vec3_compare() {
     let cv: cgmath::Vector3<f32> = mv.into();
     let cmv = cm * cv;
 
+    #[cfg(feature = "vek")]
+    let vmv = vek::Mat3::<f32>::from(mm) * vek::Vec3::<f32>::from(mv);
+
     // use nalgebra as assumed correct answer
     let mmv: mint::Vector3<f32> = nmv.into();
 
-    assert_ulps_eq!(cmv, mmv.into());
-    assert_ulps_eq!(gmv, mmv.into());
-    assert_ulps_eq!(umv, mmv, epsilon = 0.0005);
+    assert_abs_diff_eq!(cmv, mmv.into());
+    assert_abs_diff_eq!(gmv, mmv.into());
+    assert_abs_diff_eq!(umv, mmv, epsilon = 0.0005);
+    #[cfg(feature = "vek")]
+    assert_ulps_eq!(vmv, mmv.into());
 }
 
 fn mat4_mul_vec4_compare() {
@@ -98,12 +115,17 @@ fn mat4_mul_vec4_compare() {
     let cv: cgmath::Vector4<f32> = mv.into();
     let cmv = cm * cv;
 
+    #[cfg(feature = "vek")]
+    let vmv = vek::Mat4::<f32>::from(mm) * vek::Vec4::<f32>::from(mv);
+
     // use nalgebra as assumed correct answer
     let mmv: mint::Vector4<f32> = nmv.into();
 
-    assert_ulps_eq!(cmv, mmv.into());
-    assert_ulps_eq!(gmv, mmv.into());
-    assert_ulps_eq!(umv, mmv, epsilon = 0.0005);
+    assert_abs_diff_eq!(cmv, mmv.into());
+    assert_abs_diff_eq!(gmv, mmv.into());
+    assert_abs_diff_eq!(umv, mmv, epsilon = 0.0005);
+    #[cfg(feature = "vek")]
+    assert_ulps_eq!(vmv, mmv.into());
 }
 
 fn mat2_mul_mat2_compare() {
@@ -127,12 +149,25 @@ fn mat2_mul_mat2_compare() {
     let cm2: cgmath::Matrix2<f32> = mm2.into();
     let cm3 = cm1 * cm2;
 
+    #[cfg(feature = "vek")]
+    let vm3 = vek::Mat2::<f32>::from(mm1) * vek::Mat2::<f32>::from(mm2);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    let pm3 = {
+        use pathfinder_geometry::transform2d::Matrix2x2F;
+        Matrix2x2F::from_mint(mm1) * Matrix2x2F::from_mint(mm2)
+    };
+
     // use nalgebra as assumed correct answer
     let mm3: mint::ColumnMatrix2<f32> = nm3.into();
 
-    assert_ulps_eq!(cm3, mm3.into());
-    assert_ulps_eq!(gm3, mm3.into());
-    assert_ulps_eq!(um3, mm3, epsilon = 0.0005);
+    assert_abs_diff_eq!(cm3, mm3.into());
+    assert_abs_diff_eq!(gm3, mm3.into());
+    assert_abs_diff_eq!(um3, mm3, epsilon = 0.0005);
+    #[cfg(feature = "vek")]
+    assert_ulps_eq!(vm3, mm3.into());
+    #[cfg(feature = "pathfinder_geometry")]
+    assert_abs_diff_eq!(pm3, FromMint::from_mint(mm3));
 }
 
 fn mat3_mul_mat3_compare() {
@@ -156,12 +191,17 @@ fn mat3_mul_mat3_compare() {
     let cm2: cgmath::Matrix3<f32> = mm2.into();
     let cm3 = cm1 * cm2;
 
+    #[cfg(feature = "vek")]
+    let vm3 = vek::Mat3::<f32>::from(mm1) * vek::Mat3::<f32>::from(mm2);
+
     // use nalgebra as assumed correct answer
     let mm3: mint::ColumnMatrix3<f32> = nm3.into();
 
-    assert_ulps_eq!(cm3, mm3.into());
-    assert_ulps_eq!(gm3, mm3.into());
-    assert_ulps_eq!(um3, mm3, epsilon = 0.0005);
+    assert_abs_diff_eq!(cm3, mm3.into());
+    assert_abs_diff_eq!(gm3, mm3.into());
+    assert_abs_diff_eq!(um3, mm3, epsilon = 0.0005);
+    #[cfg(feature = "vek")]
+    assert_ulps_eq!(vm3, mm3.into());
 }
 
 fn mat4_mul_mat4_compare() {
@@ -185,12 +225,34 @@ fn mat4_mul_mat4_compare() {
     let cm2: cgmath::Matrix4<f32> = mm2.into();
     let cm3 = cm1 * cm2;
 
+    // euclid uses row vectors, so `a.then(b)` is `b * a` for column vectors
+    #[cfg(feature = "euclid")]
+    let em3 = {
+        use euclid::default::Transform3D;
+        Transform3D::from_mint(mm2).then(&Transform3D::from_mint(mm1))
+    };
+
+    #[cfg(feature = "vek")]
+    let vm3 = vek::Mat4::<f32>::from(mm1) * vek::Mat4::<f32>::from(mm2);
+
+    #[cfg(feature = "pathfinder_geometry")]
+    let pm3 = {
+        use pathfinder_geometry::transform3d::Transform4F;
+        Transform4F::from_mint(mm1) * Transform4F::from_mint(mm2)
+    };
+
     // use nalgebra as assumed correct answer
     let mm3: mint::ColumnMatrix4<f32> = nm3.into();
 
-    assert_ulps_eq!(cm3, mm3.into());
-    assert_ulps_eq!(gm3, mm3.into());
-    assert_ulps_eq!(um3, mm3, epsilon = 0.0005);
+    assert_abs_diff_eq!(cm3, mm3.into());
+    assert_abs_diff_eq!(gm3, mm3.into());
+    assert_abs_diff_eq!(um3, mm3, epsilon = 0.0005);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(em3, FromMint::from_mint(mm3));
+    #[cfg(feature = "vek")]
+    assert_ulps_eq!(vm3, mm3.into());
+    #[cfg(feature = "pathfinder_geometry")]
+    assert_abs_diff_eq!(pm3, FromMint::from_mint(mm3));
 }
 
 fn mat2_det_compare() {
@@ -211,10 +273,83 @@ fn mat2_det_compare() {
     let cm1: cgmath::Matrix2<f32> = mm1.into();
     let cmd = cm1.determinant();
 
+    #[cfg(feature = "vek")]
+    let vmd = vek::Mat2::<f32>::from(mm1).determinant();
+
+    #[cfg(feature = "pathfinder_geometry")]
+    let pmd = pathfinder_geometry::transform2d::Matrix2x2F::from_mint(mm1).det();
+
+    // use nalgebra as assumed correct answer
+    assert_abs_diff_eq!(cmd, nmd);
+    assert_abs_diff_eq!(gmd, nmd);
+    assert_abs_diff_eq!(umd, nmd, epsilon = 0.0005);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vmd, nmd);
+    #[cfg(feature = "pathfinder_geometry")]
+    assert_abs_diff_eq!(pmd, nmd);
+}
+
+fn mat3_det_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat3(&mut rng);
+
+    let gm1: glam::Mat3 = mm1.into();
+    let gmd = gm1.determinant();
+
+    let um1: ultraviolet::Mat3 = mm1.into();
+    let umd = um1.determinant();
+
+    let nm1: nalgebra::Matrix3<f32> = mm1.into();
+    let nmd = nm1.determinant();
+
+    let cm1: cgmath::Matrix3<f32> = mm1.into();
+    let cmd = cm1.determinant();
+
+    #[cfg(feature = "vek")]
+    let vmd = vek::Mat3::<f32>::from(mm1).determinant();
+
+    // use nalgebra as assumed correct answer
+    assert_abs_diff_eq!(cmd, nmd, epsilon = 1e-6);
+    assert_abs_diff_eq!(gmd, nmd, epsilon = 1e-6);
+    assert_abs_diff_eq!(umd, nmd, epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vmd, nmd, epsilon = 1e-6);
+}
+
+fn mat4_det_compare() {
+    use cgmath::prelude::*;
+
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = random_mint_mat4(&mut rng);
+
+    let gm1: glam::Mat4 = mm1.into();
+    let gmd = gm1.determinant();
+
+    let um1: ultraviolet::Mat4 = mm1.into();
+    let umd = um1.determinant();
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmd = nm1.determinant();
+
+    let cm1: cgmath::Matrix4<f32> = mm1.into();
+    let cmd = cm1.determinant();
+
+    #[cfg(feature = "euclid")]
+    let emd = euclid::default::Transform3D::from_mint(mm1).determinant();
+
+    #[cfg(feature = "vek")]
+    let vmd = vek::Mat4::<f32>::from(mm1).determinant();
+
     // use nalgebra as assumed correct answer
-    assert_ulps_eq!(cmd, nmd);
-    assert_ulps_eq!(gmd, nmd);
-    assert_ulps_eq!(umd, nmd, epsilon = 0.0005);
+    assert_abs_diff_eq!(cmd, nmd, epsilon = 1e-5);
+    assert_abs_diff_eq!(gmd, nmd, epsilon = 1e-5);
+    assert_abs_diff_eq!(umd, nmd, epsilon = 1e-5);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(emd, nmd, epsilon = 1e-5);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vmd, nmd, epsilon = 1e-5);
 }
 
 fn mat2_inv_compare() {
@@ -238,14 +373,19 @@ fn mat2_inv_compare() {
     let cmi = cm1.invert();
     assert!(cmi.is_some());
 
+    #[cfg(feature = "pathfinder_geometry")]
+    let pmi = pathfinder_geometry::transform2d::Matrix2x2F::from_mint(mm1).inverse();
+
     // use nalgebra as assumed correct answer
     let mmi: mint::ColumnMatrix2<f32> = nmi.unwrap().into();
 
-    assert_ulps_eq!(cmi.unwrap(), mmi.into());
+    assert_abs_diff_eq!(cmi.unwrap(), mmi.into());
     // TODO: actually make a ulps test
-    assert_ulps_eq!(gmi, mmi.into(), epsilon = 1e-2);
+    assert_abs_diff_eq!(gmi, mmi.into(), epsilon = 1e-2);
     // necessary because ultraviolet uses FMAs which eat less rounding error especially for large numbers
-    assert_ulps_eq!(umi, mmi, epsilon = 0.0005f32.max(0.0001 * nmn));
+    assert_abs_diff_eq!(umi, mmi, epsilon = 0.0005f32.max(0.0001 * nmn));
+    #[cfg(feature = "pathfinder_geometry")]
+    assert_abs_diff_eq!(pmi, FromMint::from_mint(mmi), epsilon = 1e-2);
 }
 
 fn mat3_inv_compare() {
@@ -271,9 +411,9 @@ fn mat3_inv_compare() {
     // use nalgebra as assumed correct answer
     let mmi: mint::ColumnMatrix3<f32> = nmi.unwrap().into();
 
-    assert_ulps_eq!(cmi.unwrap(), mmi.into());
-    assert_ulps_eq!(gmi, mmi.into(), epsilon = 0.0001);
-    assert_ulps_eq!(umi, mmi, epsilon = 0.0005);
+    assert_abs_diff_eq!(cmi.unwrap(), mmi.into());
+    assert_abs_diff_eq!(gmi, mmi.into(), epsilon = 0.0001);
+    assert_abs_diff_eq!(umi, mmi, epsilon = 0.0005);
 }
 
 fn mat4_inv_compare() {
@@ -296,12 +436,54 @@ fn mat4_inv_compare() {
     let cmi = cm1.invert();
     assert!(cmi.is_some());
 
+    #[cfg(feature = "euclid")]
+    let emi = euclid::default::Transform3D::from_mint(mm1).inverse();
+    #[cfg(feature = "euclid")]
+    assert!(emi.is_some());
+
+    #[cfg(feature = "vek")]
+    let vmi = vek::Mat4::<f32>::from(mm1).inverted();
+
     // use nalgebra as assumed correct answer
     let mmi: mint::ColumnMatrix4<f32> = nmi.unwrap().into();
 
-    assert_ulps_eq!(cmi.unwrap(), mmi.into(), epsilon = 0.0001);
-    assert_ulps_eq!(gmi, mmi.into(), epsilon = 0.0001);
-    assert_ulps_eq!(umi, mmi, epsilon = 0.0005);
+    assert_abs_diff_eq!(cmi.unwrap(), mmi.into(), epsilon = 0.0001);
+    assert_abs_diff_eq!(gmi, mmi.into(), epsilon = 0.0001);
+    assert_abs_diff_eq!(umi, mmi, epsilon = 0.0005);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(emi.unwrap(), FromMint::from_mint(mmi), epsilon = 0.0001);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vmi, mmi.into(), epsilon = 0.0001);
+}
+
+// pathfinder inverts the 2x2 blocks of the matrix, which assumes the upper left
+// block is well conditioned and loses most of the precision when it isn't. It's
+// compared on matrices whose upper left block has a condition number below 10,
+// where its error is about 1e-5.
+#[cfg(feature = "pathfinder_geometry")]
+fn pathfinder_mat4_inv_compare() {
+    let mut rng = Pcg64Mcg::new(rand::random());
+    let mm1 = loop {
+        let m: mint::ColumnMatrix4<f32> = random_mint_homogeneous_mat4(&mut rng);
+        let (a, b, c, d) = (m.x.x, m.y.x, m.x.y, m.y.y);
+        // the Frobenius norm condition number of a 2x2 matrix
+        let condition = (a * a + b * b + c * c + d * d) / (a * d - b * c).abs();
+        if condition < 10.0 {
+            break m;
+        }
+    };
+
+    let nm1: nalgebra::Matrix4<f32> = mm1.into();
+    let nmi = nm1.try_inverse();
+    assert!(nmi.is_some());
+
+    let pm1 = pathfinder_geometry::transform3d::Transform4F::from_mint(mm1);
+    let pmi = pm1.inverse();
+
+    // use nalgebra as assumed correct answer
+    let mmi: mint::ColumnMatrix4<f32> = nmi.unwrap().into();
+
+    assert_abs_diff_eq!(pmi, FromMint::from_mint(mmi), epsilon = 0.0001);
 }
 
 #[test]
@@ -353,6 +535,20 @@ fn test_mat2_det() {
     }
 }
 
+#[test]
+fn test_mat3_det() {
+    for _ in 0..NUM_ITERS {
+        mat3_det_compare();
+    }
+}
+
+#[test]
+fn test_mat4_det() {
+    for _ in 0..NUM_ITERS {
+        mat4_det_compare();
+    }
+}
+
 #[test]
 fn test_mat2_inverse() {
     for _ in 0..NUM_ITERS {
@@ -373,3 +569,11 @@ fn test_mat4_inverse() {
         mat4_inv_compare();
     }
 }
+
+#[cfg(feature = "pathfinder_geometry")]
+#[test]
+fn test_pathfinder_mat4_inverse() {
+    for _ in 0..NUM_ITERS {
+        pathfinder_mat4_inv_compare();
+    }
+}
@@ -1,9 +1,8 @@
 mod support;
-use cgmath;
-use glam;
 use mathbench::mint_support::{random_mint_quat, random_mint_vec3};
-use nalgebra;
 use rand_pcg::Pcg64Mcg;
+#[cfg(feature = "euclid")]
+use support::FromMint;
 
 const NUM_ITERS: usize = 1024;
 
@@ -24,11 +23,29 @@ fn quat_mul_vec3_compare() {
     let cv: cgmath::Vector3<f32> = mv.into();
     let cqv = cq * cv;
 
+    let uq: ultraviolet::Rotor3 = mq.into();
+    let uv: ultraviolet::Vec3 = mv.into();
+    let uqv: mint::Vector3<f32> = (uq * uv).into();
+
+    #[cfg(feature = "euclid")]
+    let eqv = {
+        use euclid::default::{Rotation3D, Vector3D};
+        Rotation3D::from_mint(mq).transform_vector3d(Vector3D::from_mint(mv))
+    };
+
+    #[cfg(feature = "vek")]
+    let vqv = vek::Quaternion::<f32>::from(mq) * vek::Vec3::<f32>::from(mv);
+
     // use nalgebra as assumed correct answer
     let mqv: mint::Vector3<f32> = nqv.into();
 
-    assert_ulps_eq!(cqv, mqv.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gqv, mqv.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(cqv, mqv.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(gqv, mqv.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(uqv, mqv, epsilon = 1e-6);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(eqv, FromMint::from_mint(mqv), epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vqv, mqv.into(), epsilon = 1e-6);
 }
 
 fn quat_mul_quat_compare() {
@@ -48,11 +65,30 @@ fn quat_mul_quat_compare() {
     let cq2: cgmath::Quaternion<f32> = mq2.into();
     let cq3 = cq1 * cq2;
 
+    let uq1: ultraviolet::Rotor3 = mq1.into();
+    let uq2: ultraviolet::Rotor3 = mq2.into();
+    let uq3: mint::Quaternion<f32> = (uq1 * uq2).into();
+
+    // euclid `a.then(b)` applies `a` first, which is `b * a` for quaternions
+    #[cfg(feature = "euclid")]
+    let eq3 = {
+        use euclid::default::Rotation3D;
+        Rotation3D::from_mint(mq2).then(&Rotation3D::from_mint(mq1))
+    };
+
+    #[cfg(feature = "vek")]
+    let vq3 = vek::Quaternion::<f32>::from(mq1) * vek::Quaternion::<f32>::from(mq2);
+
     // use nalgebra as assumed correct answer
     let mq3: mint::Quaternion<f32> = nq3.into();
 
-    assert_ulps_eq!(cq3, mq3.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gq3, mq3.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(cq3, mq3.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(gq3, mq3.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(uq3, mq3, epsilon = 1e-6);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(eq3, FromMint::from_mint(mq3), epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vq3, mq3.into(), epsilon = 1e-6);
 }
 
 #[test]
@@ -69,16 +105,16 @@ fn test_quat_from_axis_angle() {
     let cq = cgmath::Quaternion::from_axis_angle(cx, cgmath::Rad(rad));
     let mnq: mint::Quaternion<f32> = nq.into();
     let mcq: mint::Quaternion<f32> = cq.into();
-    assert_ulps_eq!(gq, mnq.into());
-    assert_ulps_eq!(gq, mcq.into());
+    assert_abs_diff_eq!(gq, mnq.into());
+    assert_abs_diff_eq!(gq, mcq.into());
 
     let gqv = gq * glam::Vec3::Y;
     let nqv = nq * nalgebra::Vector3::y_axis();
     let cqv = cq * cgmath::Vector3::unit_y();
     let mnqv: mint::Vector3<f32> = nqv.into_inner().into();
     let mcqv: mint::Vector3<f32> = cqv.into();
-    assert_ulps_eq!(gqv, glam::Vec3::from(mnqv), epsilon = 1e-5);
-    assert_ulps_eq!(gqv, glam::Vec3::from(mcqv), epsilon = 1e-5);
+    assert_abs_diff_eq!(gqv, glam::Vec3::from(mnqv), epsilon = 1e-5);
+    assert_abs_diff_eq!(gqv, glam::Vec3::from(mcqv), epsilon = 1e-5);
 }
 
 #[test]
@@ -0,0 +1,70 @@
+// the benchmarks' input streams, which aren't otherwise run by tests
+#[path = "../benches/support/check.rs"]
+mod check;
+#[allow(dead_code)]
+#[path = "../benches/support/environment.rs"]
+mod environment;
+
+use mathbench::BenchValue;
+use rand::RngExt;
+
+#[test]
+fn rng_streams_are_deterministic() {
+    let mut rng1 = mathbench::rng("inverse");
+    let mut rng2 = mathbench::rng("inverse");
+    for _ in 0..64 {
+        assert_eq!(rng1.random::<u64>(), rng2.random::<u64>());
+    }
+}
+
+#[test]
+fn rng_streams_are_independent() {
+    let mut rng1 = mathbench::rng("inverse");
+    let mut rng2 = mathbench::rng("transpose");
+    let values1: Vec<u64> = (0..8).map(|_| rng1.random()).collect();
+    let values2: Vec<u64> = (0..8).map(|_| rng2.random()).collect();
+    assert_ne!(values1, values2);
+}
+
+#[test]
+fn libraries_in_a_group_get_identical_inputs() {
+    // each library transforms vectors with a differently named method
+    let mut c = criterion::Criterion::default();
+    let group = check::benchmark_group(&mut c, "scalar transform vector3");
+    let rng = |lib| {
+        check::library(lib);
+        check::rng()
+    };
+    let (mut glam_rng, mut cgmath_rng, mut nalgebra_rng, mut ultraviolet_rng) = (
+        rng("glam"),
+        rng("cgmath"),
+        rng("nalgebra"),
+        rng("ultraviolet"),
+    );
+    for _ in 0..64 {
+        let glam_m: mint::ColumnMatrix4<f32> =
+            glam::Mat4::from(glam::Affine3A::random_value(&mut glam_rng)).into();
+        let glam_v: mint::Vector3<f32> = glam::Vec3A::random_value(&mut glam_rng).into();
+        let cgmath_m: mint::ColumnMatrix4<f32> =
+            cgmath::Matrix4::<f32>::random_value(&mut cgmath_rng).into();
+        let cgmath_v: mint::Vector3<f32> =
+            cgmath::Vector3::<f32>::random_value(&mut cgmath_rng).into();
+        let nalgebra_m: mint::ColumnMatrix4<f32> =
+            nalgebra::Transform3::<f32>::random_value(&mut nalgebra_rng)
+                .into_inner()
+                .into();
+        let nalgebra_v: mint::Vector3<f32> =
+            nalgebra::Vector3::<f32>::random_value(&mut nalgebra_rng).into();
+        let ultraviolet_m: mint::ColumnMatrix4<f32> =
+            ultraviolet::Mat4::random_value(&mut ultraviolet_rng).into();
+        let ultraviolet_v: mint::Vector3<f32> =
+            ultraviolet::Vec3::random_value(&mut ultraviolet_rng).into();
+        assert_eq!(glam_m, cgmath_m);
+        assert_eq!(glam_m, nalgebra_m);
+        assert_eq!(glam_m, ultraviolet_m);
+        assert_eq!(glam_v, cgmath_v);
+        assert_eq!(glam_v, nalgebra_v);
+        assert_eq!(glam_v, ultraviolet_v);
+    }
+    group.finish();
+}
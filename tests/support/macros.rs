@@ -1,31 +1,75 @@
+// the trait is named explicitly in case a library type has an inherent method
+// of the same name, such as `approx_eq` on euclid and pathfinder types
+/// Asserts that two values are equal within an absolute tolerance per
+/// component, `f32::EPSILON` by default.
 #[macro_export]
-macro_rules! assert_ulps_eq {
-    ($a:expr, $b:expr) => {{
-        use support::FloatCompare;
-        let eps = core::f32::EPSILON;
+macro_rules! assert_abs_diff_eq {
+    ($a:expr, $b:expr) => {
+        assert_abs_diff_eq!($a, $b, epsilon = f32::EPSILON)
+    };
+    ($a:expr, $b:expr, epsilon = $eps:expr) => {{
+        use $crate::support::FloatCompare;
         let (a, b) = (&$a, &$b);
+        let eps = $eps;
         assert!(
-            a.approx_eq(b, eps),
+            FloatCompare::approx_eq(a, b, eps),
             "assertion failed: `(left !== right)` \
-             (left: `{:#?}`, \nright: `{:#?}`, \nexpect diff: `{:#?}`, \nreal diff: `{:#?}`)",
+             (left: `{:#?}`, \nright: `{:#?}`, \nexpect diff: `{:#?}`, \nreal diff: `{:#?}`, \
+             \nworst {})",
             *a,
             *b,
             eps,
-            a.abs_diff(b)
+            FloatCompare::abs_diff(a, b),
+            FloatCompare::worst_component(a, b, |a, b| f64::from((a - b).abs()))
         );
     }};
-    ($a:expr, $b:expr, epsilon = $eps:expr) => {{
-        use support::FloatCompare;
+}
+
+/// Asserts that each component of two values is at most `max_ulps`
+/// representable `f32` values apart, 4 by default. Unlike an absolute
+/// tolerance this scales with the magnitude of the values.
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($a:expr, $b:expr) => {
+        assert_ulps_eq!($a, $b, max_ulps = 4)
+    };
+    ($a:expr, $b:expr, max_ulps = $ulps:expr) => {{
+        use $crate::support::FloatCompare;
         let (a, b) = (&$a, &$b);
-        let eps = $eps;
+        let ulps = $ulps;
         assert!(
-            a.approx_eq(b, $eps),
+            FloatCompare::ulps_eq(a, b, ulps),
             "assertion failed: `(left !== right)` \
-             (left: `{:#?}`, \nright: `{:#?}`, \nexpect diff: `{:#?}`, \nreal diff: `{:#?}`)",
+             (left: `{:#?}`, \nright: `{:#?}`, \nmax ulps: `{}`, \nworst {})",
             *a,
             *b,
-            eps,
-            a.abs_diff(b)
+            ulps,
+            FloatCompare::worst_component(a, b, |a, b| $crate::support::ulps_distance(a, b) as f64)
+        );
+    }};
+}
+
+/// Asserts that the difference of each component of two values is at most
+/// `max_relative` times the larger magnitude, `4.0 * f32::EPSILON` by default.
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($a:expr, $b:expr) => {
+        assert_relative_eq!($a, $b, max_relative = 4.0 * f32::EPSILON)
+    };
+    ($a:expr, $b:expr, max_relative = $rel:expr) => {{
+        use $crate::support::FloatCompare;
+        let (a, b) = (&$a, &$b);
+        let rel = $rel;
+        assert!(
+            FloatCompare::relative_eq(a, b, rel),
+            "assertion failed: `(left !== right)` \
+             (left: `{:#?}`, \nright: `{:#?}`, \nmax relative diff: `{:#?}`, \nworst {})",
+            *a,
+            *b,
+            rel,
+            FloatCompare::worst_component(a, b, |a, b| f64::from($crate::support::relative_diff(
+                a, b
+            )))
         );
     }};
 }
@@ -1,17 +1,119 @@
+// each test crate only uses some of the comparison modes
+#![allow(dead_code)]
+
 #[macro_use]
 mod macros;
 
 use glam::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
-use nalgebra;
 
-/// Trait used by the `assert_approx_eq` macro for floating point comparisons.
+/// Returns the number of representable `f32` values between `a` and `b`, or
+/// `u64::MAX` if either is NaN. `0.0` and `-0.0` are 0 ULPs apart.
+pub fn ulps_distance(a: f32, b: f32) -> u64 {
+    if a.is_nan() || b.is_nan() {
+        return u64::MAX;
+    }
+    // map the sign-magnitude bits to integers ordered the same as the floats
+    let ordered = |x: f32| {
+        let bits = x.to_bits() as i32;
+        if bits < 0 {
+            i64::from(i32::MIN) - i64::from(bits)
+        } else {
+            i64::from(bits)
+        }
+    };
+    (ordered(a) - ordered(b)).unsigned_abs()
+}
+
+/// Returns the difference of `a` and `b` relative to the larger magnitude.
+pub fn relative_diff(a: f32, b: f32) -> f32 {
+    if a == b {
+        return 0.0;
+    }
+    (a - b).abs() / a.abs().max(b.abs())
+}
+
+/// Pairs the components of two values, which must have the same number of
+/// components.
+fn component_pairs(left: Vec<f32>, right: Vec<f32>) -> impl Iterator<Item = (f32, f32)> {
+    assert_eq!(
+        left.len(),
+        right.len(),
+        "compared values have different numbers of components"
+    );
+    left.into_iter().zip(right)
+}
+
+/// The component with the largest difference between two values, printed if
+/// an assertion fails.
+#[derive(Debug)]
+pub struct WorstComponent {
+    pub index: usize,
+    pub left: f32,
+    pub right: f32,
+}
+
+impl std::fmt::Display for WorstComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "component {}: left = {:?}, right = {:?}, {} ulps apart",
+            self.index,
+            self.left,
+            self.right,
+            ulps_distance(self.left, self.right)
+        )
+    }
+}
+
+/// Trait used by the `assert_abs_diff_eq`, `assert_ulps_eq` and
+/// `assert_relative_eq` macros for floating point comparisons.
 pub trait FloatCompare<Rhs: ?Sized = Self> {
     /// Return true if the absolute difference between `self` and `other` is
     /// less then or equal to `max_abs_diff`.
     fn approx_eq(&self, other: &Rhs, max_abs_diff: f32) -> bool;
     /// Returns the absolute difference of `self` and `other` which is printed
-    /// if `assert_approx_eq` fails.
+    /// if `assert_abs_diff_eq` fails.
     fn abs_diff(&self, other: &Rhs) -> Rhs;
+    /// Returns the components of `self`, matrices are column major.
+    fn components(&self) -> Vec<f32>;
+
+    /// Return true if each component of `self` is at most `max_ulps`
+    /// representable values away from the same component of `other`.
+    fn ulps_eq(&self, other: &Rhs, max_ulps: u32) -> bool
+    where
+        Rhs: FloatCompare,
+    {
+        component_pairs(self.components(), other.components())
+            .all(|(a, b)| ulps_distance(a, b) <= u64::from(max_ulps))
+    }
+
+    /// Return true if the difference of each component of `self` and `other`
+    /// is at most `max_relative` times the larger of the two.
+    fn relative_eq(&self, other: &Rhs, max_relative: f32) -> bool
+    where
+        Rhs: FloatCompare,
+    {
+        component_pairs(self.components(), other.components())
+            .all(|(a, b)| relative_diff(a, b) <= max_relative)
+    }
+
+    /// Returns the component with the largest `diff` between `self` and
+    /// `other`.
+    fn worst_component(&self, other: &Rhs, diff: fn(f32, f32) -> f64) -> WorstComponent
+    where
+        Rhs: FloatCompare,
+    {
+        component_pairs(self.components(), other.components())
+            .enumerate()
+            .map(|(index, (left, right))| WorstComponent { index, left, right })
+            .max_by(|a, b| {
+                // NaN differences sort as the worst
+                let (a, b) = (diff(a.left, a.right), diff(b.left, b.right));
+                a.partial_cmp(&b)
+                    .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+            })
+            .expect("values have components")
+    }
 }
 
 impl FloatCompare for f32 {
@@ -23,6 +125,96 @@ impl FloatCompare for f32 {
     fn abs_diff(&self, other: &f32) -> f32 {
         (self - other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        vec![*self]
+    }
+}
+
+// compares the lanes of wide results element wise
+impl<T: FloatCompare, const N: usize> FloatCompare for [T; N] {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.approx_eq(b, max_abs_diff))
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        std::array::from_fn(|i| self[i].abs_diff(&other[i]))
+    }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.iter().flat_map(FloatCompare::components).collect()
+    }
+}
+
+/// Converts mint values to library types that don't implement `From` for them.
+pub trait FromMint<M> {
+    fn from_mint(m: M) -> Self;
+}
+
+/// Implements `FloatCompare` and `FromMint` for a type by converting it to and
+/// from the mint type with the same components, using `Into` by default.
+#[allow(unused_macros)]
+macro_rules! impl_float_compare_via_mint {
+    ($($t:ty => $mint:ty),+) => {
+        $(impl_float_compare_via_mint!($t => $mint, |v| (*v).into(), Into::into);)+
+    };
+    ($t:ty => $mint:ty, $to_mint:expr, $from_mint:expr) => {
+        impl FloatCompare for $t {
+            #[inline]
+            fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+                let to_mint: fn(&$t) -> $mint = $to_mint;
+                to_mint(self).approx_eq(&to_mint(other), max_abs_diff)
+            }
+            #[inline]
+            fn abs_diff(&self, other: &Self) -> Self {
+                let to_mint: fn(&$t) -> $mint = $to_mint;
+                let from_mint: fn($mint) -> $t = $from_mint;
+                from_mint(to_mint(self).abs_diff(&to_mint(other)))
+            }
+            #[inline]
+            fn components(&self) -> Vec<f32> {
+                let to_mint: fn(&$t) -> $mint = $to_mint;
+                to_mint(self).components()
+            }
+        }
+
+        impl FromMint<$mint> for $t {
+            #[inline]
+            fn from_mint(m: $mint) -> Self {
+                let from_mint: fn($mint) -> $t = $from_mint;
+                from_mint(m)
+            }
+        }
+    };
+}
+
+/// Implements `FloatCompare` between a wide type and an array of scalar
+/// values, one per lane, so each lane of a wide result is checked against a
+/// scalar reference. Components are ordered lane by lane.
+#[allow(unused_macros)]
+macro_rules! impl_float_compare_lanes {
+    ($t:ty => [$lane:ty; $n:literal], $lanes:expr) => {
+        impl FloatCompare<[$lane; $n]> for $t {
+            #[inline]
+            fn approx_eq(&self, other: &[$lane; $n], max_abs_diff: f32) -> bool {
+                let lanes: fn(&$t) -> [$lane; $n] = $lanes;
+                lanes(self).approx_eq(other, max_abs_diff)
+            }
+            #[inline]
+            fn abs_diff(&self, other: &[$lane; $n]) -> [$lane; $n] {
+                let lanes: fn(&$t) -> [$lane; $n] = $lanes;
+                lanes(self).abs_diff(other)
+            }
+            #[inline]
+            fn components(&self) -> Vec<f32> {
+                let lanes: fn(&$t) -> [$lane; $n] = $lanes;
+                lanes(self).components()
+            }
+        }
+    };
 }
 
 impl FloatCompare for mint::Vector2<f32> {
@@ -37,6 +229,10 @@ impl FloatCompare for mint::Vector2<f32> {
             y: self.y.abs_diff(&other.y),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        vec![self.x, self.y]
+    }
 }
 
 impl FloatCompare for mint::Vector3<f32> {
@@ -54,6 +250,10 @@ impl FloatCompare for mint::Vector3<f32> {
             z: self.z.abs_diff(&other.z),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        vec![self.x, self.y, self.z]
+    }
 }
 
 impl FloatCompare for mint::Vector4<f32> {
@@ -73,6 +273,10 @@ impl FloatCompare for mint::Vector4<f32> {
             w: self.w.abs_diff(&other.w),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        vec![self.x, self.y, self.z, self.w]
+    }
 }
 
 impl FloatCompare for mint::Quaternion<f32> {
@@ -87,6 +291,10 @@ impl FloatCompare for mint::Quaternion<f32> {
             s: self.s.abs_diff(&other.s),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        vec![self.v.x, self.v.y, self.v.z, self.s]
+    }
 }
 
 impl FloatCompare for mint::ColumnMatrix2<f32> {
@@ -101,6 +309,10 @@ impl FloatCompare for mint::ColumnMatrix2<f32> {
             y: self.y.abs_diff(&other.y),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        [self.x.components(), self.y.components()].concat()
+    }
 }
 
 impl FloatCompare for mint::ColumnMatrix3<f32> {
@@ -118,6 +330,15 @@ impl FloatCompare for mint::ColumnMatrix3<f32> {
             z: self.z.abs_diff(&other.z),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        [
+            self.x.components(),
+            self.y.components(),
+            self.z.components(),
+        ]
+        .concat()
+    }
 }
 
 impl FloatCompare for mint::ColumnMatrix4<f32> {
@@ -137,6 +358,16 @@ impl FloatCompare for mint::ColumnMatrix4<f32> {
             w: self.w.abs_diff(&other.w),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        [
+            self.x.components(),
+            self.y.components(),
+            self.z.components(),
+            self.w.components(),
+        ]
+        .concat()
+    }
 }
 
 // glam -----------------------------------------------------------------------
@@ -152,6 +383,10 @@ impl FloatCompare for Mat2 {
             (self.y_axis - other.y_axis).abs(),
         )
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_cols_array().to_vec()
+    }
 }
 
 impl FloatCompare for Mat3 {
@@ -167,6 +402,10 @@ impl FloatCompare for Mat3 {
             (self.z_axis - other.z_axis).abs(),
         )
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_cols_array().to_vec()
+    }
 }
 
 impl FloatCompare for Mat4 {
@@ -183,6 +422,10 @@ impl FloatCompare for Mat4 {
             (self.w_axis - other.w_axis).abs(),
         )
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_cols_array().to_vec()
+    }
 }
 
 impl FloatCompare for Quat {
@@ -196,6 +439,10 @@ impl FloatCompare for Quat {
         let b: Vec4 = (*other).into();
         Quat::from_vec4((a - b).abs())
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_array().to_vec()
+    }
 }
 
 impl FloatCompare for Vec2 {
@@ -207,6 +454,10 @@ impl FloatCompare for Vec2 {
     fn abs_diff(&self, other: &Vec2) -> Vec2 {
         (*self - *other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_array().to_vec()
+    }
 }
 
 impl FloatCompare for Vec3 {
@@ -218,6 +469,10 @@ impl FloatCompare for Vec3 {
     fn abs_diff(&self, other: &Vec3) -> Vec3 {
         (*self - *other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_array().to_vec()
+    }
 }
 
 impl FloatCompare for Vec4 {
@@ -229,6 +484,10 @@ impl FloatCompare for Vec4 {
     fn abs_diff(&self, other: &Vec4) -> Vec4 {
         (*self - *other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.to_array().to_vec()
+    }
 }
 
 // cgmath ---------------------------------------------------------------------
@@ -244,6 +503,11 @@ impl FloatCompare for cgmath::Vector2<f32> {
             y: self.y.abs_diff(&other.y),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::Vector2<f32> = (*self).into();
+        m.components()
+    }
 }
 
 impl FloatCompare for cgmath::Vector3<f32> {
@@ -261,6 +525,11 @@ impl FloatCompare for cgmath::Vector3<f32> {
             z: self.z.abs_diff(&other.z),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::Vector3<f32> = (*self).into();
+        m.components()
+    }
 }
 
 impl FloatCompare for cgmath::Vector4<f32> {
@@ -280,6 +549,11 @@ impl FloatCompare for cgmath::Vector4<f32> {
             w: self.w.abs_diff(&other.w),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::Vector4<f32> = (*self).into();
+        m.components()
+    }
 }
 
 impl FloatCompare for cgmath::Quaternion<f32> {
@@ -294,6 +568,11 @@ impl FloatCompare for cgmath::Quaternion<f32> {
             v: self.v.abs_diff(&other.v),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::Quaternion<f32> = (*self).into();
+        m.components()
+    }
 }
 
 impl FloatCompare for cgmath::Matrix2<f32> {
@@ -308,6 +587,11 @@ impl FloatCompare for cgmath::Matrix2<f32> {
             y: self.y.abs_diff(&other.y),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::ColumnMatrix2<f32> = (*self).into();
+        m.components()
+    }
 }
 
 impl FloatCompare for cgmath::Matrix3<f32> {
@@ -325,6 +609,11 @@ impl FloatCompare for cgmath::Matrix3<f32> {
             z: self.z.abs_diff(&other.z),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::ColumnMatrix3<f32> = (*self).into();
+        m.components()
+    }
 }
 
 impl FloatCompare for cgmath::Matrix4<f32> {
@@ -344,6 +633,11 @@ impl FloatCompare for cgmath::Matrix4<f32> {
             w: self.w.abs_diff(&other.w),
         }
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        let m: mint::ColumnMatrix4<f32> = (*self).into();
+        m.components()
+    }
 }
 
 // nalgebra -------------------------------------------------------------------
@@ -356,6 +650,10 @@ impl FloatCompare for nalgebra::Vector2<f32> {
     fn abs_diff(&self, other: &Self) -> Self {
         Self::new(self.x.abs_diff(&other.x), self.y.abs_diff(&other.y))
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
 }
 
 impl FloatCompare for nalgebra::Vector3<f32> {
@@ -373,6 +671,10 @@ impl FloatCompare for nalgebra::Vector3<f32> {
             self.z.abs_diff(&other.z),
         )
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
 }
 
 impl FloatCompare for nalgebra::Vector4<f32> {
@@ -392,17 +694,25 @@ impl FloatCompare for nalgebra::Vector4<f32> {
             self.w.abs_diff(&other.w),
         )
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
 }
 
 impl FloatCompare for nalgebra::Quaternion<f32> {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
-        self.as_vector().approx_eq(&other.as_vector(), max_abs_diff)
+        self.as_vector().approx_eq(other.as_vector(), max_abs_diff)
     }
     #[inline]
     fn abs_diff(&self, other: &Self) -> Self {
         Self::from(self.as_vector().abs_diff(other.as_vector()))
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_vector().components()
+    }
 }
 
 impl FloatCompare for nalgebra::Matrix2<f32> {
@@ -419,6 +729,10 @@ impl FloatCompare for nalgebra::Matrix2<f32> {
     fn abs_diff(&self, other: &Self) -> Self {
         (self - other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
 }
 
 impl FloatCompare for nalgebra::Matrix3<f32> {
@@ -435,6 +749,10 @@ impl FloatCompare for nalgebra::Matrix3<f32> {
     fn abs_diff(&self, other: &Self) -> Self {
         (self - other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
 }
 
 impl FloatCompare for nalgebra::Matrix4<f32> {
@@ -451,4 +769,202 @@ impl FloatCompare for nalgebra::Matrix4<f32> {
     fn abs_diff(&self, other: &Self) -> Self {
         (self - other).abs()
     }
+    #[inline]
+    fn components(&self) -> Vec<f32> {
+        self.as_slice().to_vec()
+    }
+}
+
+// euclid ---------------------------------------------------------------------
+#[cfg(feature = "euclid")]
+mod euclid_compare {
+    use super::{FloatCompare, FromMint};
+    use euclid::default::{Point3D, Rotation3D, Transform3D, Vector2D, Vector3D};
+
+    impl_float_compare_via_mint!(
+        Vector2D<f32> => mint::Vector2<f32>,
+        |v| v.to_array().into(),
+        |m| <[f32; 2]>::from(m).into()
+    );
+    impl_float_compare_via_mint!(
+        Vector3D<f32> => mint::Vector3<f32>,
+        |v| v.to_array().into(),
+        |m| <[f32; 3]>::from(m).into()
+    );
+    impl_float_compare_via_mint!(
+        Point3D<f32> => mint::Vector3<f32>,
+        |p| p.to_array().into(),
+        |m| <[f32; 3]>::from(m).into()
+    );
+    impl_float_compare_via_mint!(
+        Rotation3D<f32> => mint::Quaternion<f32>,
+        |q| [q.i, q.j, q.k, q.r].into(),
+        |m| Rotation3D::quaternion(m.v.x, m.v.y, m.v.z, m.s)
+    );
+    // euclid uses row vectors, so its rows are mint's columns
+    impl_float_compare_via_mint!(
+        Transform3D<f32> => mint::ColumnMatrix4<f32>,
+        |m| m.to_array().into(),
+        |m| Transform3D::from_array(m.into())
+    );
+}
+
+// vek ------------------------------------------------------------------------
+#[cfg(feature = "vek")]
+mod vek_compare {
+    use super::{FloatCompare, FromMint};
+
+    impl_float_compare_via_mint!(
+        vek::Vec2<f32> => mint::Vector2<f32>,
+        vek::Vec3<f32> => mint::Vector3<f32>,
+        vek::Vec4<f32> => mint::Vector4<f32>,
+        vek::Quaternion<f32> => mint::Quaternion<f32>,
+        vek::Mat2<f32> => mint::ColumnMatrix2<f32>,
+        vek::Mat3<f32> => mint::ColumnMatrix3<f32>,
+        vek::Mat4<f32> => mint::ColumnMatrix4<f32>
+    );
+}
+
+// pathfinder_geometry --------------------------------------------------------
+#[cfg(feature = "pathfinder_geometry")]
+mod pathfinder_compare {
+    use super::{FloatCompare, FromMint};
+    use pathfinder_geometry::{
+        transform2d::Matrix2x2F, transform3d::Transform4F, vector::Vector2F,
+    };
+
+    impl_float_compare_via_mint!(
+        Vector2F => mint::Vector2<f32>,
+        |v| [v.x(), v.y()].into(),
+        |m| Vector2F::new(m.x, m.y)
+    );
+    // stored column major
+    impl_float_compare_via_mint!(
+        Matrix2x2F => mint::ColumnMatrix2<f32>,
+        |m| [m.0[0], m.0[1], m.0[2], m.0[3]].into(),
+        |m| Matrix2x2F::row_major(m.x.x, m.y.x, m.x.y, m.y.y)
+    );
+    impl_float_compare_via_mint!(
+        Transform4F => mint::ColumnMatrix4<f32>,
+        |m| {
+            let cols = m.to_columns();
+            let m: [f32; 16] = std::array::from_fn(|i| cols[i / 4][i % 4]);
+            m.into()
+        },
+        |m| Transform4F::row_major(
+            m.x.x, m.y.x, m.z.x, m.w.x, m.x.y, m.y.y, m.z.y, m.w.y, m.x.z, m.y.z, m.z.z, m.w.z,
+            m.x.w, m.y.w, m.z.w, m.w.w,
+        )
+    );
+}
+
+// ultraviolet wide -----------------------------------------------------------
+#[cfg(any(feature = "ultraviolet_f32x4", feature = "ultraviolet_f32x8"))]
+mod ultraviolet_wide_compare {
+    use super::FloatCompare;
+    use ultraviolet::{Mat2, Mat3, Mat4, Vec2, Vec3, Vec4};
+
+    macro_rules! impl_ultraviolet_lanes {
+        ($n:literal, $f32xn:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mat2:ident,
+         $mat3:ident, $mat4:ident, $rotor3:ident) => {
+            use ultraviolet::{$f32xn, $mat2, $mat3, $mat4, $rotor3, $vec2, $vec3, $vec4};
+
+            impl_float_compare_lanes!($f32xn => [f32; $n], |v| v.to_array());
+            impl_float_compare_lanes!($vec2 => [mint::Vector2<f32>; $n], |v| {
+                <[Vec2; $n]>::from(*v).map(Into::into)
+            });
+            impl_float_compare_lanes!($vec3 => [mint::Vector3<f32>; $n], |v| {
+                <[Vec3; $n]>::from(*v).map(Into::into)
+            });
+            impl_float_compare_lanes!($vec4 => [mint::Vector4<f32>; $n], |v| {
+                <[Vec4; $n]>::from(*v).map(Into::into)
+            });
+            impl_float_compare_lanes!($mat2 => [mint::ColumnMatrix2<f32>; $n], |m| {
+                let [x, y] = m.cols.map(<[Vec2; $n]>::from);
+                std::array::from_fn(|i| Mat2::new(x[i], y[i]).into())
+            });
+            impl_float_compare_lanes!($mat3 => [mint::ColumnMatrix3<f32>; $n], |m| {
+                let [x, y, z] = m.cols.map(<[Vec3; $n]>::from);
+                std::array::from_fn(|i| Mat3::new(x[i], y[i], z[i]).into())
+            });
+            impl_float_compare_lanes!($mat4 => [mint::ColumnMatrix4<f32>; $n], |m| {
+                let [x, y, z, w] = m.cols.map(<[Vec4; $n]>::from);
+                std::array::from_fn(|i| Mat4::new(x[i], y[i], z[i], w[i]).into())
+            });
+            impl_float_compare_lanes!($rotor3 => [mint::Quaternion<f32>; $n], |r| {
+                let [x, y, z, w] = r.into_quaternion_array().map($f32xn::to_array);
+                std::array::from_fn(|i| [x[i], y[i], z[i], w[i]].into())
+            });
+        };
+    }
+
+    #[cfg(feature = "ultraviolet_f32x4")]
+    mod f32x4 {
+        use super::*;
+        impl_ultraviolet_lanes!(4, f32x4, Vec2x4, Vec3x4, Vec4x4, Mat2x4, Mat3x4, Mat4x4, Rotor3x4);
+    }
+
+    #[cfg(feature = "ultraviolet_f32x8")]
+    mod f32x8 {
+        use super::*;
+        impl_ultraviolet_lanes!(8, f32x8, Vec2x8, Vec3x8, Vec4x8, Mat2x8, Mat3x8, Mat4x8, Rotor3x8);
+    }
+}
+
+// nalgebra wide --------------------------------------------------------------
+#[cfg(any(feature = "nalgebra_f32x4", feature = "nalgebra_f32x8"))]
+mod nalgebra_wide_compare {
+    use super::FloatCompare;
+    use nalgebra::{Quaternion, SMatrix};
+    use simba::simd::SimdValue;
+
+    macro_rules! impl_nalgebra_lanes {
+        ($n:literal, $f32xn:ident) => {
+            use simba::simd::$f32xn;
+
+            impl_float_compare_lanes!($f32xn => [f32; $n], |v| {
+                std::array::from_fn(|i| v.extract(i))
+            });
+            impl_float_compare_lanes!(Quaternion<$f32xn> => [Quaternion<f32>; $n], |q| {
+                std::array::from_fn(|i| Quaternion::from(q.coords.map(|c| c.extract(i))))
+            });
+
+            impl<const R: usize, const C: usize> FloatCompare<[SMatrix<f32, R, C>; $n]>
+                for SMatrix<$f32xn, R, C>
+            where
+                SMatrix<f32, R, C>: FloatCompare,
+            {
+                #[inline]
+                fn approx_eq(&self, other: &[SMatrix<f32, R, C>; $n], max_abs_diff: f32) -> bool {
+                    lanes(self).approx_eq(other, max_abs_diff)
+                }
+                #[inline]
+                fn abs_diff(&self, other: &[SMatrix<f32, R, C>; $n]) -> [SMatrix<f32, R, C>; $n] {
+                    lanes(self).abs_diff(other)
+                }
+                #[inline]
+                fn components(&self) -> Vec<f32> {
+                    lanes(self).components()
+                }
+            }
+
+            fn lanes<const R: usize, const C: usize>(
+                m: &SMatrix<$f32xn, R, C>,
+            ) -> [SMatrix<f32, R, C>; $n] {
+                std::array::from_fn(|i| m.map(|c| c.extract(i)))
+            }
+        };
+    }
+
+    #[cfg(feature = "nalgebra_f32x4")]
+    mod f32x4 {
+        use super::*;
+        impl_nalgebra_lanes!(4, f32x4);
+    }
+
+    #[cfg(feature = "nalgebra_f32x8")]
+    mod f32x8 {
+        use super::*;
+        impl_nalgebra_lanes!(8, f32x8);
+    }
 }
@@ -1,10 +1,10 @@
 mod support;
 
-use cgmath::{self, InnerSpace};
-use glam;
+use cgmath::InnerSpace;
 use mathbench::mint_support::{random_mint_vec2, random_mint_vec3, random_mint_vec4};
-use nalgebra;
 use rand_pcg::Pcg64Mcg;
+#[cfg(any(feature = "euclid", feature = "pathfinder_geometry"))]
+use support::FromMint;
 
 const NUM_ITERS: usize = 1024;
 
@@ -24,12 +24,27 @@ fn vec2_normalize_compare() {
     let cv: cgmath::Vector2<f32> = mv.into();
     let cvn = cv.normalize();
 
+    #[cfg(feature = "euclid")]
+    let evn = euclid::default::Vector2D::from_mint(mv).normalize();
+
+    #[cfg(feature = "vek")]
+    let vvn = vek::Vec2::<f32>::from(mv).normalized();
+
+    #[cfg(feature = "pathfinder_geometry")]
+    let pvn = pathfinder_geometry::vector::Vector2F::from_mint(mv).normalize();
+
     // use nalgebra as assumed correct answer
     let mvn: mint::Vector2<f32> = nvn.into();
 
-    assert_ulps_eq!(cvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(uvn, mvn, epsilon = 1e-6);
+    assert_abs_diff_eq!(cvn, mvn.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(gvn, mvn.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(uvn, mvn, epsilon = 1e-6);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(evn, FromMint::from_mint(mvn), epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vvn, mvn.into(), epsilon = 1e-6);
+    #[cfg(feature = "pathfinder_geometry")]
+    assert_abs_diff_eq!(pvn, FromMint::from_mint(mvn), epsilon = 1e-6);
 }
 
 fn vec3_dot_compare() {
@@ -53,10 +68,23 @@ fn vec3_dot_compare() {
     let cv2: cgmath::Vector3<f32> = mv2.into();
     let cd = cv1.dot(cv2);
 
+    #[cfg(feature = "euclid")]
+    let ed = {
+        use euclid::default::Vector3D;
+        Vector3D::from_mint(mv1).dot(Vector3D::from_mint(mv2))
+    };
+
+    #[cfg(feature = "vek")]
+    let vd = vek::Vec3::<f32>::from(mv1).dot(vek::Vec3::<f32>::from(mv2));
+
     // use nalgebra as assumed correct answer
-    assert_ulps_eq!(cd, nd, epsilon = 1e-6);
-    assert_ulps_eq!(gd, nd, epsilon = 1e-6);
-    assert_ulps_eq!(ud, nd, epsilon = 1e-6);
+    assert_abs_diff_eq!(cd, nd, epsilon = 1e-6);
+    assert_abs_diff_eq!(gd, nd, epsilon = 1e-6);
+    assert_abs_diff_eq!(ud, nd, epsilon = 1e-6);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(ed, nd, epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vd, nd, epsilon = 1e-6);
 }
 
 fn vec3_cross_compare() {
@@ -80,12 +108,25 @@ fn vec3_cross_compare() {
     let cv2: cgmath::Vector3<f32> = mv2.into();
     let cc = cv1.cross(cv2);
 
+    #[cfg(feature = "euclid")]
+    let ec = {
+        use euclid::default::Vector3D;
+        Vector3D::from_mint(mv1).cross(Vector3D::from_mint(mv2))
+    };
+
+    #[cfg(feature = "vek")]
+    let vc = vek::Vec3::<f32>::from(mv1).cross(vek::Vec3::<f32>::from(mv2));
+
     // use nalgebra as assumed correct answer
     let mc: mint::Vector3<f32> = nc.into();
 
-    assert_ulps_eq!(cc, mc.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gc, mc.into(), epsilon = 1e-6);
-    assert_ulps_eq!(uc, mc, epsilon = 1e-6);
+    assert_abs_diff_eq!(cc, mc.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(gc, mc.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(uc, mc, epsilon = 1e-6);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(ec, FromMint::from_mint(mc), epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vc, mc.into(), epsilon = 1e-6);
 }
 
 fn vec3_normalize_compare() {
@@ -104,12 +145,22 @@ fn vec3_normalize_compare() {
     let cv: cgmath::Vector3<f32> = mv.into();
     let cvn = cv.normalize();
 
+    #[cfg(feature = "euclid")]
+    let evn = euclid::default::Vector3D::from_mint(mv).normalize();
+
+    #[cfg(feature = "vek")]
+    let vvn = vek::Vec3::<f32>::from(mv).normalized();
+
     // use nalgebra as assumed correct answer
     let mvn: mint::Vector3<f32> = nvn.into();
 
-    assert_ulps_eq!(cvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(uvn, mvn, epsilon = 1e-6);
+    assert_abs_diff_eq!(cvn, mvn.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(gvn, mvn.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(uvn, mvn, epsilon = 1e-6);
+    #[cfg(feature = "euclid")]
+    assert_abs_diff_eq!(evn, FromMint::from_mint(mvn), epsilon = 1e-6);
+    #[cfg(feature = "vek")]
+    assert_abs_diff_eq!(vvn, mvn.into(), epsilon = 1e-6);
 }
 
 fn vec4_dot_compare() {
@@ -134,9 +185,9 @@ fn vec4_dot_compare() {
     let cd = cv1.dot(cv2);
 
     // use nalgebra as assumed correct answer
-    assert_ulps_eq!(cd, nd, epsilon = 1e-6);
-    assert_ulps_eq!(gd, nd, epsilon = 1e-6);
-    assert_ulps_eq!(ud, nd, epsilon = 1e-6);
+    assert_abs_diff_eq!(cd, nd, epsilon = 1e-6);
+    assert_abs_diff_eq!(gd, nd, epsilon = 1e-6);
+    assert_abs_diff_eq!(ud, nd, epsilon = 1e-6);
 }
 
 fn vec4_normalize_compare() {
@@ -158,9 +209,9 @@ fn vec4_normalize_compare() {
     // use nalgebra as assumed correct answer
     let mvn: mint::Vector4<f32> = nvn.into();
 
-    assert_ulps_eq!(cvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(gvn, mvn.into(), epsilon = 1e-6);
-    assert_ulps_eq!(uvn, mvn, epsilon = 1e-6);
+    assert_abs_diff_eq!(cvn, mvn.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(gvn, mvn.into(), epsilon = 1e-6);
+    assert_abs_diff_eq!(uvn, mvn, epsilon = 1e-6);
 }
 
 #[test]
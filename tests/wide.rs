@@ -0,0 +1,532 @@
+// Compares each lane of the wide types against the same operation on scalar
+// inputs, using scalar nalgebra as the assumed correct answer.
+mod support;
+
+#[cfg(any(feature = "ultraviolet_f32x4", feature = "ultraviolet_f32x8"))]
+macro_rules! ultraviolet_wide_tests {
+    ($module:ident, $n:literal, $f32xn:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mat2:ident,
+     $mat3:ident, $mat4:ident, $rotor3:ident) => {
+        mod $module {
+            use crate::assert_abs_diff_eq;
+            use mathbench::mint_support::*;
+            use rand_pcg::Pcg64Mcg;
+            use ultraviolet::{$f32xn, $mat2, $mat3, $mat4, $rotor3, $vec2, $vec3, $vec4};
+
+            const NUM_ITERS: usize = 1024;
+
+            fn vec2(lanes: [mint::Vector2<f32>; $n]) -> $vec2 {
+                lanes.map(ultraviolet::Vec2::from).into()
+            }
+
+            fn vec3(lanes: [mint::Vector3<f32>; $n]) -> $vec3 {
+                lanes.map(ultraviolet::Vec3::from).into()
+            }
+
+            fn vec4(lanes: [mint::Vector4<f32>; $n]) -> $vec4 {
+                lanes.map(ultraviolet::Vec4::from).into()
+            }
+
+            fn mat2(lanes: [mint::ColumnMatrix2<f32>; $n]) -> $mat2 {
+                let lanes = lanes.map(ultraviolet::Mat2::from);
+                $mat2::new(
+                    lanes.map(|m| m.cols[0]).into(),
+                    lanes.map(|m| m.cols[1]).into(),
+                )
+            }
+
+            fn mat3(lanes: [mint::ColumnMatrix3<f32>; $n]) -> $mat3 {
+                let lanes = lanes.map(ultraviolet::Mat3::from);
+                $mat3::new(
+                    lanes.map(|m| m.cols[0]).into(),
+                    lanes.map(|m| m.cols[1]).into(),
+                    lanes.map(|m| m.cols[2]).into(),
+                )
+            }
+
+            fn mat4(lanes: [mint::ColumnMatrix4<f32>; $n]) -> $mat4 {
+                let lanes = lanes.map(ultraviolet::Mat4::from);
+                $mat4::new(
+                    lanes.map(|m| m.cols[0]).into(),
+                    lanes.map(|m| m.cols[1]).into(),
+                    lanes.map(|m| m.cols[2]).into(),
+                    lanes.map(|m| m.cols[3]).into(),
+                )
+            }
+
+            fn rotor3(lanes: [mint::Quaternion<f32>; $n]) -> $rotor3 {
+                let lanes = lanes.map(|q| [q.v.x, q.v.y, q.v.z, q.s]);
+                $rotor3::from_quaternion_array(std::array::from_fn(|i| {
+                    $f32xn::from(lanes.map(|q| q[i]))
+                }))
+            }
+
+            #[test]
+            fn test_vec3_length() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mv = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let ul = vec3(mv).mag();
+                    let nl = mv.map(|v| nalgebra::Vector3::from(v).norm());
+                    assert_abs_diff_eq!(ul, nl, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_vec3_normalize() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mv = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let uvn = vec3(mv).normalized();
+                    let mvn: [mint::Vector3<f32>; $n] =
+                        mv.map(|v| nalgebra::Vector3::from(v).normalize().into());
+                    assert_abs_diff_eq!(uvn, mvn, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_vec3_dot() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mv1 = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let mv2 = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let ud = vec3(mv1).dot(vec3(mv2));
+                    let nd: [f32; $n] = std::array::from_fn(|i| {
+                        nalgebra::Vector3::from(mv1[i]).dot(&mv2[i].into())
+                    });
+                    assert_abs_diff_eq!(ud, nd, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_vec3_cross() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mv1 = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let mv2 = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let uc = vec3(mv1).cross(vec3(mv2));
+                    let mc: [mint::Vector3<f32>; $n] = std::array::from_fn(|i| {
+                        nalgebra::Vector3::from(mv1[i]).cross(&mv2[i].into()).into()
+                    });
+                    assert_abs_diff_eq!(uc, mc, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat2_mul_vec2() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_mat2(&mut rng));
+                    let mv = std::array::from_fn(|_| random_mint_vec2(&mut rng));
+                    let umv = mat2(mm) * vec2(mv);
+                    let mmv: [mint::Vector2<f32>; $n] = std::array::from_fn(|i| {
+                        (nalgebra::Matrix2::from(mm[i]) * nalgebra::Vector2::from(mv[i])).into()
+                    });
+                    assert_abs_diff_eq!(umv, mmv, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat3_mul_vec3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_mat3(&mut rng));
+                    let mv = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let umv = mat3(mm) * vec3(mv);
+                    let mmv: [mint::Vector3<f32>; $n] = std::array::from_fn(|i| {
+                        (nalgebra::Matrix3::from(mm[i]) * nalgebra::Vector3::from(mv[i])).into()
+                    });
+                    assert_abs_diff_eq!(umv, mmv, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat4_mul_vec4() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_mat4(&mut rng));
+                    let mv = std::array::from_fn(|_| random_mint_vec4(&mut rng));
+                    let umv = mat4(mm) * vec4(mv);
+                    let mmv: [mint::Vector4<f32>; $n] = std::array::from_fn(|i| {
+                        (nalgebra::Matrix4::from(mm[i]) * nalgebra::Vector4::from(mv[i])).into()
+                    });
+                    assert_abs_diff_eq!(umv, mmv, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat2_mul_mat2() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm1 = std::array::from_fn(|_| random_mint_mat2(&mut rng));
+                    let mm2 = std::array::from_fn(|_| random_mint_mat2(&mut rng));
+                    let um3 = mat2(mm1) * mat2(mm2);
+                    let mm3: [mint::ColumnMatrix2<f32>; $n] = std::array::from_fn(|i| {
+                        (nalgebra::Matrix2::from(mm1[i]) * nalgebra::Matrix2::from(mm2[i])).into()
+                    });
+                    assert_abs_diff_eq!(um3, mm3, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat3_mul_mat3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm1 = std::array::from_fn(|_| random_mint_mat3(&mut rng));
+                    let mm2 = std::array::from_fn(|_| random_mint_mat3(&mut rng));
+                    let um3 = mat3(mm1) * mat3(mm2);
+                    let mm3: [mint::ColumnMatrix3<f32>; $n] = std::array::from_fn(|i| {
+                        (nalgebra::Matrix3::from(mm1[i]) * nalgebra::Matrix3::from(mm2[i])).into()
+                    });
+                    assert_abs_diff_eq!(um3, mm3, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat4_mul_mat4() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm1 = std::array::from_fn(|_| random_mint_mat4(&mut rng));
+                    let mm2 = std::array::from_fn(|_| random_mint_mat4(&mut rng));
+                    let um3 = mat4(mm1) * mat4(mm2);
+                    let mm3: [mint::ColumnMatrix4<f32>; $n] = std::array::from_fn(|i| {
+                        (nalgebra::Matrix4::from(mm1[i]) * nalgebra::Matrix4::from(mm2[i])).into()
+                    });
+                    assert_abs_diff_eq!(um3, mm3, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat2_det() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_mat2(&mut rng));
+                    let umd = mat2(mm).determinant();
+                    let nmd = mm.map(|m| nalgebra::Matrix2::from(m).determinant());
+                    assert_abs_diff_eq!(umd, nmd, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat3_det() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_mat3(&mut rng));
+                    let umd = mat3(mm).determinant();
+                    let nmd = mm.map(|m| nalgebra::Matrix3::from(m).determinant());
+                    assert_abs_diff_eq!(umd, nmd, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat4_det() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_mat4(&mut rng));
+                    let umd = mat4(mm).determinant();
+                    let nmd = mm.map(|m| nalgebra::Matrix4::from(m).determinant());
+                    assert_abs_diff_eq!(umd, nmd, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat2_inverse() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_invertible_mat2(&mut rng));
+                    let umi = mat2(mm).inversed();
+                    let nmi = mm.map(|m| nalgebra::Matrix2::from(m).try_inverse().unwrap());
+                    let nmn = nmi.iter().map(|m| m.norm()).fold(0.0, f32::max);
+                    let mmi: [mint::ColumnMatrix2<f32>; $n] = nmi.map(Into::into);
+                    // as for scalar ultraviolet, FMAs eat less rounding error for large numbers
+                    assert_abs_diff_eq!(umi, mmi, epsilon = 0.0005f32.max(0.0001 * nmn));
+                }
+            }
+
+            #[test]
+            fn test_mat3_inverse() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_homogeneous_mat3(&mut rng));
+                    let umi = mat3(mm).inversed();
+                    let mmi: [mint::ColumnMatrix3<f32>; $n] =
+                        mm.map(|m| nalgebra::Matrix3::from(m).try_inverse().unwrap().into());
+                    assert_abs_diff_eq!(umi, mmi, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_mat4_inverse() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mm = std::array::from_fn(|_| random_mint_homogeneous_mat4(&mut rng));
+                    let umi = mat4(mm).inversed();
+                    let mmi: [mint::ColumnMatrix4<f32>; $n] =
+                        mm.map(|m| nalgebra::Matrix4::from(m).try_inverse().unwrap().into());
+                    assert_abs_diff_eq!(umi, mmi, epsilon = 0.0005);
+                }
+            }
+
+            #[test]
+            fn test_rotor3_mul_vec3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mq = std::array::from_fn(|_| random_mint_quat(&mut rng));
+                    let mv = std::array::from_fn(|_| random_mint_vec3(&mut rng));
+                    let uqv = rotor3(mq) * vec3(mv);
+                    let mqv: [mint::Vector3<f32>; $n] = std::array::from_fn(|i| {
+                        let nq = nalgebra::UnitQuaternion::from_quaternion(mq[i].into());
+                        (nq * nalgebra::Vector3::from(mv[i])).into()
+                    });
+                    assert_abs_diff_eq!(uqv, mqv, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_rotor3_mul_rotor3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let mq1 = std::array::from_fn(|_| random_mint_quat(&mut rng));
+                    let mq2 = std::array::from_fn(|_| random_mint_quat(&mut rng));
+                    let uq3 = rotor3(mq1) * rotor3(mq2);
+                    let mq3: [mint::Quaternion<f32>; $n] = std::array::from_fn(|i| {
+                        let nq1 = nalgebra::UnitQuaternion::from_quaternion(mq1[i].into());
+                        let nq2 = nalgebra::UnitQuaternion::from_quaternion(mq2[i].into());
+                        (nq1 * nq2).into()
+                    });
+                    assert_abs_diff_eq!(uq3, mq3, epsilon = 1e-6);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ultraviolet_f32x4")]
+ultraviolet_wide_tests!(
+    ultraviolet_f32x4,
+    4,
+    f32x4,
+    Vec2x4,
+    Vec3x4,
+    Vec4x4,
+    Mat2x4,
+    Mat3x4,
+    Mat4x4,
+    Rotor3x4
+);
+
+#[cfg(feature = "ultraviolet_f32x8")]
+ultraviolet_wide_tests!(
+    ultraviolet_f32x8,
+    8,
+    f32x8,
+    Vec2x8,
+    Vec3x8,
+    Vec4x8,
+    Mat2x8,
+    Mat3x8,
+    Mat4x8,
+    Rotor3x8
+);
+
+#[cfg(any(feature = "nalgebra_f32x4", feature = "nalgebra_f32x8"))]
+macro_rules! nalgebra_wide_tests {
+    ($module:ident, $n:literal, $f32xn:ident) => {
+        mod $module {
+            use crate::assert_abs_diff_eq;
+            use mathbench::mint_support::*;
+            use nalgebra::{
+                Matrix2, Matrix3, Matrix4, Quaternion, SMatrix, UnitQuaternion, Vector2, Vector3,
+                Vector4,
+            };
+            use rand_pcg::Pcg64Mcg;
+            use simba::simd::$f32xn;
+
+            const NUM_ITERS: usize = 1024;
+
+            fn wide<const R: usize, const C: usize>(
+                lanes: [SMatrix<f32, R, C>; $n],
+            ) -> SMatrix<$f32xn, R, C> {
+                SMatrix::from_fn(|r, c| $f32xn::from(lanes.map(|m| m[(r, c)])))
+            }
+
+            fn wide_quat(lanes: [UnitQuaternion<f32>; $n]) -> UnitQuaternion<$f32xn> {
+                UnitQuaternion::new_unchecked(Quaternion::from(wide(lanes.map(|q| q.coords))))
+            }
+
+            fn random_quat(rng: &mut Pcg64Mcg) -> UnitQuaternion<f32> {
+                UnitQuaternion::from_quaternion(random_mint_quat(rng).into())
+            }
+
+            #[test]
+            fn test_vec3_length() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nv: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let wl = wide(nv).norm();
+                    let nl = nv.map(|v| v.norm());
+                    assert_abs_diff_eq!(wl, nl, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_vec3_normalize() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nv: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let wvn = wide(nv).normalize();
+                    let nvn = nv.map(|v| v.normalize());
+                    assert_abs_diff_eq!(wvn, nvn, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_vec3_dot() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nv1: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let nv2: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let wd = wide(nv1).dot(&wide(nv2));
+                    let nd: [f32; $n] = std::array::from_fn(|i| nv1[i].dot(&nv2[i]));
+                    assert_abs_diff_eq!(wd, nd, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_vec3_cross() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nv1: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let nv2: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let wc = wide(nv1).cross(&wide(nv2));
+                    let nc: [Vector3<f32>; $n] = std::array::from_fn(|i| nv1[i].cross(&nv2[i]));
+                    assert_abs_diff_eq!(wc, nc, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat2_mul_vec2() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nm: [Matrix2<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat2(&mut rng).into());
+                    let nv: [Vector2<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec2(&mut rng).into());
+                    let wmv = wide(nm) * wide(nv);
+                    let nmv: [Vector2<f32>; $n] = std::array::from_fn(|i| nm[i] * nv[i]);
+                    assert_abs_diff_eq!(wmv, nmv, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat3_mul_vec3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nm: [Matrix3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat3(&mut rng).into());
+                    let nv: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let wmv = wide(nm) * wide(nv);
+                    let nmv: [Vector3<f32>; $n] = std::array::from_fn(|i| nm[i] * nv[i]);
+                    assert_abs_diff_eq!(wmv, nmv, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat4_mul_vec4() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nm: [Matrix4<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat4(&mut rng).into());
+                    let nv: [Vector4<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec4(&mut rng).into());
+                    let wmv = wide(nm) * wide(nv);
+                    let nmv: [Vector4<f32>; $n] = std::array::from_fn(|i| nm[i] * nv[i]);
+                    assert_abs_diff_eq!(wmv, nmv, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat2_mul_mat2() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nm1: [Matrix2<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat2(&mut rng).into());
+                    let nm2: [Matrix2<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat2(&mut rng).into());
+                    let wm3 = wide(nm1) * wide(nm2);
+                    let nm3: [Matrix2<f32>; $n] = std::array::from_fn(|i| nm1[i] * nm2[i]);
+                    assert_abs_diff_eq!(wm3, nm3, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat3_mul_mat3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nm1: [Matrix3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat3(&mut rng).into());
+                    let nm2: [Matrix3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat3(&mut rng).into());
+                    let wm3 = wide(nm1) * wide(nm2);
+                    let nm3: [Matrix3<f32>; $n] = std::array::from_fn(|i| nm1[i] * nm2[i]);
+                    assert_abs_diff_eq!(wm3, nm3, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_mat4_mul_mat4() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nm1: [Matrix4<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat4(&mut rng).into());
+                    let nm2: [Matrix4<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_mat4(&mut rng).into());
+                    let wm3 = wide(nm1) * wide(nm2);
+                    let nm3: [Matrix4<f32>; $n] = std::array::from_fn(|i| nm1[i] * nm2[i]);
+                    assert_abs_diff_eq!(wm3, nm3, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_quat_mul_vec3() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nq: [UnitQuaternion<f32>; $n] =
+                        std::array::from_fn(|_| random_quat(&mut rng));
+                    let nv: [Vector3<f32>; $n] =
+                        std::array::from_fn(|_| random_mint_vec3(&mut rng).into());
+                    let wqv = wide_quat(nq) * wide(nv);
+                    let nqv: [Vector3<f32>; $n] = std::array::from_fn(|i| nq[i] * nv[i]);
+                    assert_abs_diff_eq!(wqv, nqv, epsilon = 1e-6);
+                }
+            }
+
+            #[test]
+            fn test_quat_mul_quat() {
+                let mut rng = Pcg64Mcg::new(rand::random());
+                for _ in 0..NUM_ITERS {
+                    let nq1: [UnitQuaternion<f32>; $n] =
+                        std::array::from_fn(|_| random_quat(&mut rng));
+                    let nq2: [UnitQuaternion<f32>; $n] =
+                        std::array::from_fn(|_| random_quat(&mut rng));
+                    let wq3 = (wide_quat(nq1) * wide_quat(nq2)).into_inner();
+                    let nq3: [Quaternion<f32>; $n] =
+                        std::array::from_fn(|i| (nq1[i] * nq2[i]).into_inner());
+                    assert_abs_diff_eq!(wq3, nq3, epsilon = 1e-6);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "nalgebra_f32x4")]
+nalgebra_wide_tests!(nalgebra_f32x4, 4, f32x4);
+
+#[cfg(feature = "nalgebra_f32x8")]
+nalgebra_wide_tests!(nalgebra_f32x8, 8, f32x8);
@@ -0,0 +1,100 @@
+//! Code size metrics read from Cargo's JSON messages and the LLVM IR emitted
+//! by `rustc` with `--emit=llvm-ir`.
+
+use anyhow::Result;
+use serde::Deserialize;
+use std::{
+    fs,
+    io::{BufRead, Cursor},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Deserialize)]
+struct Target {
+    name: String,
+    kind: Vec<String>,
+}
+
+/// The subset of a `compiler-artifact` message that we use.
+#[derive(Debug, Deserialize)]
+struct Artifact {
+    reason: String,
+    target: Option<Target>,
+    #[serde(default)]
+    filenames: Vec<PathBuf>,
+    executable: Option<PathBuf>,
+}
+
+/// The size of a library crate.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CrateSize {
+    pub rlib_bytes: u64,
+    pub ir_lines: usize,
+}
+
+/// Code size metrics of the library and the user crate using it.
+#[derive(Debug, Default)]
+pub struct Codegen {
+    pub library: CrateSize,
+    pub user: Option<CrateSize>,
+    pub binary_bytes: Option<u64>,
+}
+
+/// Returns the size of the rlib and the number of lines of LLVM IR of a
+/// library artifact.
+fn crate_size(filenames: &[PathBuf]) -> Result<CrateSize> {
+    let mut size = CrateSize::default();
+    for filename in filenames {
+        let extension = filename.extension().and_then(|ext| ext.to_str());
+        if extension == Some("rlib") {
+            size.rlib_bytes = fs::metadata(filename)?.len();
+        }
+        // `deps/lib<crate>-<hash>.rmeta` is next to `deps/<crate>-<hash>.ll`,
+        // the rlib may be copied out of `deps`
+        let in_deps = filename.parent().is_some_and(|dir| dir.ends_with("deps"));
+        if let (true, Some(stem)) = (in_deps, filename.file_stem().and_then(|s| s.to_str())) {
+            if let Some(stem) = stem.strip_prefix("lib") {
+                size.ir_lines = count_lines(&filename.with_file_name(format!("{}.ll", stem)))?;
+            }
+        }
+    }
+    Ok(size)
+}
+
+fn count_lines(path: &Path) -> Result<usize> {
+    if !path.is_file() {
+        return Ok(0);
+    }
+    Ok(fs::read(path)?
+        .iter()
+        .filter(|&&byte| byte == b'\n')
+        .count())
+}
+
+/// Reads code size metrics from the JSON messages of `cargo build
+/// --message-format=json` for the library `name` used by the crate `package`.
+pub fn parse_artifacts(stdout: &[u8], name: &str, package: &str) -> Result<Codegen> {
+    let name = name.replace('-', "_");
+    let package = package.replace('-', "_");
+    let mut codegen = Codegen::default();
+    for line in Cursor::new(stdout).lines() {
+        let artifact: Artifact = match serde_json::from_str(&line?) {
+            Ok(artifact) => artifact,
+            Err(_) => continue,
+        };
+        let target = match artifact.target {
+            Some(target) if artifact.reason == "compiler-artifact" => target,
+            _ => continue,
+        };
+        if target.kind.iter().any(|kind| kind == "bin") {
+            if let Some(executable) = artifact.executable {
+                codegen.binary_bytes = Some(fs::metadata(executable)?.len());
+            }
+        } else if target.name == name {
+            codegen.library = crate_size(&artifact.filenames)?;
+        } else if target.name == package {
+            codegen.user = Some(crate_size(&artifact.filenames)?);
+        }
+    }
+    Ok(codegen)
+}
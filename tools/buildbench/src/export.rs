@@ -0,0 +1,157 @@
+use crate::TimingInfo;
+use anyhow::Result;
+use serde::Serialize;
+use std::io::Write;
+
+/// A flattened `TimingInfo` for export. Times are in seconds and sizes in
+/// bytes, fields that weren't measured are empty.
+#[derive(Debug, Serialize)]
+struct BuildRecord {
+    name: String,
+    version: String,
+    profile: String,
+    overrides: String,
+    features: String,
+    units: u32,
+    total_mean: f64,
+    total_min: f64,
+    total_stddev: f64,
+    self_mean: f64,
+    self_min: f64,
+    self_stddev: f64,
+    incremental_mean: Option<f64>,
+    incremental_min: Option<f64>,
+    incremental_stddev: Option<f64>,
+    user_clean_mean: Option<f64>,
+    user_clean_min: Option<f64>,
+    user_clean_stddev: Option<f64>,
+    user_incremental_mean: Option<f64>,
+    user_incremental_min: Option<f64>,
+    user_incremental_stddev: Option<f64>,
+    rlib_bytes: Option<u64>,
+    ir_lines: Option<usize>,
+    user_ir_lines: Option<usize>,
+    binary_bytes: Option<u64>,
+}
+
+impl From<&TimingInfo> for BuildRecord {
+    fn from(info: &TimingInfo) -> Self {
+        let incremental = info.incremental_time;
+        let user_clean = info.user_time.map(|(clean, _)| clean);
+        let user_incremental = info.user_time.map(|(_, incremental)| incremental);
+        let codegen = info.codegen.as_ref();
+        BuildRecord {
+            name: info.name.clone(),
+            version: info.version.clone(),
+            profile: info.profile.as_str().to_string(),
+            overrides: info.overrides.as_string(),
+            features: info.features.as_string(),
+            units: info.units,
+            total_mean: info.total_time.mean,
+            total_min: info.total_time.min,
+            total_stddev: info.total_time.stddev,
+            self_mean: info.self_time.mean,
+            self_min: info.self_time.min,
+            self_stddev: info.self_time.stddev,
+            incremental_mean: incremental.map(|stats| stats.mean),
+            incremental_min: incremental.map(|stats| stats.min),
+            incremental_stddev: incremental.map(|stats| stats.stddev),
+            user_clean_mean: user_clean.map(|stats| stats.mean),
+            user_clean_min: user_clean.map(|stats| stats.min),
+            user_clean_stddev: user_clean.map(|stats| stats.stddev),
+            user_incremental_mean: user_incremental.map(|stats| stats.mean),
+            user_incremental_min: user_incremental.map(|stats| stats.min),
+            user_incremental_stddev: user_incremental.map(|stats| stats.stddev),
+            rlib_bytes: codegen.map(|codegen| codegen.library.rlib_bytes),
+            ir_lines: codegen.map(|codegen| codegen.library.ir_lines),
+            user_ir_lines: codegen.and_then(|codegen| codegen.user.map(|user| user.ir_lines)),
+            binary_bytes: codegen.and_then(|codegen| codegen.binary_bytes),
+        }
+    }
+}
+
+pub fn write_json<W: Write>(results: &[TimingInfo], mut writer: W) -> Result<()> {
+    let records: Vec<BuildRecord> = results.iter().map(BuildRecord::from).collect();
+    serde_json::to_writer_pretty(&mut writer, &records)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(results: &[TimingInfo], writer: W) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for result in results {
+        writer.serialize(BuildRecord::from(result))?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        codegen::{Codegen, CrateSize},
+        timings::Stats,
+        Features, Profile, ProfileOverrides,
+    };
+
+    fn timing_info() -> TimingInfo {
+        TimingInfo {
+            name: "glam".to_string(),
+            version: "0.20.1".to_string(),
+            profile: Profile::Release,
+            overrides: ProfileOverrides {
+                lto: Some("fat".to_string()),
+                ..ProfileOverrides::default()
+            },
+            features: Features::default(),
+            total_time: Stats::new(&[2.0, 4.0]),
+            self_time: Stats::new(&[1.0]),
+            units: 3,
+            incremental_time: None,
+            user_time: None,
+            codegen: Some(Codegen {
+                library: CrateSize {
+                    rlib_bytes: 2048,
+                    ir_lines: 100,
+                },
+                user: None,
+                binary_bytes: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn json_flattens_timing_info() {
+        let mut output = Vec::new();
+        write_json(&[timing_info()], &mut output).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let record = &json[0];
+        assert_eq!(record["profile"], "release");
+        assert_eq!(record["overrides"], "lto=fat");
+        assert_eq!(record["features"], "defaults");
+        assert_eq!(record["total_mean"], 3.0);
+        assert_eq!(record["total_min"], 2.0);
+        assert_eq!(record["self_stddev"], 0.0);
+        assert_eq!(record["incremental_mean"], serde_json::Value::Null);
+        assert_eq!(record["rlib_bytes"], 2048);
+        assert_eq!(record["user_ir_lines"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn csv_has_a_column_per_field() {
+        let mut output = Vec::new();
+        write_csv(&[timing_info()], &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let mut lines = output.lines();
+        let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+        let row: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert!(lines.next().is_none());
+        assert_eq!(header.len(), row.len());
+        let value = |name: &str| row[header.iter().position(|&h| h == name).unwrap()];
+        assert_eq!(value("name"), "glam");
+        assert_eq!(value("units"), "3");
+        assert_eq!(value("incremental_mean"), "");
+        assert_eq!(value("rlib_bytes"), "2048");
+    }
+}
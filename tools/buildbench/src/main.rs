@@ -1,42 +1,122 @@
+mod codegen;
+mod export;
+mod timings;
+mod usage;
+
 use anyhow::{anyhow, Result};
 use cargo_toml::Manifest;
 use clap::{App, Arg};
+use codegen::Codegen;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::{
-    collections::HashSet,
-    convert::{TryFrom, TryInto},
-    fs::{self, File},
-    io::{BufRead, Cursor, Write},
-    path::Path,
-    process::Command,
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    env, fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, Cursor, Write},
+    path::{Path, PathBuf},
+    process::{Command, Output},
     str::FromStr,
+    time::Instant,
 };
 use tempfile::{tempdir, TempDir};
+use timings::Stats;
 
 #[derive(Debug, Default)]
 struct TimingInfo {
     name: String,
     version: String,
     profile: Profile,
+    overrides: ProfileOverrides,
     features: Features,
-    total_time: f64,
-    self_time: f64,
+    total_time: Stats,
+    self_time: Stats,
     units: u32,
+    /// Dev profile rebuild time after editing the source.
+    incremental_time: Option<Stats>,
+    /// Clean and incremental build times of the generated user crate.
+    user_time: Option<(Stats, Stats)>,
+    codegen: Option<Codegen>,
 }
 
-#[derive(Debug, Copy, Clone)]
-enum Profile {
-    Dev,
-    Release,
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+enum Format {
+    #[default]
+    Markdown,
+    Json,
+    Csv,
 }
 
-impl Default for Profile {
-    fn default() -> Self {
-        Profile::Release
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "markdown" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format \"{}\" found!", value)),
+        }
+    }
+}
+
+impl Format {
+    fn possible_values() -> &'static [&'static str] {
+        &["markdown", "json", "csv"]
     }
 }
 
+/// The toolchain used for builds. Nightly reports timings from Cargo's output,
+/// on stable the total time is measured with a wall clock.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+enum Toolchain {
+    #[default]
+    Nightly,
+    Stable,
+}
+
+impl Toolchain {
+    /// Uses nightly if it is installed, otherwise stable.
+    fn detect() -> Self {
+        let nightly = Command::new("cargo")
+            .args(["+nightly", "--version"])
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if nightly {
+            Toolchain::Nightly
+        } else {
+            Toolchain::Stable
+        }
+    }
+
+    fn get_flags(&self) -> Vec<&str> {
+        match self {
+            Toolchain::Nightly => vec!["+nightly"],
+            Toolchain::Stable => Vec::new(),
+        }
+    }
+
+    fn get_build_flags(&self) -> Vec<&str> {
+        match self {
+            // `-Z timings=info` prints per unit timings on older nightlies,
+            // newer ones only write them to the `--timings` HTML report
+            Toolchain::Nightly => vec!["-Z", "timings=html,info", "--timings"],
+            Toolchain::Stable => vec!["--timings"],
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+enum Profile {
+    Dev,
+    #[default]
+    Release,
+    Bench,
+    /// A named profile that inherits from `release`.
+    Custom(String),
+}
+
 impl TryFrom<&str> for Profile {
     type Error = String;
 
@@ -44,73 +124,236 @@ impl TryFrom<&str> for Profile {
         match value {
             "dev" => Ok(Profile::Dev),
             "release" => Ok(Profile::Release),
-            _ => Err(format!("Unknown profile \"{}\" found!", value)),
+            "bench" => Ok(Profile::Bench),
+            "test" | "doc" => Err(format!("Profile \"{}\" is not supported!", value)),
+            _ if !value.is_empty()
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+            {
+                Ok(Profile::Custom(value.to_string()))
+            }
+            _ => Err(format!("Invalid profile name \"{}\" found!", value)),
         }
     }
 }
 
 impl Profile {
-    fn possible_values() -> &'static [&'static str] {
-        &["dev", "release"]
-    }
-
-    fn get_flags(&self) -> Option<&str> {
+    fn get_flags(&self) -> Vec<&str> {
         match self {
-            Profile::Dev => None,
-            Profile::Release => Some("--release"),
+            Profile::Dev => Vec::new(),
+            Profile::Release => vec!["--release"],
+            Profile::Bench | Profile::Custom(_) => vec!["--profile", self.as_str()],
         }
     }
+
     fn as_str(&self) -> &str {
         match self {
             Profile::Dev => "dev",
             Profile::Release => "release",
+            Profile::Bench => "bench",
+            Profile::Custom(name) => name.as_str(),
         }
     }
 }
 
-#[derive(Debug, Copy, Clone)]
-enum Features {
-    Defaults,
-    NoDefaults,
+/// Settings that override those of the selected profile in the temporary
+/// manifest.
+#[derive(Debug, Default, Clone)]
+struct ProfileOverrides {
+    lto: Option<String>,
+    codegen_units: Option<u32>,
+    opt_level: Option<String>,
+}
+
+impl ProfileOverrides {
+    fn lto_possible_values() -> &'static [&'static str] {
+        &["off", "thin", "fat"]
+    }
+
+    fn opt_level_possible_values() -> &'static [&'static str] {
+        &["0", "1", "2", "3", "s", "z"]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.lto.is_none() && self.codegen_units.is_none() && self.opt_level.is_none()
+    }
+
+    /// Returns the profile settings as they are written to `Cargo.toml`.
+    fn get_toml_values(&self) -> Vec<String> {
+        let mut values = Vec::new();
+        if let Some(lto) = &self.lto {
+            values.push(format!("lto = \"{}\"", lto));
+        }
+        if let Some(codegen_units) = self.codegen_units {
+            values.push(format!("codegen-units = {}", codegen_units));
+        }
+        if let Some(opt_level) = &self.opt_level {
+            if opt_level.parse::<u32>().is_ok() {
+                values.push(format!("opt-level = {}", opt_level));
+            } else {
+                values.push(format!("opt-level = \"{}\"", opt_level));
+            }
+        }
+        values
+    }
+
+    fn as_string(&self) -> String {
+        let mut values = Vec::new();
+        if let Some(lto) = &self.lto {
+            values.push(format!("lto={}", lto));
+        }
+        if let Some(codegen_units) = self.codegen_units {
+            values.push(format!("codegen-units={}", codegen_units));
+        }
+        if let Some(opt_level) = &self.opt_level {
+            values.push(format!("opt-level={}", opt_level));
+        }
+        if values.is_empty() {
+            "none".to_string()
+        } else {
+            values.join(" ")
+        }
+    }
+}
+
+/// The features a crate is built with, either its default features or a
+/// feature list with or without the default features.
+#[derive(Debug, Clone, PartialEq)]
+struct Features {
+    default_features: bool,
+    features: Vec<String>,
 }
 
 impl Default for Features {
     fn default() -> Self {
-        Features::Defaults
+        Features {
+            default_features: true,
+            features: Vec::new(),
+        }
     }
 }
 
 impl TryFrom<&str> for Features {
     type Error = String;
 
+    /// Parses a comma separated feature list, `defaults` and `no-defaults`
+    /// select whether default features are enabled.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "defaults" => Ok(Features::Defaults),
-            "no-defaults" => Ok(Features::NoDefaults),
-            _ => Err(format!("Unknown feature {} found!", value)),
+        let mut features = Features::default();
+        for feature in value.split(',').map(str::trim) {
+            match feature {
+                "defaults" => features.default_features = true,
+                "no-defaults" => features.default_features = false,
+                _ if !feature.is_empty()
+                    && feature
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "-_/".contains(c)) =>
+                {
+                    features.features.push(feature.to_string())
+                }
+                _ => return Err(format!("Unknown feature \"{}\" found!", feature)),
+            }
         }
+        Ok(features)
     }
 }
 
 impl Features {
-    fn possible_values() -> &'static [&'static str] {
-        &["defaults", "no-defaults"]
+    fn as_string(&self) -> String {
+        let mut values = Vec::new();
+        if !self.default_features {
+            values.push("no-defaults");
+        } else if self.features.is_empty() {
+            values.push("defaults");
+        }
+        values.extend(self.features.iter().map(String::as_str));
+        values.join(",")
     }
-    fn as_str(&self) -> &str {
-        match self {
-            Features::Defaults => "defaults",
-            Features::NoDefaults => "no-defaults",
+
+    fn get_toml_values(&self) -> Vec<String> {
+        let mut values = Vec::new();
+        if !self.default_features {
+            values.push("default-features = false".to_string());
         }
+        if !self.features.is_empty() {
+            let features: Vec<String> = self
+                .features
+                .iter()
+                .map(|feature| format!("\"{}\"", feature))
+                .collect();
+            values.push(format!("features = [{}]", features.join(", ")));
+        }
+        values
     }
-    fn get_toml_value(&self) -> Option<&str> {
-        match self {
-            Features::Defaults => None,
-            Features::NoDefaults => Some("default-features = false"),
+}
+
+/// A replacement for crates.io used to build without network access.
+#[derive(Debug)]
+enum Source {
+    /// A directory created by `cargo vendor`.
+    Vendor(PathBuf),
+    /// A local registry such as one created by `cargo local-registry`.
+    LocalRegistry(PathBuf),
+}
+
+/// Where the temporary builds get their dependencies from.
+#[derive(Debug, Default)]
+struct Registry {
+    /// The workspace `Cargo.lock`, copied into each temporary build so the
+    /// versions resolved by `mathbench` are the ones measured.
+    lockfile: Option<PathBuf>,
+    source: Option<Source>,
+    offline: bool,
+}
+
+impl Registry {
+    fn get_flags(&self) -> Vec<&str> {
+        if self.offline {
+            vec!["--offline"]
+        } else {
+            Vec::new()
         }
     }
+
+    /// Returns the contents of the `.cargo/config.toml` replacing crates.io.
+    fn get_config(&self) -> Option<String> {
+        let (kind, path) = match self.source.as_ref()? {
+            Source::Vendor(path) => ("directory", path),
+            Source::LocalRegistry(path) => ("local-registry", path),
+        };
+        Some(format!(
+            "[source.crates-io]\n\
+             replace-with = \"mathbench-source\"\n\
+             [source.mathbench-source]\n\
+             {} = {:?}\n",
+            kind, path
+        ))
+    }
 }
 
-fn create_temp_build(name: &str, version: &str, features: Features) -> Result<TempDir> {
+/// Options that apply to every build.
+#[derive(Debug, Default)]
+struct Options<'a> {
+    toolchain: Toolchain,
+    iterations: u32,
+    registry: Registry,
+    report_dir: Option<&'a Path>,
+    incremental: bool,
+    user_code: bool,
+    codegen: bool,
+    verbose: bool,
+}
+
+fn create_temp_build(
+    name: &str,
+    version: &str,
+    profile: &Profile,
+    overrides: &ProfileOverrides,
+    features: &Features,
+    registry: &Registry,
+    source: &str,
+) -> Result<TempDir> {
     let dir = tempdir()?;
 
     let toml_path = dir.path().join("Cargo.toml");
@@ -121,98 +364,377 @@ fn create_temp_build(name: &str, version: &str, features: Features) -> Result<Te
     writeln!(file, "edition = \"2018\"")?;
     writeln!(file, "[dependencies.{}]", name)?;
     writeln!(file, "version = \"{}\"", version)?;
-    if let Some(toml_value) = features.get_toml_value() {
+    for toml_value in features.get_toml_values() {
+        writeln!(file, "{}", toml_value)?;
+    }
+    if let Profile::Custom(_) = profile {
+        writeln!(file, "[profile.{}]", profile.as_str())?;
+        writeln!(file, "inherits = \"release\"")?;
+    } else if !overrides.is_empty() {
+        writeln!(file, "[profile.{}]", profile.as_str())?;
+    }
+    for toml_value in overrides.get_toml_values() {
         writeln!(file, "{}", toml_value)?;
     }
     drop(file);
 
+    if let Some(lockfile) = &registry.lockfile {
+        fs::copy(lockfile, dir.path().join("Cargo.lock"))?;
+    }
+    if let Some(config) = registry.get_config() {
+        let config_dir_path = dir.path().join(".cargo");
+        fs::create_dir(&config_dir_path)?;
+        fs::write(config_dir_path.join("config.toml"), config)?;
+    }
+
     let src_dir_path = dir.path().join("src");
     fs::create_dir(&src_dir_path)?;
 
     let src_path = src_dir_path.join("lib.rs");
     let mut file = File::create(src_path)?;
-    writeln!(file)?;
+    writeln!(file, "{}", source)?;
 
     Ok(dir)
 }
 
+/// Returns the version of `name` in a `Cargo.lock`.
+fn resolved_version(lockfile: &Path, name: &str) -> Option<String> {
+    let lockfile: toml::Value = toml::from_str(&fs::read_to_string(lockfile).ok()?).ok()?;
+    lockfile
+        .get("package")?
+        .as_array()?
+        .iter()
+        .find(|package| package.get("name").and_then(|n| n.as_str()) == Some(name))?
+        .get("version")?
+        .as_str()
+        .map(|version| version.to_string())
+}
+
 fn parse_time(time: &str) -> f64 {
     let time_match = Regex::new(r"^(\d+\.\d+)s$").unwrap();
     let completed_time = time_match.captures(time).unwrap().get(1).unwrap().as_str();
     f64::from_str(completed_time).unwrap()
 }
 
-fn bench_crate(
-    name: &str,
-    version: &str,
-    profile: Profile,
-    features: Features,
-    report_dir: Option<&Path>,
-    verbose: bool,
-) -> Result<TimingInfo> {
-    let build_dir = create_temp_build(name, version, features)?;
+fn cargo_command(build_dir: &Path, toolchain: Toolchain, args: &[&str]) -> Command {
+    let mut command = Command::new("cargo");
+    command
+        .current_dir(build_dir)
+        .args(toolchain.get_flags())
+        .args(args);
+    command
+}
 
-    let mut args = vec!["+nightly", "build", "-Z", "timings=html,info"];
-    if let Some(profile_flags) = profile.get_flags() {
-        args.push(profile_flags);
+fn cargo(build_dir: &Path, toolchain: Toolchain, args: &[&str]) -> Result<Output> {
+    Ok(cargo_command(build_dir, toolchain, args).output()?)
+}
+
+/// A `cargo` command that failed, with the compiler output it captured.
+#[derive(Debug)]
+struct CargoError {
+    command: String,
+    stderr: String,
+}
+
+impl fmt::Display for CargoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`cargo {}` failed", self.command)
     }
+}
 
-    let output = Command::new("cargo")
-        .current_dir(build_dir.path())
-        .args(&["+nightly", "build", "--release", "-Z", "timings=html,info"])
-        .output()?;
+impl std::error::Error for CargoError {}
 
-    let mut timing_info = TimingInfo::default();
-    timing_info.name = name.to_string();
-    timing_info.version = version.to_string();
-    timing_info.profile = profile;
-    timing_info.features = features;
+/// Returns a `CargoError` if the `cargo` command run with `args` failed.
+fn check_output(args: &[&str], output: &Output) -> Result<()> {
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(CargoError {
+            command: args.join(" "),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        }
+        .into())
+    }
+}
+
+/// A crate, profile and feature combination that failed to build.
+struct Failure {
+    name: String,
+    version: String,
+    profile: String,
+    features: String,
+    error: anyhow::Error,
+}
 
+/// Writes each failure as a markdown section including the captured compiler
+/// output.
+fn report_failures<W: Write>(failures: &[Failure], mut writer: W) -> Result<()> {
+    writeln!(writer)?;
+    writeln!(writer, "## Failures")?;
+    for failure in failures {
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "### {} {} {} {}",
+            failure.name, failure.version, failure.profile, failure.features
+        )?;
+        writeln!(writer)?;
+        match failure.error.downcast_ref::<CargoError>() {
+            Some(e) => {
+                writeln!(writer, "{}:", e)?;
+                writeln!(writer)?;
+                writeln!(writer, "```")?;
+                writeln!(writer, "{}", e.stderr.trim_end())?;
+                writeln!(writer, "```")?;
+            }
+            None => writeln!(writer, "{:?}", failure.error)?,
+        }
+    }
+    Ok(())
+}
+
+/// Timings scraped from a single build.
+#[derive(Debug, Default)]
+struct BuildTiming {
+    total_time: Option<f64>,
+    /// Library build times by crate name.
+    unit_times: HashMap<String, f64>,
+    units: u32,
+}
+
+fn parse_build_output(stderr: &[u8], verbose: bool) -> Result<BuildTiming> {
     lazy_static! {
         static ref COMPLETED_MATCH: Regex =
             Regex::new(r"^\s*Completed ([\S]+) ([\S]+) in ([\S]+)").unwrap();
         static ref FINISHED_MATCH: Regex =
-            Regex::new(r"\s*Finished [\S]+ \[.+\] target\(s\) in ([\S]+)").unwrap();
+            Regex::new(r"\s*Finished .+ target\(s\) in ([\S]+)").unwrap();
     }
 
-    for line in Cursor::new(output.stderr).lines() {
+    let mut timing = BuildTiming::default();
+    for line in Cursor::new(stderr).lines() {
         let line = line?;
         if verbose {
-            println!("{}", line);
+            eprintln!("{}", line);
         }
         if let Some(completed_captures) = COMPLETED_MATCH.captures(line.as_str()) {
-            timing_info.units += 1;
-            let completed_name = completed_captures.get(1).unwrap().as_str();
-            if name == completed_name {
-                timing_info.self_time = parse_time(completed_captures.get(3).unwrap().as_str());
-            }
+            timing.units += 1;
+            timing.unit_times.insert(
+                completed_captures.get(1).unwrap().as_str().to_string(),
+                parse_time(completed_captures.get(3).unwrap().as_str()),
+            );
         } else if let Some(finished_captures) = FINISHED_MATCH.captures(line.as_str()) {
-            timing_info.total_time = parse_time(finished_captures.get(1).unwrap().as_str());
+            timing.total_time = Some(parse_time(finished_captures.get(1).unwrap().as_str()));
+        }
+    }
+    Ok(timing)
+}
+
+/// Adds a function called `name` to `src/lib.rs` so the crate is rebuilt.
+fn edit_source(build_dir: &Path, name: &str) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .append(true)
+        .open(build_dir.join("src/lib.rs"))?;
+    writeln!(file, "pub fn {}() -> u32 {{ 0 }}", name)?;
+    Ok(())
+}
+
+/// Runs `cargo` with `args` and returns the total build time and the timings
+/// of each unit.
+fn timed_build(build_dir: &Path, args: &[&str], options: &Options) -> Result<(f64, BuildTiming)> {
+    let start = Instant::now();
+    let output = cargo(build_dir, options.toolchain, args)?;
+    let wall_time = start.elapsed().as_secs_f64();
+
+    let mut timing = parse_build_output(&output.stderr, options.verbose)?;
+    check_output(args, &output)?;
+
+    // newer versions of Cargo only report unit timings in the HTML report
+    if timing.units == 0 && args.contains(&"--timings") {
+        if let Some(report) = timings::find_report(build_dir) {
+            let (units, unit_times) = timings::parse_report(&report)?;
+            timing.units = units;
+            timing.unit_times = unit_times;
         }
     }
 
-    if !output.status.success() {
-        return Err(anyhow!("Build failed."));
+    let total_time = match options.toolchain {
+        Toolchain::Nightly => timing.total_time.unwrap_or(wall_time),
+        Toolchain::Stable => wall_time,
+    };
+    Ok((total_time, timing))
+}
+
+fn bench_crate(
+    name: &str,
+    version: &str,
+    profile: &Profile,
+    overrides: &ProfileOverrides,
+    features: &Features,
+    options: &Options,
+) -> Result<TimingInfo> {
+    let registry = &options.registry;
+    let toolchain = options.toolchain;
+    let source = if options.user_code {
+        usage::user_code(name)
+    } else {
+        None
+    };
+    let build_dir = create_temp_build(
+        name,
+        version,
+        profile,
+        overrides,
+        features,
+        registry,
+        source.unwrap_or_default(),
+    )?;
+    let package = format!("mathbench-{}-buildtime", name);
+
+    // fetch dependencies first so downloading them isn't timed
+    let mut args = vec!["fetch"];
+    args.extend(registry.get_flags());
+    let output = cargo(build_dir.path(), toolchain, &args)?;
+    check_output(&args, &output)?;
+
+    // user code rebuilds are only timed as a whole, so don't overwrite the
+    // timing report of the clean build
+    let mut rebuild_args = vec!["build"];
+    rebuild_args.extend(profile.get_flags());
+    rebuild_args.extend(registry.get_flags());
+    let mut args = vec!["build"];
+    args.extend(toolchain.get_build_flags());
+    args.extend(&rebuild_args[1..]);
+
+    let mut total_times = Vec::new();
+    let mut self_times = Vec::new();
+    let mut user_clean_times = Vec::new();
+    let mut user_incremental_times = Vec::new();
+    let mut units = 0;
+    for iteration in 0..options.iterations {
+        if iteration != 0 {
+            let output = cargo(build_dir.path(), toolchain, &["clean"])?;
+            check_output(&["clean"], &output)?;
+        }
+
+        let (total_time, timing) = timed_build(build_dir.path(), &args, options)?;
+        total_times.push(total_time);
+        self_times.push(timing.unit_times.get(name).copied().unwrap_or_default());
+        units = timing.units;
+
+        if source.is_some() {
+            user_clean_times.push(timing.unit_times.get(&package).copied().unwrap_or_default());
+
+            // edit the user crate and time the rebuild
+            edit_source(build_dir.path(), &format!("user_edit_{}", iteration))?;
+            let (rebuild_time, _) = timed_build(build_dir.path(), &rebuild_args, options)?;
+            user_incremental_times.push(rebuild_time);
+        }
+    }
+
+    let incremental_time = if options.incremental {
+        // build the dev profile once, then time rebuilds after editing the source
+        let mut dev_args = vec!["build"];
+        dev_args.extend(registry.get_flags());
+        let output = cargo(build_dir.path(), toolchain, &dev_args)?;
+        check_output(&dev_args, &output)?;
+
+        let mut incremental_times = Vec::new();
+        for iteration in 0..options.iterations {
+            edit_source(build_dir.path(), &format!("incremental_edit_{}", iteration))?;
+            let (rebuild_time, _) = timed_build(build_dir.path(), &dev_args, options)?;
+            incremental_times.push(rebuild_time);
+        }
+        Some(Stats::new(&incremental_times))
+    } else {
+        None
+    };
+
+    let codegen = if options.codegen {
+        if source.is_some() {
+            // link a binary using the library to measure its size
+            fs::write(
+                build_dir.path().join("src/main.rs"),
+                format!(
+                    "fn main() {{\n    {}::run();\n}}\n",
+                    package.replace('-', "_")
+                ),
+            )?;
+        }
+
+        // emit LLVM IR to a separate target directory so the timed builds
+        // aren't affected
+        let mut args = vec!["build", "--message-format=json"];
+        args.extend(profile.get_flags());
+        args.extend(registry.get_flags());
+        let mut rustflags = env::var("RUSTFLAGS").unwrap_or_default();
+        rustflags.push_str(" --emit=llvm-ir");
+        let output = cargo_command(build_dir.path(), toolchain, &args)
+            .env("RUSTFLAGS", rustflags.trim_start())
+            .env("CARGO_TARGET_DIR", build_dir.path().join("target/codegen"))
+            .output()?;
+        check_output(&args, &output)?;
+        Some(codegen::parse_artifacts(&output.stdout, name, &package)?)
+    } else {
+        None
+    };
+
+    let mut timing_info = TimingInfo {
+        name: name.to_string(),
+        version: version.to_string(),
+        profile: profile.clone(),
+        overrides: overrides.clone(),
+        features: features.clone(),
+        total_time: Stats::new(&total_times),
+        self_time: Stats::new(&self_times),
+        units,
+        incremental_time,
+        codegen,
+        user_time: source.map(|_| {
+            (
+                Stats::new(&user_clean_times),
+                Stats::new(&user_incremental_times),
+            )
+        }),
+    };
+
+    // report the version that was resolved rather than the version requirement
+    if let Some(version) = resolved_version(&build_dir.path().join("Cargo.lock"), name) {
+        timing_info.version = version;
     }
 
-    if let Some(report_dir) = report_dir {
+    if let Some(report_dir) = options.report_dir {
         // copy timing file
+        let mut file_name = format!("cargo-timing-{}-{}", name, profile.as_str());
+        if !overrides.is_empty() {
+            file_name.push('-');
+            file_name.push_str(&overrides.as_string().replace(['=', ' '], "-"));
+        }
         let timing_html = report_dir.join(format!(
-            "cargo-timing-{}-{}-{}.html",
-            name,
-            profile.as_str(),
-            features.as_str()
+            "{}-{}.html",
+            file_name,
+            features.as_string().replace(',', "-")
         ));
-        println!("Copying timing report to {:?}", timing_html.as_path());
-        if let Err(e) = fs::copy(build_dir.path().join("cargo-timing.html"), timing_html) {
-            eprintln!("Failed to copy file: {}", e);
+        eprintln!("Copying timing report to {:?}", timing_html.as_path());
+        match timings::find_report(build_dir.path()) {
+            Some(report) => {
+                if let Err(e) = fs::copy(report, timing_html) {
+                    eprintln!("Failed to copy file: {}", e);
+                }
+            }
+            None => eprintln!("Failed to copy file: no timing report found"),
         }
     }
 
     Ok(timing_info)
 }
 
-fn summarize(profiles: &[Profile], features: &[Features], results: &[TimingInfo]) {
+fn summarize<W: Write>(
+    profiles: &[Profile],
+    overrides: &[ProfileOverrides],
+    iterations: u32,
+    results: &[TimingInfo],
+    mut writer: W,
+) -> Result<()> {
     use prettytable::{
         format::{Alignment, FormatBuilder, LinePosition, LineSeparator},
         Cell, Row, Table,
@@ -235,12 +757,48 @@ fn summarize(profiles: &[Profile], features: &[Features], results: &[TimingInfo]
     if profiles.len() > 1 {
         titles.push(Cell::new_align("profile", Alignment::LEFT));
     }
-    if features.len() > 1 {
+    let show_overrides = overrides.len() > 1 || overrides.iter().any(|o| !o.is_empty());
+    if show_overrides {
+        titles.push(Cell::new_align("overrides", Alignment::LEFT));
+    }
+    let show_features = results
+        .iter()
+        .any(|info| info.features != Features::default());
+    if show_features {
         titles.push(Cell::new_align("features", Alignment::LEFT));
     }
-    titles.push(Cell::new_align("total (s)", Alignment::RIGHT));
-    titles.push(Cell::new_align("self (s)", Alignment::RIGHT));
+    // with multiple iterations show the mean, min and standard deviation
+    let stat_names: &[&str] = if iterations > 1 {
+        &["mean", "min", "stddev"]
+    } else {
+        &[""]
+    };
+    let show_user_time = results.iter().any(|info| info.user_time.is_some());
+    let show_incremental_time = results.iter().any(|info| info.incremental_time.is_some());
+    let mut times = vec!["total", "self"];
+    if show_incremental_time {
+        times.push("incremental");
+    }
+    if show_user_time {
+        times.extend(["user clean", "user incremental"]);
+    }
+    for time in times {
+        for stat in stat_names {
+            let title = if stat.is_empty() {
+                format!("{} (s)", time)
+            } else {
+                format!("{} {} (s)", time, stat)
+            };
+            titles.push(Cell::new_align(&title, Alignment::RIGHT));
+        }
+    }
     titles.push(Cell::new_align("units", Alignment::RIGHT));
+    let show_codegen = results.iter().any(|info| info.codegen.is_some());
+    if show_codegen {
+        for title in ["rlib (KiB)", "IR lines", "user IR lines", "binary (KiB)"] {
+            titles.push(Cell::new_align(title, Alignment::RIGHT));
+        }
+    }
     table.set_titles(Row::new(titles));
 
     for info in results {
@@ -251,49 +809,100 @@ fn summarize(profiles: &[Profile], features: &[Features], results: &[TimingInfo]
         if profiles.len() > 1 {
             row.push(Cell::new_align(info.profile.as_str(), Alignment::LEFT));
         }
-        if features.len() > 1 {
-            row.push(Cell::new_align(info.features.as_str(), Alignment::LEFT));
+        if show_overrides {
+            row.push(Cell::new_align(
+                &info.overrides.as_string(),
+                Alignment::LEFT,
+            ));
+        }
+        if show_features {
+            row.push(Cell::new_align(&info.features.as_string(), Alignment::LEFT));
+        }
+        // `(time, decimal places)`, rebuilds and the user crate take a
+        // fraction of a second so they need more precision to tell libraries
+        // apart
+        let mut times = vec![(Some(&info.total_time), 1), (Some(&info.self_time), 1)];
+        if show_incremental_time {
+            times.push((info.incremental_time.as_ref(), 3));
+        }
+        if show_user_time {
+            let user_time = info.user_time.as_ref();
+            times.push((user_time.map(|(clean, _)| clean), 3));
+            times.push((user_time.map(|(_, incremental)| incremental), 3));
+        }
+        for (time, precision) in times {
+            let values = match time {
+                Some(time) if iterations > 1 => vec![time.mean, time.min, time.stddev],
+                Some(time) => vec![time.mean],
+                None => Vec::new(),
+            };
+            for i in 0..stat_names.len() {
+                let value = values.get(i).map_or_else(
+                    || "-".to_string(),
+                    |value| format!("{:.*}", precision, value),
+                );
+                row.push(Cell::new_align(&value, Alignment::RIGHT));
+            }
         }
-        row.push(Cell::new_align(
-            &format!("{:.1}", info.total_time),
-            Alignment::RIGHT,
-        ));
-        row.push(Cell::new_align(
-            &format!("{:.1}", info.self_time),
-            Alignment::RIGHT,
-        ));
         row.push(Cell::new_align(
             &format!("{}", info.units),
             Alignment::RIGHT,
         ));
+        if show_codegen {
+            let codegen = info.codegen.as_ref();
+            let kib = |bytes: u64| format!("{:.1}", bytes as f64 / 1024.0);
+            let values = [
+                codegen.map(|codegen| kib(codegen.library.rlib_bytes)),
+                codegen.map(|codegen| codegen.library.ir_lines.to_string()),
+                codegen
+                    .and_then(|codegen| codegen.user)
+                    .map(|user| user.ir_lines.to_string()),
+                codegen.and_then(|codegen| codegen.binary_bytes.map(kib)),
+            ];
+            for value in values {
+                row.push(Cell::new_align(
+                    value.as_deref().unwrap_or("-"),
+                    Alignment::RIGHT,
+                ));
+            }
+        }
         table.add_row(Row::new(row));
     }
 
-    table.printstd();
+    table.print(&mut writer)?;
+    Ok(())
 }
 
-fn main() -> Result<()> {
-    let manifest = Manifest::from_path("Cargo.toml")?;
+/// Dependencies of `mathbench` that aren't math libraries, such as the SIMD
+/// crates the wide benchmarks use.
+const HELPER_DEPENDENCIES: &[&str] = &["simba", "wide_mathbench"];
 
-    let lib_pairs: Vec<(&str, &str)> = manifest
+/// Returns the name and version of each math library `mathbench` depends on.
+fn libraries(manifest: &Manifest) -> Vec<(&str, &str)> {
+    manifest
         .dependencies
         .iter()
+        .filter(|(name, _)| !HELPER_DEPENDENCIES.contains(&name.as_str()))
         .filter_map(|(name, dep)| {
-            // assume that detailed dependencies are the libs we want
-            if let Some(detail) = dep.detail() {
-                Some((
+            // assume that the other detailed dependencies are the libs we want
+            dep.detail().map(|detail| {
+                (
                     name.as_str(),
                     detail
                         .version
                         .as_ref()
                         .expect("dependency version expected")
                         .as_str(),
-                ))
-            } else {
-                None
-            }
+                )
+            })
         })
-        .collect();
+        .collect()
+}
+
+fn main() -> Result<()> {
+    let manifest = Manifest::from_path("Cargo.toml")?;
+
+    let lib_pairs = libraries(&manifest);
 
     let possible_libs: Vec<&str> = lib_pairs.iter().map(|&(name, _)| name).collect();
     let matches = App::new("mathbench buildbench")
@@ -309,17 +918,87 @@ fn main() -> Result<()> {
                 .short('F')
                 .takes_value(true)
                 .multiple(true)
-                .possible_values(Features::possible_values()),
+                .help(
+                    "features to build every crate with, defaults, no-defaults or a feature \
+                     list, or CRATE=FEATURES to build only CRATE with a comma separated feature \
+                     list, e.g. glam=mint,rand or nalgebra=no-defaults,libm",
+                ),
             Arg::with_name("profiles")
                 .long("profile")
                 .short('P')
                 .takes_value(true)
                 .multiple(true)
-                .possible_values(Profile::possible_values()),
+                .help(
+                    "profiles to build, dev, release, bench or a custom profile name \
+                     which inherits from release",
+                ),
+            Arg::with_name("lto")
+                .long("lto")
+                .takes_value(true)
+                .multiple(true)
+                .possible_values(ProfileOverrides::lto_possible_values())
+                .help("override the lto setting of each profile"),
+            Arg::with_name("codegen-units")
+                .long("codegen-units")
+                .takes_value(true)
+                .multiple(true)
+                .help("override the codegen-units setting of each profile"),
+            Arg::with_name("opt-level")
+                .long("opt-level")
+                .takes_value(true)
+                .multiple(true)
+                .possible_values(ProfileOverrides::opt_level_possible_values())
+                .help("override the opt-level setting of each profile"),
             Arg::with_name("report-dir")
                 .long("report-dir")
                 .short('R')
                 .takes_value(true),
+            Arg::with_name("iterations")
+                .long("iterations")
+                .short('n')
+                .takes_value(true)
+                .default_value("1")
+                .help("number of clean builds of each crate, reporting the mean, min and stddev"),
+            Arg::with_name("stable")
+                .long("stable")
+                .help("build with the stable toolchain even if nightly is installed"),
+            Arg::with_name("incremental")
+                .long("incremental")
+                .help("time dev profile incremental rebuilds after editing the source of each crate"),
+            Arg::with_name("user-code")
+                .long("user-code")
+                .help("build representative user code for each library and time its clean and incremental builds"),
+            Arg::with_name("codegen")
+                .long("codegen")
+                .help("report rlib and binary sizes and lines of LLVM IR of each crate"),
+            Arg::with_name("offline")
+                .long("offline")
+                .help("build without network access, using vendor/ if it exists"),
+            Arg::with_name("vendor")
+                .long("vendor")
+                .takes_value(true)
+                .value_name("DIR")
+                .conflicts_with("local-registry")
+                .help("replace crates.io with a directory created by cargo vendor"),
+            Arg::with_name("local-registry")
+                .long("local-registry")
+                .takes_value(true)
+                .value_name("DIR")
+                .help("replace crates.io with a local registry"),
+            Arg::with_name("no-lockfile")
+                .long("no-lockfile")
+                .help("resolve the latest compatible versions instead of using Cargo.lock"),
+            Arg::with_name("format")
+                .long("format")
+                .short('f')
+                .takes_value(true)
+                .possible_values(Format::possible_values())
+                .help("output format, markdown is a table, json and csv include every statistic"),
+            Arg::with_name("output")
+                .long("output")
+                .short('o')
+                .takes_value(true)
+                .help("write output to a file instead of stdout"),
             Arg::with_name("verbose").long("verbose").short('v'),
         ])
         .get_matches();
@@ -329,16 +1008,55 @@ fn main() -> Result<()> {
         .map_or(HashSet::new(), |v| v.collect());
 
     let profiles: Vec<Profile> = if let Some(profiles) = matches.values_of("profiles") {
-        profiles.map(|v| v.try_into().unwrap()).collect()
+        profiles
+            .map(Profile::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(|e| anyhow!(e))?
     } else {
         vec![Profile::default()]
     };
 
-    let features: Vec<Features> = if let Some(features) = matches.values_of("features") {
-        features.map(|v| v.try_into().unwrap()).collect()
-    } else {
-        vec![Features::default()]
+    // every combination of the given overrides is built for each profile
+    let values = |name| -> Vec<Option<&str>> {
+        matches
+            .values_of(name)
+            .map_or(vec![None], |values| values.map(Some).collect())
     };
+    let codegen_units: Vec<Option<u32>> = values("codegen-units")
+        .into_iter()
+        .map(|v| v.map(u32::from_str).transpose())
+        .collect::<Result<_, _>>()
+        .map_err(|e| anyhow!("Invalid codegen-units: {}", e))?;
+    let mut overrides = Vec::new();
+    for lto in values("lto") {
+        for codegen_units in &codegen_units {
+            for opt_level in values("opt-level") {
+                overrides.push(ProfileOverrides {
+                    lto: lto.map(|s| s.to_string()),
+                    codegen_units: *codegen_units,
+                    opt_level: opt_level.map(|s| s.to_string()),
+                });
+            }
+        }
+    }
+
+    // features given without a crate name are used for every crate
+    let mut features: Vec<Features> = Vec::new();
+    let mut crate_features: HashMap<&str, Vec<Features>> = HashMap::new();
+    for value in matches.values_of("features").into_iter().flatten() {
+        match value.split_once('=') {
+            Some((name, value)) => {
+                if !possible_libs.contains(&name) {
+                    return Err(anyhow!("Unknown crate \"{}\" found in features!", name));
+                }
+                crate_features
+                    .entry(name)
+                    .or_default()
+                    .push(Features::try_from(value).map_err(|e| anyhow!(e))?);
+            }
+            None => features.push(Features::try_from(value).map_err(|e| anyhow!(e))?),
+        }
+    }
 
     let report_dir = matches.value_of("report-dir").map(|dir| {
         let report_dir = Path::new(dir);
@@ -348,28 +1066,112 @@ fn main() -> Result<()> {
 
     let verbose = matches.is_present("verbose");
 
+    let offline = matches.is_present("offline");
+    let source = if let Some(dir) = matches.value_of("vendor") {
+        Some(Source::Vendor(fs::canonicalize(dir)?))
+    } else if let Some(dir) = matches.value_of("local-registry") {
+        Some(Source::LocalRegistry(fs::canonicalize(dir)?))
+    } else if offline && Path::new("vendor").is_dir() {
+        Some(Source::Vendor(fs::canonicalize("vendor")?))
+    } else {
+        None
+    };
+    let lockfile = Path::new("Cargo.lock");
+    let format: Format = matches
+        .value_of("format")
+        .map_or(Ok(Format::default()), Format::try_from)
+        .map_err(|e| anyhow!(e))?;
+
+    let mut writer: Box<dyn Write> = match matches.value_of("output") {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout()),
+    };
+
+    let iterations: u32 = matches
+        .value_of("iterations")
+        .unwrap()
+        .parse()
+        .map_err(|e| anyhow!("Invalid iterations: {}", e))?;
+    if iterations == 0 {
+        return Err(anyhow!("Iterations must be at least 1."));
+    }
+
+    let toolchain = if matches.is_present("stable") {
+        Toolchain::Stable
+    } else {
+        Toolchain::detect()
+    };
+    if toolchain == Toolchain::Stable {
+        eprintln!("Using the stable toolchain, total times are measured with a wall clock");
+    }
+
+    let options = Options {
+        toolchain,
+        iterations,
+        registry: Registry {
+            lockfile: if lockfile.is_file() && !matches.is_present("no-lockfile") {
+                Some(lockfile.to_path_buf())
+            } else {
+                None
+            },
+            source,
+            offline,
+        },
+        report_dir,
+        incremental: matches.is_present("incremental"),
+        user_code: matches.is_present("user-code"),
+        codegen: matches.is_present("codegen"),
+        verbose,
+    };
+
     let mut results = Vec::new();
+    let mut failures = Vec::new();
     for (name, version) in lib_pairs {
         if allowed_libs.is_empty() || allowed_libs.contains(name) {
+            let mut lib_features = features.clone();
+            lib_features.extend(crate_features.remove(name).unwrap_or_default());
+            if lib_features.is_empty() {
+                lib_features.push(Features::default());
+            }
             for profile in &profiles {
-                for feature in &features {
-                    println!(
-                        "Building {} {} {} profile {}",
-                        name,
-                        version,
-                        profile.as_str(),
-                        feature.as_str()
-                    );
-                    match bench_crate(name, version, *profile, *feature, report_dir, verbose) {
-                        Ok(info) => results.push(info),
-                        Err(e) => {
-                            eprintln!(
-                                "Error building {} {} {}: {:?}",
-                                name,
-                                profile.as_str(),
-                                feature.as_str(),
-                                e
-                            );
+                for profile_overrides in &overrides {
+                    for feature in &lib_features {
+                        let mut profile_name = format!("{} profile", profile.as_str());
+                        if !profile_overrides.is_empty() {
+                            profile_name.push_str(&format!(" ({})", profile_overrides.as_string()));
+                        }
+                        eprintln!(
+                            "Building {} {} {} {}",
+                            name,
+                            version,
+                            profile_name,
+                            feature.as_string()
+                        );
+                        match bench_crate(
+                            name,
+                            version,
+                            profile,
+                            profile_overrides,
+                            feature,
+                            &options,
+                        ) {
+                            Ok(info) => results.push(info),
+                            Err(e) => {
+                                eprintln!(
+                                    "Error building {} {} {}: {}",
+                                    name,
+                                    profile.as_str(),
+                                    feature.as_string(),
+                                    e
+                                );
+                                failures.push(Failure {
+                                    name: name.to_string(),
+                                    version: version.to_string(),
+                                    profile: profile_name,
+                                    features: feature.as_string(),
+                                    error: e,
+                                });
+                            }
                         }
                     }
                 }
@@ -377,10 +1179,133 @@ fn main() -> Result<()> {
         }
     }
 
-    if !results.is_empty() {
-        summarize(&profiles, &features, &results);
+    match format {
+        Format::Markdown => {
+            if !results.is_empty() {
+                summarize(
+                    &profiles,
+                    &overrides,
+                    options.iterations,
+                    &results,
+                    &mut writer,
+                )?;
+            }
+        }
+        Format::Json => export::write_json(&results, &mut writer)?,
+        Format::Csv => export::write_csv(&results, &mut writer)?,
+    }
+
+    if !failures.is_empty() {
+        // keep failures out of machine readable output
+        if format == Format::Markdown {
+            report_failures(&failures, &mut writer)?;
+        } else {
+            report_failures(&failures, io::stderr())?;
+        }
+        return Err(anyhow!(
+            "{} of {} builds failed.",
+            failures.len(),
+            failures.len() + results.len()
+        ));
     }
 
-    // TODO: output error if any build failed
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+    fn workspace_manifest() -> Manifest {
+        Manifest::from_path(Path::new(WORKSPACE_DIR).join("Cargo.toml")).unwrap()
+    }
+
+    #[test]
+    fn libraries_skip_helper_dependencies() {
+        let manifest = workspace_manifest();
+        let mut names: Vec<&str> = libraries(&manifest)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            [
+                "cgmath",
+                "euclid",
+                "glam",
+                "nalgebra",
+                "pathfinder_geometry",
+                "ultraviolet",
+                "vek"
+            ]
+        );
+    }
+
+    #[test]
+    fn features_parse_feature_lists() {
+        assert_eq!(Features::try_from("defaults"), Ok(Features::default()));
+
+        let features = Features::try_from("no-defaults, mint,serde/std").unwrap();
+        assert_eq!(
+            features,
+            Features {
+                default_features: false,
+                features: vec!["mint".to_string(), "serde/std".to_string()],
+            }
+        );
+        assert_eq!(features.as_string(), "no-defaults,mint,serde/std");
+        assert_eq!(
+            features.get_toml_values(),
+            [
+                "default-features = false",
+                "features = [\"mint\", \"serde/std\"]"
+            ]
+        );
+
+        // default features stay enabled unless they are turned off
+        let features = Features::try_from("mint").unwrap();
+        assert!(features.default_features);
+        assert_eq!(features.as_string(), "mint");
+
+        assert!(Features::try_from("").is_err());
+        assert!(Features::try_from("mint rand").is_err());
+        assert!(Features::try_from("mint,,rand").is_err());
+        assert!(Features::try_from("\"mint\"").is_err());
+    }
+
+    #[test]
+    fn default_libraries_build() {
+        let manifest = workspace_manifest();
+        let registry = Registry {
+            lockfile: Some(Path::new(WORKSPACE_DIR).join("Cargo.lock")),
+            ..Registry::default()
+        };
+        // share one target directory so each dependency is only built once
+        let target_dir = tempdir().unwrap();
+        for (name, version) in libraries(&manifest) {
+            let build_dir = create_temp_build(
+                name,
+                version,
+                &Profile::Dev,
+                &ProfileOverrides::default(),
+                &Features::default(),
+                &registry,
+                "",
+            )
+            .unwrap();
+            let output = cargo_command(build_dir.path(), Toolchain::Stable, &["check"])
+                .env("CARGO_TARGET_DIR", target_dir.path())
+                .output()
+                .unwrap();
+            assert!(
+                output.status.success(),
+                "{} failed to build:\n{}",
+                name,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Summary statistics of repeated measurements.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Stats {
+    pub mean: f64,
+    pub min: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn new(samples: &[f64]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let min = samples.iter().copied().fold(f64::MAX, f64::min);
+        // sample standard deviation, zero for a single sample
+        let stddev = if samples.len() > 1 {
+            (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+        Stats { mean, min, stddev }
+    }
+}
+
+/// The subset of a unit in the `UNIT_DATA` of a Cargo timing report that we use.
+#[derive(Debug, Deserialize)]
+struct Unit {
+    name: String,
+    target: String,
+    duration: f64,
+}
+
+/// Returns the path of the HTML report written by `cargo build --timings`.
+/// Older versions of Cargo write it to the package root.
+pub fn find_report(build_dir: &Path) -> Option<PathBuf> {
+    [
+        build_dir.join("target/cargo-timings/cargo-timing.html"),
+        build_dir.join("cargo-timing.html"),
+    ]
+    .iter()
+    .find(|path| path.is_file())
+    .cloned()
+}
+
+/// Reads the number of units built and the time taken to build the library of
+/// each crate from a Cargo timing report.
+pub fn parse_report(path: &Path) -> Result<(u32, HashMap<String, f64>)> {
+    const UNIT_DATA: &str = "const UNIT_DATA = ";
+    let html = fs::read_to_string(path)?;
+    let start = html
+        .find(UNIT_DATA)
+        .ok_or_else(|| anyhow!("No unit data found in {:?}", path))?
+        + UNIT_DATA.len();
+    let end = html[start..]
+        .find("];")
+        .ok_or_else(|| anyhow!("Unterminated unit data in {:?}", path))?
+        + start
+        + 1;
+    let units: Vec<Unit> = serde_json::from_str(&html[start..end])?;
+
+    // only count library targets, which are unnamed, and not build scripts
+    let mut unit_times = HashMap::new();
+    for unit in units.iter().filter(|unit| unit.target.trim().is_empty()) {
+        *unit_times.entry(unit.name.clone()).or_default() += unit.duration;
+    }
+    Ok((units.len() as u32, unit_times))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn stats_of_samples() {
+        let stats = Stats::new(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.min, 2.0);
+        assert!((stats.stddev - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+
+        let stats = Stats::new(&[3.0]);
+        assert_eq!((stats.mean, stats.min, stats.stddev), (3.0, 3.0, 0.0));
+
+        let stats = Stats::new(&[]);
+        assert_eq!((stats.mean, stats.min, stats.stddev), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn report_counts_units_and_times_libraries() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cargo-timing.html");
+        fs::write(
+            &path,
+            r#"<script>
+const UNIT_DATA = [
+  {"i":0,"name":"libm","version":"0.2.8","mode":"run-custom-build","target":" build script (run)","start":0.0,"duration":0.25},
+  {"i":1,"name":"libm","version":"0.2.8","mode":"todo","target":"","start":0.25,"duration":1.5},
+  {"i":2,"name":"glam","version":"0.20.1","mode":"todo","target":"","start":1.75,"duration":2.0},
+  {"i":3,"name":"build","version":"0.1.0","mode":"todo","target":" bin \"build\"","start":3.75,"duration":0.5}
+];
+const CONCURRENCY_DATA = [];
+</script>"#,
+        )
+        .unwrap();
+
+        let (units, unit_times) = parse_report(&path).unwrap();
+        assert_eq!(units, 4);
+        assert_eq!(unit_times.len(), 2);
+        assert_eq!(unit_times["libm"], 1.5);
+        assert_eq!(unit_times["glam"], 2.0);
+
+        fs::write(&path, "<html></html>").unwrap();
+        assert!(parse_report(&path).is_err());
+    }
+}
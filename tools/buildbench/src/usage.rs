@@ -0,0 +1,18 @@
+//! Representative user code for each library, exercising the same vector,
+//! matrix inverse, rotation and transform operations as the benchmarks. Generic
+//! libraries do much of their code generation in the user's crate, which an
+//! empty `lib.rs` doesn't measure.
+
+/// Returns the user code for the library `name`, if there is any.
+pub fn user_code(name: &str) -> Option<&'static str> {
+    match name {
+        "cgmath" => Some(include_str!("../usage/cgmath.rs")),
+        "euclid" => Some(include_str!("../usage/euclid.rs")),
+        "glam" => Some(include_str!("../usage/glam.rs")),
+        "nalgebra" => Some(include_str!("../usage/nalgebra.rs")),
+        "pathfinder_geometry" => Some(include_str!("../usage/pathfinder_geometry.rs")),
+        "ultraviolet" => Some(include_str!("../usage/ultraviolet.rs")),
+        "vek" => Some(include_str!("../usage/vek.rs")),
+        _ => None,
+    }
+}
@@ -0,0 +1,62 @@
+//! Representative `euclid` usage for measuring downstream compile times.
+
+use euclid::{
+    default::{Point3D, Rotation3D, Transform2D, Transform3D, Vector3D},
+    Angle,
+};
+
+pub fn vector_math(a: Vector3D<f32>, b: Vector3D<f32>, t: f32) -> Vector3D<f32> {
+    let n = a.cross(b).normalize();
+    let d = a.dot(b);
+    a.lerp(b, t) + n * d - Vector3D::splat((a - b).length())
+}
+
+pub fn matrix_inverse(
+    m3: Transform2D<f32>,
+    m4: Transform3D<f32>,
+) -> (Option<Transform2D<f32>>, Option<Transform3D<f32>>) {
+    (m3.inverse(), m4.inverse())
+}
+
+pub fn quaternion_rotation(
+    axis: Vector3D<f32>,
+    angle: f32,
+    q: Rotation3D<f32>,
+    v: Vector3D<f32>,
+) -> Vector3D<f32> {
+    let r = Rotation3D::around_axis(axis.normalize(), Angle::radians(angle));
+    r.then(&q).normalize().transform_vector3d(v)
+}
+
+pub fn transform(
+    scale: Vector3D<f32>,
+    rotation: Rotation3D<f32>,
+    translation: Vector3D<f32>,
+    point: Point3D<f32>,
+) -> Option<Point3D<f32>> {
+    let model = Transform3D::scale(scale.x, scale.y, scale.z)
+        .then(&rotation.to_transform())
+        .then(&Transform3D::translation(
+            translation.x,
+            translation.y,
+            translation.z,
+        ));
+    let view = Transform3D::translation(0.0, 0.0, -5.0);
+    let projection = Transform3D::perspective(5.0);
+    model.then(&view).then(&projection).transform_point3d(point)
+}
+
+/// Calls each function with opaque inputs, for linking a binary.
+pub fn run() {
+    use std::hint::black_box;
+    let a = black_box(Vector3D::new(1.0, 2.0, 3.0));
+    let b = black_box(Vector3D::new(4.0, 5.0, 6.0));
+    let q = black_box(Rotation3D::around_y(Angle::radians(0.5)));
+    black_box(vector_math(a, b, 0.5));
+    black_box(matrix_inverse(
+        black_box(Transform2D::identity()),
+        black_box(Transform3D::identity()),
+    ));
+    black_box(quaternion_rotation(a, 1.0, q, b));
+    black_box(transform(a, q, b, black_box(Point3D::new(1.0, 1.0, 1.0))));
+}
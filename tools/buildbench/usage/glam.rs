@@ -0,0 +1,37 @@
+//! Representative `glam` usage for measuring downstream compile times.
+
+use glam::{Mat3, Mat4, Quat, Vec3};
+
+pub fn vector_math(a: Vec3, b: Vec3, t: f32) -> Vec3 {
+    let n = a.cross(b).normalize();
+    let d = a.dot(b);
+    a.lerp(b, t) + n * d - (a - b).length() * Vec3::ONE
+}
+
+pub fn matrix_inverse(m3: Mat3, m4: Mat4) -> (Mat3, Mat4) {
+    (m3.inverse(), m4.inverse())
+}
+
+pub fn quaternion_rotation(axis: Vec3, angle: f32, q: Quat, v: Vec3) -> Vec3 {
+    let r = Quat::from_axis_angle(axis.normalize(), angle);
+    (r * q).normalize() * v
+}
+
+pub fn transform(scale: Vec3, rotation: Quat, translation: Vec3, point: Vec3) -> Vec3 {
+    let model = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+    let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+    let projection = Mat4::perspective_rh(1.0, 1.5, 0.1, 100.0);
+    (projection * view * model).project_point3(point)
+}
+
+/// Calls each function with opaque inputs, for linking a binary.
+pub fn run() {
+    use std::hint::black_box;
+    let a = black_box(Vec3::new(1.0, 2.0, 3.0));
+    let b = black_box(Vec3::new(4.0, 5.0, 6.0));
+    let q = black_box(Quat::from_rotation_y(0.5));
+    black_box(vector_math(a, b, 0.5));
+    black_box(matrix_inverse(black_box(Mat3::IDENTITY), black_box(Mat4::IDENTITY)));
+    black_box(quaternion_rotation(a, 1.0, q, b));
+    black_box(transform(a, q, b, black_box(Vec3::ONE)));
+}
@@ -0,0 +1,60 @@
+//! Representative `nalgebra` usage for measuring downstream compile times.
+
+use nalgebra::{
+    Isometry3, Matrix3, Matrix4, Perspective3, Point3, Similarity3, Unit, UnitQuaternion, Vector3,
+};
+
+pub fn vector_math(a: Vector3<f32>, b: Vector3<f32>, t: f32) -> Vector3<f32> {
+    let n = a.cross(&b).normalize();
+    let d = a.dot(&b);
+    a.lerp(&b, t) + n * d - Vector3::repeat((a - b).norm())
+}
+
+pub fn matrix_inverse(
+    m3: Matrix3<f32>,
+    m4: Matrix4<f32>,
+) -> (Option<Matrix3<f32>>, Option<Matrix4<f32>>) {
+    (m3.try_inverse(), m4.try_inverse())
+}
+
+pub fn quaternion_rotation(
+    axis: Vector3<f32>,
+    angle: f32,
+    q: UnitQuaternion<f32>,
+    v: Vector3<f32>,
+) -> Vector3<f32> {
+    let r = UnitQuaternion::from_axis_angle(&Unit::new_normalize(axis), angle);
+    (r * q) * v
+}
+
+pub fn transform(
+    scale: f32,
+    rotation: UnitQuaternion<f32>,
+    translation: Vector3<f32>,
+    point: Point3<f32>,
+) -> Point3<f32> {
+    let model = Similarity3::from_parts(translation.into(), rotation, scale).to_homogeneous();
+    let view = Isometry3::look_at_rh(
+        &Point3::new(0.0, 0.0, 5.0),
+        &Point3::origin(),
+        &Vector3::y(),
+    )
+    .to_homogeneous();
+    let projection = Perspective3::new(1.5, 1.0, 0.1, 100.0).to_homogeneous();
+    (projection * view * model).transform_point(&point)
+}
+
+/// Calls each function with opaque inputs, for linking a binary.
+pub fn run() {
+    use std::hint::black_box;
+    let a = black_box(Vector3::new(1.0, 2.0, 3.0));
+    let b = black_box(Vector3::new(4.0, 5.0, 6.0));
+    let q = black_box(UnitQuaternion::from_euler_angles(0.0, 0.5, 0.0));
+    black_box(vector_math(a, b, 0.5));
+    black_box(matrix_inverse(
+        black_box(Matrix3::identity()),
+        black_box(Matrix4::identity()),
+    ));
+    black_box(quaternion_rotation(a, 1.0, q, b));
+    black_box(transform(2.0, q, b, black_box(Point3::new(1.0, 1.0, 1.0))));
+}
@@ -0,0 +1,56 @@
+//! Representative `pathfinder_geometry` usage for measuring downstream compile
+//! times.
+
+use pathfinder_geometry::{
+    transform2d::Transform2F,
+    transform3d::Transform4F,
+    vector::{Vector2F, Vector3F, Vector4F},
+};
+
+pub fn vector_math(a: Vector3F, b: Vector3F, t: f32) -> Vector3F {
+    let n = a.cross(b).normalize();
+    let d = a.to_2d().dot(b.to_2d());
+    a + (b - a).scale(t) + n.scale(d) - Vector3F::splat((a - b).length())
+}
+
+pub fn matrix_inverse(m3: Transform2F, m4: Transform4F) -> (Transform2F, Transform4F) {
+    (m3.inverse(), m4.inverse())
+}
+
+pub fn rotation(yaw: f32, pitch: f32, roll: f32, v: Vector4F) -> Vector4F {
+    Transform4F::from_rotation(yaw, pitch, roll) * v
+}
+
+pub fn transform(scale: Vector4F, angles: Vector3F, translation: Vector4F, point: Vector4F) -> Vector4F {
+    let model = Transform4F::from_translation(translation)
+        * Transform4F::from_rotation(angles.x(), angles.y(), angles.z())
+        * Transform4F::from_scale(scale);
+    let view = Transform4F::looking_at(
+        Vector3F::new(0.0, 0.0, 5.0),
+        Vector3F::splat(0.0),
+        Vector3F::new(0.0, 1.0, 0.0),
+    );
+    let projection = Transform4F::from_perspective(1.0, 1.5, 0.1, 100.0);
+    projection * view * model * point
+}
+
+pub fn transform_2d(scale: Vector2F, theta: f32, translation: Vector2F, point: Vector2F) -> Vector2F {
+    Transform2F::from_scale_rotation_translation(scale, theta, translation) * point
+}
+
+/// Calls each function with opaque inputs, for linking a binary.
+pub fn run() {
+    use std::hint::black_box;
+    let a = black_box(Vector3F::new(1.0, 2.0, 3.0));
+    let b = black_box(Vector3F::new(4.0, 5.0, 6.0));
+    let v = black_box(Vector4F::new(1.0, 1.0, 1.0, 1.0));
+    let p = black_box(Vector2F::new(1.0, 2.0));
+    black_box(vector_math(a, b, 0.5));
+    black_box(matrix_inverse(
+        black_box(Transform2F::default()),
+        black_box(Transform4F::default()),
+    ));
+    black_box(rotation(0.5, 0.0, 0.0, v));
+    black_box(transform(v, a, v, v));
+    black_box(transform_2d(p, 0.5, p, p));
+}
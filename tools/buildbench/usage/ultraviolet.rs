@@ -0,0 +1,42 @@
+//! Representative `ultraviolet` usage for measuring downstream compile times.
+
+use ultraviolet::{projection::rh_yup::perspective_gl, Bivec3, Lerp, Mat3, Mat4, Rotor3, Vec3};
+
+pub fn vector_math(a: Vec3, b: Vec3, t: f32) -> Vec3 {
+    let n = a.cross(b).normalized();
+    let d = a.dot(b);
+    a.lerp(b, t) + n * d - Vec3::broadcast((a - b).mag())
+}
+
+pub fn matrix_inverse(m3: Mat3, m4: Mat4) -> (Mat3, Mat4) {
+    (m3.inversed(), m4.inversed())
+}
+
+pub fn quaternion_rotation(axis: Vec3, angle: f32, q: Rotor3, v: Vec3) -> Vec3 {
+    let r = Rotor3::from_angle_plane(angle, Bivec3::from_normalized_axis(axis.normalized()));
+    (r * q).normalized() * v
+}
+
+pub fn transform(scale: Vec3, rotation: Rotor3, translation: Vec3, point: Vec3) -> Vec3 {
+    let model = Mat4::from_translation(translation)
+        * rotation.into_matrix().into_homogeneous()
+        * Mat4::from_nonuniform_scale(scale);
+    let view = Mat4::look_at(Vec3::new(0.0, 0.0, 5.0), Vec3::zero(), Vec3::unit_y());
+    let projection = perspective_gl(1.0, 1.5, 0.1, 100.0);
+    (projection * view * model).transform_point3(point)
+}
+
+/// Calls each function with opaque inputs, for linking a binary.
+pub fn run() {
+    use std::hint::black_box;
+    let a = black_box(Vec3::new(1.0, 2.0, 3.0));
+    let b = black_box(Vec3::new(4.0, 5.0, 6.0));
+    let q = black_box(Rotor3::from_rotation_xz(0.5));
+    black_box(vector_math(a, b, 0.5));
+    black_box(matrix_inverse(
+        black_box(Mat3::identity()),
+        black_box(Mat4::identity()),
+    ));
+    black_box(quaternion_rotation(a, 1.0, q, b));
+    black_box(transform(a, q, b, black_box(Vec3::one())));
+}
@@ -0,0 +1,50 @@
+//! Representative `vek` usage for measuring downstream compile times.
+
+use vek::{Mat4, Quaternion, Vec3};
+
+pub fn vector_math(a: Vec3<f32>, b: Vec3<f32>, t: f32) -> Vec3<f32> {
+    let n = a.cross(b).normalized();
+    let d = a.dot(b);
+    Vec3::lerp(a, b, t) + n * d - Vec3::broadcast((a - b).magnitude())
+}
+
+pub fn matrix_inverse(m4: Mat4<f32>) -> Mat4<f32> {
+    m4.inverted()
+}
+
+pub fn quaternion_rotation(
+    axis: Vec3<f32>,
+    angle: f32,
+    q: Quaternion<f32>,
+    v: Vec3<f32>,
+) -> Vec3<f32> {
+    let r = Quaternion::rotation_3d(angle, axis.normalized());
+    (r * q).normalized() * v
+}
+
+pub fn transform(
+    scale: Vec3<f32>,
+    rotation: Quaternion<f32>,
+    translation: Vec3<f32>,
+    point: Vec3<f32>,
+) -> Vec3<f32> {
+    let model = Mat4::<f32>::translation_3d(translation)
+        * Mat4::from(rotation)
+        * Mat4::scaling_3d(scale);
+    let view = Mat4::<f32>::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::zero(), Vec3::unit_y());
+    let projection = Mat4::<f32>::perspective_rh_zo(1.0, 1.5, 0.1, 100.0);
+    let model_view_projection: Mat4<f32> = projection * view * model;
+    model_view_projection.mul_point(point)
+}
+
+/// Calls each function with opaque inputs, for linking a binary.
+pub fn run() {
+    use std::hint::black_box;
+    let a = black_box(Vec3::new(1.0, 2.0, 3.0));
+    let b = black_box(Vec3::new(4.0, 5.0, 6.0));
+    let q = black_box(Quaternion::rotation_y(0.5));
+    black_box(vector_math(a, b, 0.5));
+    black_box(matrix_inverse(black_box(Mat4::identity())));
+    black_box(quaternion_rotation(a, 1.0, q, b));
+    black_box(transform(a, q, b, black_box(Vec3::one())));
+}
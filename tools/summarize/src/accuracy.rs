@@ -0,0 +1,128 @@
+use crate::{criterion::read_json, markdown_table};
+use anyhow::Result;
+use serde::Deserialize;
+use std::{collections::BTreeMap, io::Write, path::Path};
+
+/// The accuracy of one library's implementation of an operation, recorded by
+/// the `accuracy` bench in `mathbench-accuracy/<baseline>.json` next to the
+/// Criterion output. Errors are measured against an f64 reference.
+#[derive(Debug, Deserialize)]
+pub struct Accuracy {
+    operation: String,
+    library: String,
+    samples: usize,
+    failures: usize,
+    max_ulps: f64,
+    mean_ulps: f64,
+    max_relative: f64,
+    mean_relative: f64,
+}
+
+/// Reads the accuracy results recorded for `baseline`, if any.
+pub fn read_accuracy(criterion_dir: &Path, baseline: &str) -> Result<Vec<Accuracy>> {
+    Ok(read_json(
+        &criterion_dir
+            .join("mathbench-accuracy")
+            .join(format!("{}.json", baseline)),
+    )?
+    .unwrap_or_default())
+}
+
+/// A table title and the cell value for a record.
+type Metric = (&'static str, fn(&Accuracy) -> String);
+
+const METRICS: [Metric; 2] = [
+    ("ulps (max / mean)", |a| {
+        format!("{:.1} / {:.2}", a.max_ulps, a.mean_ulps)
+    }),
+    ("relative error (max / mean)", |a| {
+        format!("{:.1e} / {:.1e}", a.max_relative, a.mean_relative)
+    }),
+];
+
+/// Returns the column titles and the rows of a table per error metric, with a
+/// row per operation and a column per library in `libs` that has results.
+pub fn tables<'a>(
+    accuracy: &'a [Accuracy],
+    libs: &[&'a str],
+) -> Vec<(Vec<&'a str>, Vec<Vec<String>>)> {
+    let libs: Vec<&str> = libs
+        .iter()
+        .copied()
+        .filter(|lib| accuracy.iter().any(|a| a.library == *lib))
+        .collect();
+    if libs.is_empty() {
+        return Vec::new();
+    }
+    let mut operations: BTreeMap<&str, BTreeMap<&str, &Accuracy>> = BTreeMap::new();
+    for a in accuracy {
+        operations
+            .entry(&a.operation)
+            .or_default()
+            .insert(&a.library, a);
+    }
+
+    METRICS
+        .iter()
+        .map(|(title, value)| {
+            let mut titles = vec![*title];
+            titles.extend(&libs);
+            let rows = operations
+                .iter()
+                .map(|(operation, results)| {
+                    let mut row = vec![operation.to_string()];
+                    row.extend(libs.iter().map(|lib| match results.get(lib) {
+                        Some(a) if a.failures > 0 => {
+                            format!("{} ({} of {} failed)", value(a), a.failures, a.samples)
+                        }
+                        Some(a) => value(a),
+                        None => "-".to_string(),
+                    }));
+                    row
+                })
+                .collect();
+            (titles, rows)
+        })
+        .collect()
+}
+
+/// Writes the accuracy results as markdown tables.
+pub fn write_markdown<W: Write>(
+    accuracy: &[Accuracy],
+    libs: &[&str],
+    writer: &mut W,
+) -> Result<()> {
+    use prettytable::{format::Alignment, Cell, Row};
+
+    // the first column is the operation, the rest are numbers
+    let align = |index| {
+        if index == 0 {
+            Alignment::LEFT
+        } else {
+            Alignment::RIGHT
+        }
+    };
+    for (index, (titles, rows)) in tables(accuracy, libs).into_iter().enumerate() {
+        if index > 0 {
+            writeln!(writer)?;
+        }
+        let mut table = markdown_table();
+        table.set_titles(Row::new(
+            titles
+                .iter()
+                .enumerate()
+                .map(|(index, title)| Cell::new_align(title, align(index)))
+                .collect(),
+        ));
+        for row in rows {
+            table.add_row(Row::new(
+                row.iter()
+                    .enumerate()
+                    .map(|(index, value)| Cell::new_align(value, align(index)))
+                    .collect(),
+            ));
+        }
+        table.print(writer)?;
+    }
+    Ok(())
+}
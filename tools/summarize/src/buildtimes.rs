@@ -0,0 +1,177 @@
+use crate::{criterion::read_json, markdown_table};
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::{io::Write, path::Path};
+
+/// A build time record exported by `buildbench --format json`. Times are in
+/// seconds.
+#[derive(Debug, Deserialize)]
+pub struct BuildTime {
+    name: String,
+    version: String,
+    profile: String,
+    /// Profile setting overrides, e.g. `lto=fat codegen-units=1`, or `none`.
+    overrides: String,
+    features: String,
+    units: u32,
+    total_mean: f64,
+    self_mean: f64,
+    incremental_mean: Option<f64>,
+    user_clean_mean: Option<f64>,
+    rlib_bytes: Option<u64>,
+}
+
+pub fn read_build_times(path: &Path) -> Result<Vec<BuildTime>> {
+    read_json(path)?.ok_or_else(|| anyhow!("Build times file '{}' not found.", path.display()))
+}
+
+/// An optional column title and its value for a record.
+type OptionalColumn = (&'static str, fn(&BuildTime) -> Option<String>);
+
+fn secs(time: f64) -> String {
+    format!("{:.1}", time)
+}
+
+/// Formats the time of a build that takes a fraction of a second, such as a
+/// rebuild or the user crate, precisely enough to tell libraries apart.
+fn short_secs(time: f64) -> String {
+    format!("{:.3}", time)
+}
+
+/// Returns the column titles and the rows of the build times table. Optional
+/// columns are only included if any record has a value.
+pub fn table(build_times: &[BuildTime]) -> (Vec<&'static str>, Vec<Vec<String>>) {
+    let optional: [OptionalColumn; 3] = [
+        ("incremental (s)", |b| b.incremental_mean.map(short_secs)),
+        ("user clean (s)", |b| b.user_clean_mean.map(short_secs)),
+        ("rlib (KiB)", |b| {
+            b.rlib_bytes
+                .map(|bytes| format!("{:.1}", bytes as f64 / 1024.0))
+        }),
+    ];
+    let optional: Vec<_> = optional
+        .iter()
+        .filter(|(_, value)| build_times.iter().any(|b| value(b).is_some()))
+        .collect();
+
+    let mut titles = vec![
+        "crate",
+        "version",
+        "profile",
+        "overrides",
+        "features",
+        "total (s)",
+        "self (s)",
+    ];
+    titles.extend(optional.iter().map(|(title, _)| *title));
+    titles.push("units");
+
+    let rows = build_times
+        .iter()
+        .map(|b| {
+            let mut row = vec![
+                b.name.clone(),
+                b.version.clone(),
+                b.profile.clone(),
+                b.overrides.clone(),
+                b.features.clone(),
+                secs(b.total_mean),
+                secs(b.self_mean),
+            ];
+            row.extend(
+                optional
+                    .iter()
+                    .map(|(_, value)| value(b).unwrap_or_else(|| "-".to_string())),
+            );
+            row.push(b.units.to_string());
+            row
+        })
+        .collect();
+    (titles, rows)
+}
+
+/// Writes the build times as a markdown table.
+pub fn write_markdown<W: Write>(build_times: &[BuildTime], writer: &mut W) -> Result<()> {
+    use prettytable::{format::Alignment, Cell, Row};
+
+    let (titles, rows) = table(build_times);
+    // the first five columns are text, the rest are numbers
+    let align = |index| {
+        if index < 5 {
+            Alignment::LEFT
+        } else {
+            Alignment::RIGHT
+        }
+    };
+    let mut table = markdown_table();
+    table.set_titles(Row::new(
+        titles
+            .iter()
+            .enumerate()
+            .map(|(index, title)| Cell::new_align(title, align(index)))
+            .collect(),
+    ));
+    for row in rows {
+        table.add_row(Row::new(
+            row.iter()
+                .enumerate()
+                .map(|(index, value)| Cell::new_align(value, align(index)))
+                .collect(),
+        ));
+    }
+    table.print(writer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_time(overrides: &str) -> BuildTime {
+        BuildTime {
+            name: "glam".to_string(),
+            version: "0.33.2".to_string(),
+            profile: "release".to_string(),
+            overrides: overrides.to_string(),
+            features: "defaults".to_string(),
+            units: 1,
+            total_mean: 1.0,
+            self_mean: 1.0,
+            incremental_mean: None,
+            user_clean_mean: None,
+            rlib_bytes: None,
+        }
+    }
+
+    #[test]
+    fn overrides_distinguish_rows() {
+        let (titles, rows) = table(&[build_time("none"), build_time("lto=fat")]);
+        assert_eq!(titles[3], "overrides");
+        assert_eq!(rows[0][3], "none");
+        assert_eq!(rows[1][3], "lto=fat");
+        assert_ne!(rows[0], rows[1]);
+    }
+
+    #[test]
+    fn optional_columns_need_a_value() {
+        let mut with_rlib = build_time("none");
+        with_rlib.rlib_bytes = Some(2048);
+        let (titles, _) = table(&[build_time("none")]);
+        assert!(!titles.contains(&"rlib (KiB)"));
+        let (titles, rows) = table(&[build_time("none"), with_rlib]);
+        let index = titles.iter().position(|&t| t == "rlib (KiB)").unwrap();
+        assert_eq!(rows[0][index], "-");
+        assert_eq!(rows[1][index], "2.0");
+    }
+
+    #[test]
+    fn rebuild_times_keep_precision() {
+        let mut build_time = build_time("none");
+        build_time.incremental_mean = Some(0.0425);
+        build_time.user_clean_mean = Some(0.3127);
+        let (titles, rows) = table(&[build_time]);
+        let value = |title| rows[0][titles.iter().position(|&t| t == title).unwrap()].as_str();
+        assert_eq!(value("incremental (s)"), "0.043");
+        assert_eq!(value("user clean (s)"), "0.313");
+    }
+}
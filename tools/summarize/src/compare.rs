@@ -0,0 +1,247 @@
+use crate::{criterion::BenchResult, fmt_time, markdown_table};
+use anyhow::Result;
+use std::{collections::BTreeMap, io::Write};
+
+/// The outcome of comparing a benchmark between two baselines.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Change {
+    /// The confidence intervals overlap.
+    Insignificant,
+    /// The new confidence interval is entirely below the base one.
+    Improved,
+    /// The new confidence interval is entirely above the base one.
+    Regressed,
+}
+
+impl Change {
+    fn new(base: &BenchResult, new: &BenchResult) -> Self {
+        if new.lower_bound > base.upper_bound {
+            Change::Regressed
+        } else if new.upper_bound < base.lower_bound {
+            Change::Improved
+        } else {
+            Change::Insignificant
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Change::Insignificant => "",
+            Change::Improved => "improved",
+            Change::Regressed => "regressed",
+        }
+    }
+}
+
+/// A benchmark present in at least one of the compared baselines.
+struct Comparison<'a> {
+    bench_name: String,
+    library: &'a str,
+    base: Option<&'a BenchResult>,
+    new: Option<&'a BenchResult>,
+}
+
+impl Comparison<'_> {
+    /// Percentage change in time from base to new, positive is slower.
+    fn percent_change(&self) -> Option<f64> {
+        match (self.base, self.new) {
+            (Some(base), Some(new)) => {
+                Some((new.point_estimate - base.point_estimate) / base.point_estimate * 100.0)
+            }
+            _ => None,
+        }
+    }
+
+    fn change(&self) -> Option<Change> {
+        match (self.base, self.new) {
+            (Some(base), Some(new)) => Some(Change::new(base, new)),
+            _ => None,
+        }
+    }
+}
+
+fn pair_results<'a>(
+    base: &'a [BenchResult],
+    new: &'a [BenchResult],
+    libs: &[&str],
+) -> Vec<Comparison<'a>> {
+    // key by bench name then library order so the output is sorted the same as the summary
+    let mut comparisons: BTreeMap<(String, Option<usize>), Comparison<'a>> = BTreeMap::new();
+    let lib_index = |lib: &str| libs.iter().position(|&l| l == lib);
+    for (is_base, results) in [(true, base), (false, new)] {
+        for result in results {
+            let comparison = comparisons
+                .entry((result.bench_name(), lib_index(&result.library)))
+                .or_insert_with(|| Comparison {
+                    bench_name: result.bench_name(),
+                    library: &result.library,
+                    base: None,
+                    new: None,
+                });
+            if is_base {
+                comparison.base.get_or_insert(result);
+            } else {
+                comparison.new.get_or_insert(result);
+            }
+        }
+    }
+    comparisons.into_values().collect()
+}
+
+/// Prints a table comparing the `base` and `new` baselines and returns the
+/// number of significant regressions larger than `max_regression` percent.
+pub fn compare<W: Write>(
+    base_name: &str,
+    base: &[BenchResult],
+    new_name: &str,
+    new: &[BenchResult],
+    libs: &[&str],
+    max_regression: f64,
+    writer: &mut W,
+) -> Result<usize> {
+    use prettytable::{format::Alignment, Cell, Row};
+
+    let mut table = markdown_table();
+    table.set_titles(Row::new(vec![
+        Cell::new_align("benchmark", Alignment::LEFT),
+        Cell::new_align("library", Alignment::LEFT),
+        Cell::new_align(base_name, Alignment::RIGHT),
+        Cell::new_align(new_name, Alignment::RIGHT),
+        Cell::new_align("change", Alignment::RIGHT),
+        Cell::new_align("significant", Alignment::LEFT),
+    ]));
+
+    let mut regressions = 0;
+    for comparison in pair_results(base, new, libs) {
+        let max_value = comparison
+            .base
+            .iter()
+            .chain(comparison.new.iter())
+            .map(|result| result.point_estimate)
+            .fold(0.0, f64::max);
+        let fmt_result = |result: Option<&BenchResult>| {
+            result.map_or("N/A".to_string(), |result| {
+                fmt_time(result.point_estimate, max_value)
+            })
+        };
+        let percent_change = comparison.percent_change();
+        let change = comparison.change();
+
+        let mut significant = change.map_or("", |change| change.as_str()).to_string();
+        if change == Some(Change::Regressed)
+            && percent_change.is_some_and(|percent| percent > max_regression)
+        {
+            regressions += 1;
+            significant.push_str(" (!)");
+        }
+
+        table.add_row(Row::new(vec![
+            Cell::new_align(&comparison.bench_name, Alignment::LEFT),
+            Cell::new_align(comparison.library, Alignment::LEFT),
+            Cell::new_align(&fmt_result(comparison.base), Alignment::RIGHT),
+            Cell::new_align(&fmt_result(comparison.new), Alignment::RIGHT),
+            Cell::new_align(
+                &percent_change.map_or("N/A".to_string(), |percent| format!("{:+.2}%", percent)),
+                Alignment::RIGHT,
+            ),
+            Cell::new_align(&significant, Alignment::LEFT),
+        ]));
+    }
+
+    table.print(writer)?;
+    Ok(regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(
+        library: &str,
+        point_estimate: f64,
+        lower_bound: f64,
+        upper_bound: f64,
+    ) -> BenchResult {
+        BenchResult {
+            group_id: "scalar matrix4 inverse".to_string(),
+            input_size: None,
+            library: library.to_string(),
+            point_estimate,
+            lower_bound,
+            upper_bound,
+            throughput_unit: None,
+            throughput: None,
+        }
+    }
+
+    #[test]
+    fn overlapping_intervals_are_insignificant() {
+        let base = result("glam", 10.0, 9.0, 11.0);
+        assert_eq!(
+            Change::new(&base, &result("glam", 11.5, 10.5, 12.5)),
+            Change::Insignificant
+        );
+        // touching intervals still overlap
+        assert_eq!(
+            Change::new(&base, &result("glam", 12.0, 11.0, 13.0)),
+            Change::Insignificant
+        );
+        assert_eq!(
+            Change::new(&base, &result("glam", 12.5, 11.5, 13.5)),
+            Change::Regressed
+        );
+        assert_eq!(
+            Change::new(&base, &result("glam", 7.5, 6.5, 8.5)),
+            Change::Improved
+        );
+    }
+
+    #[test]
+    fn only_significant_regressions_over_the_maximum_count() {
+        let base = [
+            result("glam", 10.0, 9.9, 10.1),
+            result("cgmath", 10.0, 9.0, 11.0),
+            result("nalgebra", 10.0, 9.9, 10.1),
+            result("ultraviolet", 10.0, 9.9, 10.1),
+        ];
+        let new = [
+            // significant and 20% slower
+            result("glam", 12.0, 11.9, 12.1),
+            // 20% slower but within the noise
+            result("cgmath", 12.0, 10.5, 13.5),
+            // significant but only 5% slower
+            result("nalgebra", 10.5, 10.4, 10.6),
+            // significant and 20% faster
+            result("ultraviolet", 8.0, 7.9, 8.1),
+        ];
+        let libs = ["glam", "cgmath", "nalgebra", "ultraviolet"];
+        let mut output = Vec::new();
+        let regressions = compare("base", &base, "new", &new, &libs, 10.0, &mut output).unwrap();
+        assert_eq!(regressions, 1);
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.matches("(!)").count(), 1);
+        assert!(output.contains("improved"));
+
+        let regressions = compare("base", &base, "new", &new, &libs, 1.0, &mut Vec::new()).unwrap();
+        assert_eq!(regressions, 2);
+    }
+
+    #[test]
+    fn missing_results_are_not_regressions() {
+        let base = [result("glam", 10.0, 9.9, 10.1)];
+        let new = [result("cgmath", 20.0, 19.9, 20.1)];
+        let mut output = Vec::new();
+        let regressions = compare(
+            "base",
+            &base,
+            "new",
+            &new,
+            &["glam", "cgmath"],
+            0.0,
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(regressions, 0);
+        assert_eq!(String::from_utf8(output).unwrap().matches("N/A").count(), 4);
+    }
+}
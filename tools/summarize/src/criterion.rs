@@ -0,0 +1,159 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, File},
+    io::{BufReader, ErrorKind},
+    path::Path,
+};
+
+/// The subset of Criterion's `benchmark.json` that we use.
+#[derive(Debug, Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    function_id: Option<String>,
+    value_str: Option<String>,
+    throughput: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConfidenceInterval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Estimate {
+    confidence_interval: ConfidenceInterval,
+    point_estimate: f64,
+}
+
+/// The subset of Criterion's `estimates.json` that we use.
+#[derive(Debug, Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    slope: Option<Estimate>,
+}
+
+/// A single library's result for a single benchmark. Times are in nanoseconds.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchResult {
+    pub group_id: String,
+    pub input_size: Option<u64>,
+    pub library: String,
+    pub point_estimate: f64,
+    pub lower_bound: f64,
+    pub upper_bound: f64,
+    pub throughput_unit: Option<String>,
+    pub throughput: Option<u64>,
+}
+
+impl BenchResult {
+    /// The benchmark name as displayed in the summary table, e.g. `scalar euler 2d x10000`.
+    pub fn bench_name(&self) -> String {
+        match self.input_size {
+            Some(input_size) => format!("{} x{}", self.group_id, input_size),
+            None => self.group_id.clone(),
+        }
+    }
+}
+
+pub fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Option<T>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    serde_json::from_reader(BufReader::new(file))
+        .map(Some)
+        .map_err(|e| anyhow!("Failed to parse {:?}: {}", path, e))
+}
+
+/// Criterion serializes throughput as an externally tagged enum such as
+/// `{"Elements": 10000}`.
+fn parse_throughput(value: Option<serde_json::Value>) -> (Option<String>, Option<u64>) {
+    if let Some(serde_json::Value::Object(map)) = value {
+        if let Some((unit, amount)) = map.into_iter().next() {
+            if let Some(amount) = amount.as_u64() {
+                return (Some(unit.to_lowercase()), Some(amount));
+            }
+        }
+    }
+    (None, None)
+}
+
+fn parse_bench(json_dir: &Path) -> Result<Option<BenchResult>> {
+    let benchmark: BenchmarkId = match read_json(&json_dir.join("benchmark.json"))? {
+        Some(benchmark) => benchmark,
+        None => return Ok(None),
+    };
+    let estimates: Estimates = match read_json(&json_dir.join("estimates.json"))? {
+        Some(estimates) => estimates,
+        None => return Ok(None),
+    };
+
+    // older Criterion versions don't always record a slope, fall back to the mean
+    let estimate = estimates.slope.unwrap_or(estimates.mean);
+    let (throughput_unit, throughput) = parse_throughput(benchmark.throughput);
+
+    Ok(Some(BenchResult {
+        group_id: benchmark.group_id,
+        input_size: benchmark.value_str.and_then(|s| s.parse().ok()),
+        library: benchmark.function_id.unwrap_or_default(),
+        point_estimate: estimate.point_estimate,
+        lower_bound: estimate.confidence_interval.lower_bound,
+        upper_bound: estimate.confidence_interval.upper_bound,
+        throughput_unit,
+        throughput,
+    }))
+}
+
+/// Reads all results for the given `libs` from a Criterion output directory.
+///
+/// `baseline` is the name of the Criterion baseline to read, `new` is the most
+/// recent run and other names are those saved with `--save-baseline`.
+///
+/// Results are sorted by group, input size and then the order of `libs`.
+pub fn collect_benches(
+    criterion_dir: &Path,
+    libs: &[&str],
+    baseline: &str,
+) -> Result<Vec<BenchResult>> {
+    let mut results = Vec::new();
+    for bench_entry in fs::read_dir(criterion_dir)? {
+        let bench_path = bench_entry?.path();
+        if bench_path.file_name().is_none_or(|name| name == "report") || !bench_path.is_dir() {
+            continue;
+        }
+        for lib_name in libs {
+            let lib_path = bench_path.join(lib_name);
+            if !lib_path.is_dir() {
+                continue;
+            }
+            let baseline_path = lib_path.join(baseline);
+            if baseline_path.is_dir() {
+                results.extend(parse_bench(&baseline_path)?);
+                continue;
+            }
+            // check for input sizes
+            for input_entry in fs::read_dir(&lib_path)? {
+                let input_path = input_entry?.path();
+                let is_input_size = input_path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.parse::<u64>().is_ok());
+                if is_input_size {
+                    results.extend(parse_bench(&input_path.join(baseline))?);
+                }
+            }
+        }
+    }
+
+    let lib_index = |lib: &str| libs.iter().position(|&l| l == lib);
+    results.sort_by(|a, b| {
+        a.group_id
+            .cmp(&b.group_id)
+            .then(a.input_size.cmp(&b.input_size))
+            .then(lib_index(&a.library).cmp(&lib_index(&b.library)))
+    });
+    Ok(results)
+}
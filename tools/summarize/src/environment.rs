@@ -0,0 +1,394 @@
+use crate::{criterion::read_json, markdown_table};
+use anyhow::Result;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt::Display, io::Write, path::Path};
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct Profile {
+    name: String,
+    opt_level: String,
+    debug: String,
+}
+
+/// The machine and toolchain environment recorded by a benchmark group in
+/// `mathbench-env/<group>/<baseline>.json` next to the Criterion output.
+#[derive(Debug, PartialEq, Deserialize)]
+struct Record {
+    cpu: String,
+    target: String,
+    target_features: Vec<String>,
+    rustc: String,
+    rustflags: String,
+    profile: Profile,
+    features: Vec<String>,
+    crates: BTreeMap<String, String>,
+    /// Input seed, not recorded by older runs.
+    #[serde(default)]
+    seed: Option<u64>,
+    /// Input distribution, older runs only used uniform inputs.
+    #[serde(default = "uniform_inputs")]
+    inputs: String,
+}
+
+fn uniform_inputs() -> String {
+    "uniform".to_string()
+}
+
+fn fmt_list<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    let items: Vec<String> = items
+        .into_iter()
+        .map(|item| format!("`{}`", item))
+        .collect();
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+impl Record {
+    /// Returns `(name, value, affects_results)` for each recorded field.
+    /// Results are only comparable if the fields that affect results match.
+    fn fields(&self) -> Vec<(&'static str, String, bool)> {
+        vec![
+            ("cpu", format!("`{}`", self.cpu), true),
+            ("target", format!("`{}`", self.target), true),
+            ("target features", fmt_list(&self.target_features), true),
+            ("rustc", format!("`{}`", self.rustc), true),
+            (
+                "RUSTFLAGS",
+                fmt_list(Some(&self.rustflags).filter(|flags| !flags.is_empty())),
+                true,
+            ),
+            (
+                "profile",
+                format!(
+                    "`{}` (opt-level `{}`, debug `{}`)",
+                    self.profile.name, self.profile.opt_level, self.profile.debug
+                ),
+                true,
+            ),
+            ("features", fmt_list(&self.features), false),
+            (
+                "crates",
+                fmt_list(
+                    self.crates
+                        .iter()
+                        .map(|(name, version)| format!("{} {}", name, version)),
+                ),
+                false,
+            ),
+            (
+                "seed",
+                fmt_list(self.seed.map(|seed| format!("{:#x}", seed))),
+                false,
+            ),
+            // the input distribution changes the time some operations take
+            ("inputs", format!("`{}`", self.inputs), true),
+        ]
+    }
+}
+
+/// The environments recorded by the benchmark groups of a set of results, with
+/// the number of groups that recorded each.
+#[derive(Debug)]
+pub struct Environment {
+    records: Vec<(Record, usize)>,
+}
+
+impl Environment {
+    /// Returns `(name, value, affects_results)` for each recorded field. A
+    /// field the groups recorded different values of lists each value with
+    /// the number of groups that recorded it.
+    fn fields(&self) -> Vec<(&'static str, String, bool)> {
+        let fields: Vec<_> = self
+            .records
+            .iter()
+            .map(|(record, groups)| (record.fields(), *groups))
+            .collect();
+        let (first, _) = &fields[0];
+        (0..first.len())
+            .map(|index| {
+                let (name, _, affects_results) = first[index];
+                let mut values: Vec<(&str, usize)> = Vec::new();
+                for (fields, groups) in &fields {
+                    let value = fields[index].1.as_str();
+                    match values.iter_mut().find(|(v, _)| *v == value) {
+                        Some((_, count)) => *count += groups,
+                        None => values.push((value, *groups)),
+                    }
+                }
+                let value = if values.len() == 1 {
+                    values[0].0.to_string()
+                } else {
+                    values
+                        .iter()
+                        .map(|(value, groups)| format!("{} ({} groups)", value, groups))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                (name, value, affects_results)
+            })
+            .collect()
+    }
+
+    /// Returns the names of the fields that affect results which the groups
+    /// recorded different values of, i.e. the results aren't comparable with
+    /// each other.
+    pub fn conflicts(&self) -> Vec<&'static str> {
+        let (first, _) = &self.records[0];
+        first
+            .fields()
+            .into_iter()
+            .enumerate()
+            .filter(|(index, (_, value, affects_results))| {
+                *affects_results
+                    && self
+                        .records
+                        .iter()
+                        .any(|(record, _)| record.fields()[*index].1 != *value)
+            })
+            .map(|(_, (name, _, _))| name)
+            .collect()
+    }
+
+    /// Returns the recorded compiler version, e.g. `rustc 1.56.1 (59eed8a2a
+    /// 2021-11-01)`, or `None` if the groups were compiled with different
+    /// versions.
+    pub fn rustc(&self) -> Option<&str> {
+        let (first, _) = &self.records[0];
+        if self
+            .records
+            .iter()
+            .all(|(record, _)| record.rustc == first.rustc)
+        {
+            Some(&first.rustc)
+        } else {
+            None
+        }
+    }
+
+    /// Writes the environment as a markdown list.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> Result<()> {
+        for (name, value, _) in self.fields() {
+            writeln!(writer, "* {}: {}", name, value)?;
+        }
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    /// Returns `(name, value)` for each recorded field, for the HTML report.
+    pub fn field_values(&self) -> Vec<(&'static str, String)> {
+        self.fields()
+            .into_iter()
+            .map(|(name, value, _)| (name, value))
+            .collect()
+    }
+
+    /// Writes a table of the fields that differ between two environments and
+    /// returns the names of the differing fields that affect results.
+    pub fn write_differences<W: Write>(
+        base_name: &str,
+        base: &Environment,
+        new_name: &str,
+        new: &Environment,
+        writer: &mut W,
+    ) -> Result<Vec<&'static str>> {
+        use prettytable::{format::Alignment, Cell, Row};
+
+        let mut table = markdown_table();
+        table.set_titles(Row::new(vec![
+            Cell::new_align("environment", Alignment::LEFT),
+            Cell::new_align(base_name, Alignment::LEFT),
+            Cell::new_align(new_name, Alignment::LEFT),
+        ]));
+
+        let mut mismatches = Vec::new();
+        for ((name, base_value, affects_results), (_, new_value, _)) in
+            base.fields().into_iter().zip(new.fields())
+        {
+            if base_value == new_value {
+                continue;
+            }
+            if affects_results {
+                mismatches.push(name);
+            }
+            table.add_row(Row::new(vec![
+                Cell::new_align(name, Alignment::LEFT),
+                Cell::new_align(&base_value, Alignment::LEFT),
+                Cell::new_align(&new_value, Alignment::LEFT),
+            ]));
+        }
+
+        if !table.is_empty() {
+            table.print(writer)?;
+            writeln!(writer)?;
+        }
+        Ok(mismatches)
+    }
+}
+
+/// Reads the environments recorded for `groups` in `baseline`, if any.
+pub fn read_environment<'a>(
+    criterion_dir: &Path,
+    baseline: &str,
+    groups: impl IntoIterator<Item = &'a str>,
+) -> Result<Option<Environment>> {
+    let dir = criterion_dir.join("mathbench-env");
+    let file_name = format!("{}.json", baseline);
+    let mut records: Vec<(Record, usize)> = Vec::new();
+    for group in groups {
+        let record = match read_json(&dir.join(group).join(&file_name))? {
+            Some(record) => record,
+            None => continue,
+        };
+        match records.iter_mut().find(|(r, _)| *r == record) {
+            Some((_, count)) => *count += 1,
+            None => records.push((record, 1)),
+        }
+    }
+    Ok(if records.is_empty() {
+        None
+    } else {
+        Some(Environment { records })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(rustc: &str, inputs: &str) -> Record {
+        serde_json::from_value(serde_json::json!({
+            "cpu": "cpu",
+            "target": "x86_64-unknown-linux-gnu",
+            "target_features": ["sse2"],
+            "rustc": rustc,
+            "rustflags": "",
+            "profile": { "name": "bench", "opt_level": "3", "debug": "false" },
+            "features": ["default"],
+            "crates": { "glam": "0.33.2" },
+            "seed": 1,
+            "inputs": inputs,
+        }))
+        .unwrap()
+    }
+
+    fn environment(inputs: &str) -> Environment {
+        Environment {
+            records: vec![(record("1.95.0", inputs), 1)],
+        }
+    }
+
+    fn rustc(environment: &Environment) -> String {
+        let (_, rustc) = environment
+            .field_values()
+            .into_iter()
+            .find(|(name, _)| *name == "rustc")
+            .unwrap();
+        rustc
+    }
+
+    #[test]
+    fn different_inputs_affect_results() {
+        let mut output = Vec::new();
+        let mismatches = Environment::write_differences(
+            "uniform",
+            &environment("uniform"),
+            "subnormal",
+            &environment("subnormal"),
+            &mut output,
+        )
+        .unwrap();
+        assert_eq!(mismatches, ["inputs"]);
+
+        let mismatches = Environment::write_differences(
+            "base",
+            &environment("uniform"),
+            "new",
+            &environment("uniform"),
+            &mut output,
+        )
+        .unwrap();
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn groups_are_merged_and_conflicts_flagged() {
+        let environment = Environment {
+            records: vec![
+                (record("1.95.0", "uniform"), 2),
+                (record("1.97.0-nightly", "uniform"), 1),
+            ],
+        };
+        assert_eq!(environment.conflicts(), ["rustc"]);
+        assert_eq!(environment.rustc(), None);
+        assert_eq!(
+            rustc(&environment),
+            "`1.95.0` (2 groups), `1.97.0-nightly` (1 groups)"
+        );
+
+        let environment = Environment {
+            records: vec![(record("1.95.0", "uniform"), 3)],
+        };
+        assert!(environment.conflicts().is_empty());
+        assert_eq!(environment.rustc(), Some("1.95.0"));
+        assert_eq!(rustc(&environment), "`1.95.0`");
+    }
+
+    #[test]
+    fn groups_are_read_separately() {
+        let dir = std::env::temp_dir().join(format!("mathbench-env-test-{}", std::process::id()));
+        let write = |path: std::path::PathBuf, rustc: &str| {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            let json = serde_json::json!({
+                "cpu": "cpu",
+                "target": "x86_64-unknown-linux-gnu",
+                "target_features": [],
+                "rustc": rustc,
+                "rustflags": "",
+                "profile": { "name": "bench", "opt_level": "3", "debug": "false" },
+                "features": [],
+                "crates": {},
+            });
+            std::fs::write(path, json.to_string()).unwrap();
+        };
+        let env_dir = dir.join("mathbench-env");
+        write(
+            env_dir.join("scalar vector3 dot").join("new.json"),
+            "1.95.0",
+        );
+        write(
+            env_dir.join("wide vector3 dot").join("new.json"),
+            "1.97.0-nightly",
+        );
+
+        let scalar = read_environment(&dir, "new", vec!["scalar vector3 dot"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(rustc(&scalar), "`1.95.0`");
+        // groups without a recorded environment are skipped
+        let scalar = read_environment(
+            &dir,
+            "new",
+            vec!["scalar vector3 dot", "scalar matrix4 inverse"],
+        )
+        .unwrap()
+        .unwrap();
+        assert!(scalar.conflicts().is_empty());
+        assert!(
+            read_environment(&dir, "new", vec!["scalar matrix4 inverse"])
+                .unwrap()
+                .is_none()
+        );
+        let all = read_environment(&dir, "new", vec!["scalar vector3 dot", "wide vector3 dot"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(all.conflicts(), ["rustc"]);
+        assert!(read_environment(&dir, "base", vec!["scalar vector3 dot"])
+            .unwrap()
+            .is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,78 @@
+use crate::criterion::BenchResult;
+use anyhow::Result;
+use std::io::Write;
+
+pub fn write_json<W: Write>(results: &[BenchResult], mut writer: W) -> Result<()> {
+    serde_json::to_writer_pretty(&mut writer, results)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(results: &[BenchResult], writer: W) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for result in results {
+        writer.serialize(result)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<BenchResult> {
+        vec![
+            BenchResult {
+                group_id: "scalar matrix4 inverse".to_string(),
+                input_size: None,
+                library: "glam".to_string(),
+                point_estimate: 10.5,
+                lower_bound: 10.0,
+                upper_bound: 11.0,
+                throughput_unit: None,
+                throughput: None,
+            },
+            BenchResult {
+                group_id: "scalar euler 2d".to_string(),
+                input_size: Some(10000),
+                library: "cgmath".to_string(),
+                point_estimate: 2000.0,
+                lower_bound: 1900.0,
+                upper_bound: 2100.0,
+                throughput_unit: Some("elements".to_string()),
+                throughput: Some(10000),
+            },
+        ]
+    }
+
+    #[test]
+    fn json_has_a_record_per_result() {
+        let mut output = Vec::new();
+        write_json(&results(), &mut output).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        let records = json.as_array().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["group_id"], "scalar matrix4 inverse");
+        assert_eq!(records[0]["input_size"], serde_json::Value::Null);
+        assert_eq!(records[1]["throughput_unit"], "elements");
+        assert_eq!(records[1]["point_estimate"], 2000.0);
+    }
+
+    #[test]
+    fn csv_leaves_missing_values_empty() {
+        let mut output = Vec::new();
+        write_csv(&results(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "group_id,input_size,library,point_estimate,lower_bound,upper_bound,\
+                 throughput_unit,throughput",
+                "scalar matrix4 inverse,,glam,10.5,10.0,11.0,,",
+                "scalar euler 2d,10000,cgmath,2000.0,1900.0,2100.0,elements,10000",
+            ]
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{
+    accuracy::{self, Accuracy},
+    all_libs,
+    buildtimes::{self, BuildTime},
+    criterion::BenchResult,
+    display_name,
+    environment::Environment,
+    fmt_time, SCALAR_F64_PREFIX, SCALAR_PREFIX, WIDE_PREFIX,
+};
+use anyhow::Result;
+use std::{collections::BTreeMap, io::Write};
+
+/// Bar colors, indexed by the library's position in `all_libs()` so a library
+/// has the same color in every chart regardless of which libraries are shown.
+/// There must be at least one color per library.
+const PALETTE: &[&str] = &[
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac", "#1f77b4", "#17becf", "#d62728", "#2ca02c", "#9467bd", "#8c564b",
+    "#e377c2", "#bcbd22", "#aec7e8", "#ffbb78",
+];
+
+const CHART_WIDTH: f64 = 760.0;
+const LABEL_WIDTH: f64 = 150.0;
+const VALUE_WIDTH: f64 = 130.0;
+const BAR_HEIGHT: f64 = 16.0;
+const BAR_GAP: f64 = 4.0;
+const CLUSTER_HEADER: f64 = 20.0;
+const CLUSTER_GAP: f64 = 8.0;
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; color: #222; }
+h1, h2 { font-weight: normal; }
+figure { margin: 0 0 2em 0; }
+figcaption { font-weight: bold; margin-bottom: 0.5em; }
+svg text { font-size: 12px; }
+.legend span { display: inline-block; margin-right: 1.5em; }
+.legend i { display: inline-block; width: 12px; height: 12px; margin-right: 0.4em; vertical-align: middle; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; border-bottom: 1px solid #ddd; text-align: right; }
+th:nth-child(-n+5), td:nth-child(-n+5) { text-align: left; }
+table.accuracy { margin-bottom: 1em; }
+table.accuracy th:nth-child(n+2), table.accuracy td:nth-child(n+2) { text-align: right; }
+";
+
+fn lib_color(lib: &str) -> &'static str {
+    all_libs()
+        .iter()
+        .position(|&l| l == lib)
+        .and_then(|index| PALETTE.get(index).copied())
+        .unwrap_or("#888888")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A bar's point estimate and confidence interval, scaled to time per element
+/// for benchmarks that report a throughput so input sizes share an axis.
+struct Bar<'a> {
+    library: &'a str,
+    point_estimate: f64,
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+impl<'a> Bar<'a> {
+    fn new(result: &'a BenchResult) -> Self {
+        let elements = match (result.throughput_unit.as_deref(), result.throughput) {
+            (Some("elements"), Some(n)) if n > 0 => n as f64,
+            _ => 1.0,
+        };
+        Bar {
+            library: &result.library,
+            point_estimate: result.point_estimate / elements,
+            lower_bound: result.lower_bound / elements,
+            upper_bound: result.upper_bound / elements,
+        }
+    }
+}
+
+/// Results of a benchmark group, clustered by input size.
+type Clusters<'a> = BTreeMap<Option<u64>, Vec<Bar<'a>>>;
+
+fn write_chart<W: Write>(group_id: &str, clusters: &Clusters, writer: &mut W) -> Result<()> {
+    let per_element = clusters.keys().any(|input_size| input_size.is_some());
+    let max_value = clusters
+        .values()
+        .flatten()
+        .map(|bar| bar.upper_bound.max(bar.point_estimate))
+        .fold(0.0, f64::max);
+    let bar_area = CHART_WIDTH - LABEL_WIDTH - VALUE_WIDTH;
+    let scale = |x: f64| {
+        if max_value > 0.0 {
+            x / max_value * bar_area
+        } else {
+            0.0
+        }
+    };
+
+    let height: f64 = clusters
+        .iter()
+        .map(|(input_size, bars)| {
+            let header = if input_size.is_some() {
+                CLUSTER_HEADER
+            } else {
+                0.0
+            };
+            header + bars.len() as f64 * (BAR_HEIGHT + BAR_GAP) + CLUSTER_GAP
+        })
+        .sum();
+
+    writeln!(writer, "<figure>")?;
+    write!(writer, "<figcaption>{}", escape(display_name(group_id)))?;
+    if per_element {
+        write!(writer, " (time per element)")?;
+    }
+    writeln!(writer, "</figcaption>")?;
+    writeln!(
+        writer,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = CHART_WIDTH,
+        h = height
+    )?;
+    writeln!(
+        writer,
+        r##"<line x1="{x}" y1="0" x2="{x}" y2="{h}" stroke="#444"/>"##,
+        x = LABEL_WIDTH,
+        h = height
+    )?;
+
+    let mut y = 0.0;
+    for (input_size, bars) in clusters {
+        if let Some(input_size) = input_size {
+            writeln!(
+                writer,
+                r#"<text x="0" y="{}" font-weight="bold">x{}</text>"#,
+                y + CLUSTER_HEADER - 6.0,
+                input_size
+            )?;
+            y += CLUSTER_HEADER;
+        }
+        for bar in bars {
+            let mid = y + BAR_HEIGHT / 2.0;
+            let value = fmt_time(bar.point_estimate, bar.point_estimate);
+            writeln!(
+                writer,
+                r#"<text x="{}" y="{}" text-anchor="end" dominant-baseline="middle">{}</text>"#,
+                LABEL_WIDTH - 6.0,
+                mid,
+                escape(bar.library)
+            )?;
+            writeln!(
+                writer,
+                r#"<rect x="{}" y="{}" width="{:.2}" height="{}" fill="{}"><title>{}: {} [{}, {}]</title></rect>"#,
+                LABEL_WIDTH,
+                y,
+                scale(bar.point_estimate),
+                BAR_HEIGHT,
+                lib_color(bar.library),
+                escape(bar.library),
+                value,
+                fmt_time(bar.lower_bound, bar.point_estimate),
+                fmt_time(bar.upper_bound, bar.point_estimate)
+            )?;
+            // error bar covering the confidence interval
+            let (lower, upper) = (
+                LABEL_WIDTH + scale(bar.lower_bound),
+                LABEL_WIDTH + scale(bar.upper_bound),
+            );
+            let (top, bottom) = (y + 3.0, y + BAR_HEIGHT - 3.0);
+            writeln!(
+                writer,
+                r##"<path d="M{l:.2} {m}H{u:.2}M{l:.2} {t}V{b}M{u:.2} {t}V{b}" stroke="#222" fill="none"/>"##,
+                l = lower,
+                u = upper,
+                m = mid,
+                t = top,
+                b = bottom
+            )?;
+            writeln!(
+                writer,
+                r#"<text x="{:.2}" y="{}" dominant-baseline="middle">{}</text>"#,
+                upper.max(LABEL_WIDTH + scale(bar.point_estimate)) + 6.0,
+                mid,
+                value
+            )?;
+            y += BAR_HEIGHT + BAR_GAP;
+        }
+        y += CLUSTER_GAP;
+    }
+
+    writeln!(writer, "</svg>")?;
+    writeln!(writer, "</figure>")?;
+    Ok(())
+}
+
+fn write_table<W: Write>(
+    class: Option<&str>,
+    titles: &[&str],
+    rows: Vec<Vec<String>>,
+    writer: &mut W,
+) -> Result<()> {
+    match class {
+        Some(class) => writeln!(writer, "<table class=\"{}\">", class)?,
+        None => writeln!(writer, "<table>")?,
+    }
+    writeln!(writer, "<tr>")?;
+    for title in titles {
+        writeln!(writer, "<th>{}</th>", escape(title))?;
+    }
+    writeln!(writer, "</tr>")?;
+    for row in rows {
+        writeln!(writer, "<tr>")?;
+        for value in row {
+            writeln!(writer, "<td>{}</td>", escape(&value))?;
+        }
+        writeln!(writer, "</tr>")?;
+    }
+    writeln!(writer, "</table>")?;
+    Ok(())
+}
+
+/// Writes a self-contained HTML report with a bar chart per benchmark group.
+/// Error bars show Criterion's confidence interval. Charts are plain inline SVG
+/// so the report can be viewed offline and published as a CI artifact.
+pub fn write_html<W: Write>(
+    results: &[BenchResult],
+    libs: &[&str],
+    environment: Option<&Environment>,
+    accuracy: &[Accuracy],
+    build_times: &[BuildTime],
+    mut writer: W,
+) -> Result<()> {
+    // results are already sorted by group, input size and library
+    let mut groups: BTreeMap<&str, Clusters> = BTreeMap::new();
+    for result in results {
+        groups
+            .entry(&result.group_id)
+            .or_default()
+            .entry(result.input_size)
+            .or_default()
+            .push(Bar::new(result));
+    }
+
+    writeln!(writer, "<!DOCTYPE html>")?;
+    writeln!(writer, "<html lang=\"en\">")?;
+    writeln!(writer, "<head>")?;
+    writeln!(writer, "<meta charset=\"utf-8\">")?;
+    writeln!(writer, "<title>mathbench results</title>")?;
+    writeln!(writer, "<style>\n{}</style>", STYLE)?;
+    writeln!(writer, "</head>")?;
+    writeln!(writer, "<body>")?;
+    writeln!(writer, "<h1>mathbench results</h1>")?;
+
+    if let Some(environment) = environment {
+        writeln!(writer, "<ul class=\"environment\">")?;
+        for (name, value) in environment.field_values() {
+            // values are formatted as markdown code spans, drop the backticks
+            writeln!(
+                writer,
+                "<li>{}: {}</li>",
+                escape(name),
+                escape(&value.replace('`', ""))
+            )?;
+        }
+        writeln!(writer, "</ul>")?;
+    }
+
+    writeln!(writer, "<p class=\"legend\">")?;
+    for lib in libs {
+        if results.iter().any(|result| result.library == *lib) {
+            writeln!(
+                writer,
+                "<span><i style=\"background: {}\"></i>{}</span>",
+                lib_color(lib),
+                escape(lib)
+            )?;
+        }
+    }
+    writeln!(writer, "</p>")?;
+
+    for (heading, prefix) in [
+        ("Scalar", SCALAR_PREFIX),
+        ("Scalar f64", SCALAR_F64_PREFIX),
+        ("Wide", WIDE_PREFIX),
+    ] {
+        let mut section = groups
+            .iter()
+            .filter(|(group_id, _)| group_id.starts_with(prefix))
+            .peekable();
+        if section.peek().is_none() {
+            continue;
+        }
+        writeln!(writer, "<h2>{}</h2>", heading)?;
+        for (group_id, clusters) in section {
+            write_chart(group_id, clusters, &mut writer)?;
+        }
+    }
+
+    let accuracy_tables = accuracy::tables(accuracy, libs);
+    if !accuracy_tables.is_empty() {
+        writeln!(writer, "<h2>Accuracy</h2>")?;
+        for (titles, rows) in accuracy_tables {
+            write_table(Some("accuracy"), &titles, rows, &mut writer)?;
+        }
+    }
+
+    if !build_times.is_empty() {
+        let (titles, rows) = buildtimes::table(build_times);
+        writeln!(writer, "<h2>Build times</h2>")?;
+        write_table(None, &titles, rows, &mut writer)?;
+    }
+
+    writeln!(writer, "</body>")?;
+    writeln!(writer, "</html>")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn lib_colors_are_unique() {
+        let libs = all_libs();
+        let colors: HashSet<&str> = libs.iter().map(|lib| lib_color(lib)).collect();
+        assert_eq!(colors.len(), libs.len());
+        assert!(!colors.contains("#888888"));
+    }
+}
@@ -1,3 +1,580 @@
-fn main() {
-    unimplemented!()
+mod accuracy;
+mod buildtimes;
+mod compare;
+mod criterion;
+mod environment;
+mod export;
+mod html;
+mod readme;
+mod score;
+
+use anyhow::{anyhow, Result};
+use clap::{App, Arg};
+use criterion::{collect_benches, BenchResult};
+use environment::{read_environment, Environment};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    convert::{TryFrom, TryInto},
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+
+const DEFAULT: &[&str] = &["glam", "cgmath", "nalgebra"];
+const OPTIONAL: &[&str] = &["euclid", "vek", "pathfinder", "ultraviolet"];
+const WIDE: &[&str] = &[
+    "glam_f32x1",
+    "ultraviolet_f32x4",
+    "nalgebra_f32x4",
+    "ultraviolet_f32x8",
+    "nalgebra_f32x8",
+    "nalgebra_f32x16",
+    "glam_f64x1",
+    "ultraviolet_f64x2",
+    "nalgebra_f64x2",
+    "ultraviolet_f64x4",
+    "nalgebra_f64x4",
+    "nalgebra_f64x8",
+];
+
+const SCALAR_PREFIX: &str = "scalar ";
+const SCALAR_F64_PREFIX: &str = "scalar-f64 ";
+const WIDE_PREFIX: &str = "wide ";
+
+const CRITERION_DIR: &str = "target/criterion";
+
+/// Benchmark results keyed by benchmark name then library name.
+type Benches = BTreeMap<String, HashMap<String, f64>>;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+enum Format {
+    #[default]
+    Markdown,
+    Json,
+    Csv,
+    Html,
+}
+
+impl TryFrom<&str> for Format {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "markdown" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "html" => Ok(Format::Html),
+            _ => Err(format!("Unknown format \"{}\" found!", value)),
+        }
+    }
+}
+
+impl Format {
+    fn possible_values() -> &'static [&'static str] {
+        &["markdown", "json", "csv", "html"]
+    }
+}
+
+fn scalar_libs() -> Vec<&'static str> {
+    DEFAULT.iter().chain(OPTIONAL).copied().collect()
+}
+
+fn all_libs() -> Vec<&'static str> {
+    scalar_libs()
+        .into_iter()
+        .chain(WIDE.iter().copied())
+        .collect()
+}
+
+fn group_benches(results: &[BenchResult]) -> Benches {
+    let mut benches = Benches::new();
+    for result in results {
+        benches
+            .entry(result.bench_name())
+            .or_default()
+            .entry(result.library.clone())
+            .or_insert(result.point_estimate);
+    }
+    benches
+}
+
+/// Returns the distinct benchmark groups of `results`.
+fn group_ids(results: &[BenchResult]) -> BTreeSet<&str> {
+    results
+        .iter()
+        .map(|result| result.group_id.as_str())
+        .collect()
+}
+
+/// Warns if the groups of a baseline were run in environments whose results
+/// aren't comparable, e.g. some groups were rerun with another compiler.
+fn warn_conflicts(baseline: &str, environment: &Environment) {
+    let conflicts = environment.conflicts();
+    if !conflicts.is_empty() {
+        eprintln!(
+            "The benchmark groups of baseline '{}' were run in different environments ({}).",
+            baseline,
+            conflicts.join(", ")
+        );
+    }
+}
+
+/// Formats `x` with `digits` significant digits the same way Python's `'{:.4}'`
+/// format spec does, which is what the original summary script used. Unlike
+/// `'{:.4g}'` it switches to scientific notation from `digits - 1` digits
+/// before the decimal point.
+fn fmt_significant(x: f64, digits: usize) -> String {
+    let sci = format!("{:.*e}", digits - 1, x);
+    let (mantissa, exp) = sci.split_at(sci.find('e').unwrap());
+    let exp: i32 = exp[1..].parse().unwrap();
+    if exp < -4 || exp >= digits as i32 - 1 {
+        let mantissa = if mantissa.contains('.') {
+            mantissa.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            mantissa
+        };
+        let sign = if exp < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", mantissa, sign, exp.abs())
+    } else {
+        let precision = (digits as i32 - 1 - exp) as usize;
+        let fixed = format!("{:.*}", precision, x);
+        if fixed.contains('.') {
+            let trimmed = fixed.trim_end_matches('0');
+            if trimmed.ends_with('.') {
+                format!("{}0", trimmed)
+            } else {
+                trimmed.to_string()
+            }
+        } else {
+            format!("{}.0", fixed)
+        }
+    }
+}
+
+/// Strips the scalar or wide prefix off a bench name for display. Double
+/// precision benches keep an `f64` prefix, e.g. `f64 matrix4 inverse`.
+fn display_name(bench_name: &str) -> &str {
+    if bench_name.starts_with(SCALAR_F64_PREFIX) {
+        return &bench_name[SCALAR_F64_PREFIX.len() - "f64 ".len()..];
+    }
+    bench_name
+        .strip_prefix(SCALAR_PREFIX)
+        .or_else(|| bench_name.strip_prefix(WIDE_PREFIX))
+        .unwrap_or(bench_name)
+}
+
+/// Creates a `prettytable` table that prints as markdown.
+fn markdown_table() -> prettytable::Table {
+    use prettytable::{
+        format::{FormatBuilder, LinePosition, LineSeparator},
+        Table,
+    };
+
+    let markdown_format = FormatBuilder::new()
+        .padding(1, 1)
+        .borders('|')
+        .separator(LinePosition::Title, LineSeparator::new('-', '|', '|', '|'))
+        .column_separator('|')
+        .build();
+
+    let mut table = Table::new();
+    table.set_format(markdown_format);
+    table
+}
+
+/// Formats a time in nanoseconds, switching to microseconds if `max_value` is
+/// large so all values in a row use the same unit.
+fn fmt_time(x: f64, max_value: f64) -> String {
+    if max_value >= 1000.0 {
+        format!("{} us", fmt_significant(x / 1000.0, 4))
+    } else {
+        format!("{:3.4} ns", x)
+    }
+}
+
+fn fmt_bench(x: Option<f64>, max_value: f64, min_value: f64, threshold: f64) -> String {
+    match x {
+        Some(x) => {
+            let highlight = x / min_value <= threshold;
+            let value = fmt_time(x, max_value);
+            if highlight {
+                format!("__{}__", value)
+            } else {
+                format!("  {}  ", value)
+            }
+        }
+        None => "   N/A      ".to_string(),
+    }
+}
+
+/// Prints a table of the benches whose names start with `prefix`, one of the
+/// scalar, scalar f64 or wide prefixes, as their times aren't comparable.
+fn summarize<W: Write>(
+    results: &[BenchResult],
+    libs: &[&str],
+    prefix: &str,
+    threshold: f64,
+    writer: &mut W,
+) -> Result<()> {
+    use prettytable::{format::Alignment, Cell, Row};
+
+    let mut table = markdown_table();
+
+    let mut titles = vec![Cell::new_align("benchmark", Alignment::LEFT)];
+    for lib in libs {
+        titles.push(Cell::new_align(&format!("  {}  ", lib), Alignment::RIGHT));
+    }
+    table.set_titles(Row::new(titles));
+
+    for (bench_name, bench) in &group_benches(results) {
+        if !bench_name.starts_with(prefix) {
+            continue;
+        }
+
+        let values: Vec<f64> = libs
+            .iter()
+            .filter_map(|lib| bench.get(*lib).copied())
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+        let max_value = values.iter().copied().fold(f64::MIN, f64::max);
+        let mut min_value = values.iter().copied().fold(f64::MAX, f64::min);
+        // hack so nothing is highlighted if there's only one lib to display
+        if libs.len() == 1 {
+            min_value = max_value + 1.0;
+        }
+
+        let mut row = vec![Cell::new_align(display_name(bench_name), Alignment::LEFT)];
+        for lib in libs {
+            row.push(Cell::new_align(
+                &fmt_bench(bench.get(*lib).copied(), max_value, min_value, threshold),
+                Alignment::RIGHT,
+            ));
+        }
+        table.add_row(Row::new(row));
+    }
+
+    table.print(writer)?;
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let choices = all_libs();
+    let matches = App::new("mathbench summarize")
+        .about("Summarizes Criterion results of mathbench benchmarks.")
+        .args(&[
+            Arg::with_name("libs")
+                .takes_value(true)
+                .multiple(true)
+                .required(false)
+                .possible_values(&choices),
+            Arg::with_name("wide")
+                .long("wide")
+                .short('w')
+                .help("include all wide libraries"),
+            Arg::with_name("scalar")
+                .long("scalar")
+                .short('s')
+                .help("include all scalar libraries"),
+            Arg::with_name("all")
+                .long("all")
+                .short('a')
+                .help("include all libraries"),
+            Arg::with_name("threshold")
+                .long("threshold")
+                .short('t')
+                .takes_value(true)
+                .default_value("2.5")
+                .help("percent of minimum value to highlight"),
+            Arg::with_name("criterion-dir")
+                .long("criterion-dir")
+                .short('d')
+                .takes_value(true)
+                .default_value(CRITERION_DIR)
+                .help("directory containing Criterion output"),
+            Arg::with_name("format")
+                .long("format")
+                .short('f')
+                .takes_value(true)
+                .possible_values(Format::possible_values())
+                .help(
+                    "output format, markdown is a table, json and csv include every result, \
+                     html is a report with a chart per benchmark group",
+                ),
+            Arg::with_name("output")
+                .long("output")
+                .short('o')
+                .takes_value(true)
+                .help("write output to a file instead of stdout"),
+            Arg::with_name("score")
+                .long("score")
+                .help("print the geometric mean score of each library after the summary table"),
+            Arg::with_name("build-times")
+                .long("build-times")
+                .takes_value(true)
+                .help("include build times exported by `buildbench --format json` in the report"),
+            Arg::with_name("update-readme")
+                .long("update-readme")
+                .takes_value(true)
+                .value_name("README")
+                .conflicts_with_all(&["format", "output", "score", "build-times", "compare"])
+                .help(
+                    "regenerate the scalar and wide results between the marker comments in README",
+                ),
+            Arg::with_name("compare")
+                .long("compare")
+                .short('c')
+                .takes_value(true)
+                .number_of_values(2)
+                .value_names(&["BASE", "NEW"])
+                .conflicts_with("format")
+                .help("compare two baselines saved with Criterion's --save-baseline"),
+            Arg::with_name("force")
+                .long("force")
+                .requires("compare")
+                .help("compare baselines even if they were run in different environments"),
+            Arg::with_name("max-regression")
+                .long("max-regression")
+                .takes_value(true)
+                .default_value("5")
+                .requires("compare")
+                .help("percent slowdown of a significant regression that causes a non-zero exit"),
+        ])
+        .get_matches();
+
+    let wide = matches.is_present("wide");
+    let libs: Vec<&str> = if matches.is_present("all") {
+        choices.clone()
+    } else if matches.is_present("scalar") {
+        scalar_libs()
+    } else if wide {
+        WIDE.to_vec()
+    } else if let Some(values) = matches.values_of("libs") {
+        let mut libs: Vec<&str> = Vec::new();
+        for lib in values {
+            if !libs.contains(&lib) {
+                libs.push(lib);
+            }
+        }
+        libs
+    } else {
+        DEFAULT.to_vec()
+    };
+
+    let threshold_percent: f64 = matches
+        .value_of("threshold")
+        .unwrap()
+        .parse()
+        .map_err(|e| anyhow!("Invalid threshold: {}", e))?;
+    let threshold = 1.0 + threshold_percent / 100.0;
+
+    let criterion_dir = Path::new(matches.value_of("criterion-dir").unwrap());
+    if !criterion_dir.is_dir() {
+        return Err(anyhow!(
+            "'{}' directory doesn't exist, run `cargo bench` first.",
+            criterion_dir.display()
+        ));
+    }
+
+    let format: Format = matches
+        .value_of("format")
+        .map_or(Ok(Format::default()), |v| v.try_into())
+        .map_err(|e| anyhow!(e))?;
+
+    let mut writer: Box<dyn Write> = match matches.value_of("output") {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout()),
+    };
+
+    if let Some(mut baselines) = matches.values_of("compare") {
+        let base_name = baselines.next().unwrap();
+        let new_name = baselines.next().unwrap();
+        let max_regression: f64 = matches
+            .value_of("max-regression")
+            .unwrap()
+            .parse()
+            .map_err(|e| anyhow!("Invalid max regression: {}", e))?;
+
+        let base = collect_benches(criterion_dir, &libs, base_name)?;
+        let new = collect_benches(criterion_dir, &libs, new_name)?;
+        if base.is_empty() || new.is_empty() {
+            return Err(anyhow!(
+                "No results found for baseline '{}'.",
+                if base.is_empty() { base_name } else { new_name }
+            ));
+        }
+
+        match (
+            read_environment(criterion_dir, base_name, group_ids(&base))?,
+            read_environment(criterion_dir, new_name, group_ids(&new))?,
+        ) {
+            (Some(base_env), Some(new_env)) => {
+                warn_conflicts(base_name, &base_env);
+                warn_conflicts(new_name, &new_env);
+                let mismatches = Environment::write_differences(
+                    base_name,
+                    &base_env,
+                    new_name,
+                    &new_env,
+                    &mut writer,
+                )?;
+                if !mismatches.is_empty() && !matches.is_present("force") {
+                    return Err(anyhow!(
+                        "Baselines '{}' and '{}' were run in different environments ({}), \
+                         use --force to compare them anyway.",
+                        base_name,
+                        new_name,
+                        mismatches.join(", ")
+                    ));
+                }
+            }
+            (base_env, _) => eprintln!(
+                "No environment recorded for baseline '{}', unable to check it matches.",
+                if base_env.is_none() {
+                    base_name
+                } else {
+                    new_name
+                }
+            ),
+        }
+
+        let regressions = compare::compare(
+            base_name,
+            &base,
+            new_name,
+            &new,
+            &libs,
+            max_regression,
+            &mut writer,
+        )?;
+        if regressions != 0 {
+            return Err(anyhow!(
+                "{} benchmark(s) regressed by more than {}%.",
+                regressions,
+                max_regression
+            ));
+        }
+        return Ok(());
+    }
+
+    if let Some(readme_path) = matches.value_of("update-readme") {
+        let results = collect_benches(criterion_dir, &all_libs(), "new")?;
+        return readme::update_readme(
+            Path::new(readme_path),
+            criterion_dir,
+            &results,
+            threshold_percent,
+        );
+    }
+
+    if matches.is_present("score") && format != Format::Markdown {
+        return Err(anyhow!("--score is only supported by the markdown format."));
+    }
+
+    let build_times = match matches.value_of("build-times") {
+        Some(_) if format != Format::Markdown && format != Format::Html => {
+            return Err(anyhow!(
+                "--build-times is only supported by the markdown and html formats."
+            ));
+        }
+        Some(path) => buildtimes::read_build_times(Path::new(path))?,
+        None => Vec::new(),
+    };
+
+    let results = collect_benches(criterion_dir, &libs, "new")?;
+    let environment = read_environment(criterion_dir, "new", group_ids(&results))?;
+    if let Some(environment) = &environment {
+        warn_conflicts("new", environment);
+    }
+    // accuracy is only measured for the scalar libraries
+    let accuracy = if wide {
+        Vec::new()
+    } else {
+        accuracy::read_accuracy(criterion_dir, "new")?
+    };
+    match format {
+        Format::Markdown => {
+            if let Some(environment) = &environment {
+                environment.write_header(&mut writer)?;
+            }
+            // f64 results get their own table and score, they would otherwise
+            // share a column with the f32 results of the same library
+            let prefixes = if wide {
+                vec![WIDE_PREFIX]
+            } else {
+                vec![SCALAR_PREFIX, SCALAR_F64_PREFIX]
+            };
+            let benches = group_benches(&results);
+            for (index, prefix) in prefixes.into_iter().enumerate() {
+                if index != 0 {
+                    if !benches.keys().any(|name| name.starts_with(prefix)) {
+                        continue;
+                    }
+                    writeln!(writer)?;
+                }
+                summarize(&results, &libs, prefix, threshold, &mut writer)?;
+                if matches.is_present("score") {
+                    writeln!(writer)?;
+                    score::score(&benches, &libs, prefix, &mut writer)?;
+                }
+            }
+            if !accuracy::tables(&accuracy, &libs).is_empty() {
+                writeln!(writer)?;
+                accuracy::write_markdown(&accuracy, &libs, &mut writer)?;
+            }
+            if !build_times.is_empty() {
+                writeln!(writer)?;
+                buildtimes::write_markdown(&build_times, &mut writer)?;
+            }
+        }
+        Format::Json => export::write_json(&results, &mut writer)?,
+        Format::Csv => export::write_csv(&results, &mut writer)?,
+        Format::Html => html::write_html(
+            &results,
+            &libs,
+            environment.as_ref(),
+            &accuracy,
+            &build_times,
+            &mut writer,
+        )?,
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_name_keeps_precision() {
+        assert_eq!(display_name("scalar matrix4 inverse"), "matrix4 inverse");
+        assert_eq!(
+            display_name("scalar-f64 matrix4 inverse"),
+            "f64 matrix4 inverse"
+        );
+        assert_eq!(display_name("wide matrix4 inverse"), "matrix4 inverse");
+        assert_eq!(display_name(SCALAR_F64_PREFIX), "f64 ");
+    }
+
+    #[test]
+    fn fmt_significant_matches_python() {
+        // expected values are Python's `'{:.4}'.format(x)`
+        for (x, expected) in [
+            (0.0, "0.0"),
+            (1.0, "1.0"),
+            (2.5, "2.5"),
+            (100.0, "100.0"),
+            (0.1234567, "0.1235"),
+            (12.3456, "12.35"),
+            (1234.5, "1.234e+03"),
+            (12345.6, "1.235e+04"),
+            (9999.5, "1e+04"),
+            (0.00012345, "0.0001234"),
+            (0.000012345, "1.234e-05"),
+        ] {
+            assert_eq!(fmt_significant(x, 4), expected, "{}", x);
+        }
+    }
 }
@@ -0,0 +1,252 @@
+use crate::{
+    criterion::BenchResult, environment::read_environment, group_ids, scalar_libs, summarize,
+    SCALAR_PREFIX, WIDE, WIDE_PREFIX,
+};
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use std::{fs, io::Write, path::Path};
+
+/// The subset of `Cargo.lock` that we use.
+#[derive(Debug, Deserialize)]
+struct Lockfile {
+    package: Vec<LockPackage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LockPackage {
+    name: String,
+    version: String,
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
+/// Returns the crate name of a library column, e.g. `pathfinder` is benched
+/// using `pathfinder_geometry` and `ultraviolet_f32x4` using `ultraviolet`.
+fn crate_name(lib: &str) -> &str {
+    let name = ["_f32x", "_f64x"].iter().fold(lib, |name, suffix| {
+        name.split(suffix).next().unwrap_or(name)
+    });
+    match name {
+        "pathfinder" => "pathfinder_geometry",
+        _ => name,
+    }
+}
+
+/// Looks up the version of `name` that `mathbench` depends on in `Cargo.lock`.
+/// When the lock file contains several versions of a crate the dependency
+/// entry is written as `name version` which is used to pick the right one.
+fn locked_version(lockfile: &Lockfile, name: &str) -> Option<String> {
+    let mathbench = lockfile.package.iter().find(|p| p.name == "mathbench")?;
+    let dependency = mathbench
+        .dependencies
+        .iter()
+        .find(|dep| dep.split(' ').next() == Some(name))?;
+    match dependency.split(' ').nth(1) {
+        Some(version) => Some(version.to_string()),
+        None => lockfile
+            .package
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| p.version.clone()),
+    }
+}
+
+/// Falls back to the version requirement in `Cargo.toml` if there's no lock file.
+fn manifest_version(manifest: &toml::Value, name: &str) -> Option<String> {
+    match manifest.get("dependencies")?.get(name)? {
+        toml::Value::String(version) => Some(version.clone()),
+        dependency => dependency
+            .get("version")?
+            .as_str()
+            .map(|version| version.to_string()),
+    }
+}
+
+/// Returns the `(crate, version)` of each library sorted by crate name.
+fn lib_versions(workspace_dir: &Path, libs: &[&str]) -> Result<Vec<(String, String)>> {
+    let manifest_path = workspace_dir.join("Cargo.toml");
+    let manifest: toml::Value = toml::from_str(
+        &fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?,
+    )?;
+    let lockfile: Option<Lockfile> = match fs::read_to_string(workspace_dir.join("Cargo.lock")) {
+        Ok(contents) => Some(toml::from_str(&contents)?),
+        Err(_) => None,
+    };
+
+    let mut names: Vec<&str> = libs.iter().map(|lib| crate_name(lib)).collect();
+    names.sort_unstable();
+    names.dedup();
+
+    let mut versions = Vec::new();
+    for name in names {
+        let version = lockfile
+            .as_ref()
+            .and_then(|lockfile| locked_version(lockfile, name))
+            .or_else(|| manifest_version(&manifest, name))
+            .ok_or_else(|| anyhow!("No version of '{}' found in Cargo.toml", name))?;
+        versions.push((name.to_string(), version));
+    }
+    Ok(versions)
+}
+
+/// Returns the compiler version the `results` were recorded with without the
+/// `rustc ` prefix, e.g. `1.56.1 (59eed8a2a 2021-11-01)`.
+fn rustc_version(criterion_dir: &Path, name: &str, results: &[BenchResult]) -> Result<String> {
+    let environment =
+        read_environment(criterion_dir, "new", group_ids(results))?.ok_or_else(|| {
+            anyhow!(
+                "No environment recorded for the {} results, rerun them to record their compiler.",
+                name
+            )
+        })?;
+    let rustc = environment.rustc().ok_or_else(|| {
+        anyhow!(
+            "The {} results were compiled with different Rust compilers, rerun them with one.",
+            name
+        )
+    })?;
+    Ok(rustc.strip_prefix("rustc ").unwrap_or(rustc).to_string())
+}
+
+/// Generates the contents of a README results section for `libs`.
+fn generate_section(
+    results: &[BenchResult],
+    libs: &[&str],
+    prefix: &str,
+    threshold_percent: f64,
+    versions: &[(String, String)],
+    rustc_version: &str,
+) -> Result<String> {
+    let mut section = Vec::new();
+    writeln!(
+        section,
+        "These benchmarks were compiled with the `{}` Rust compiler. Lower",
+        rustc_version
+    )?;
+    writeln!(
+        section,
+        "(better) numbers are highlighted within a {}% range of the minimum for each",
+        threshold_percent
+    )?;
+    writeln!(section, "row.")?;
+    writeln!(section)?;
+    writeln!(section, "The versions of the libraries tested were:")?;
+    writeln!(section)?;
+    for (name, version) in versions {
+        writeln!(section, "* `{}` - `{}`", name, version)?;
+    }
+    writeln!(section)?;
+    let threshold = 1.0 + threshold_percent / 100.0;
+    summarize(results, libs, prefix, threshold, &mut section)?;
+    Ok(String::from_utf8(section)?)
+}
+
+/// Replaces the text between the `start` and `end` marker lines of `readme`.
+fn replace_between(readme: &str, start: &str, end: &str, contents: &str) -> Result<String> {
+    let start_index = readme
+        .find(start)
+        .ok_or_else(|| anyhow!("Marker '{}' not found in README", start))?
+        + start.len();
+    let end_index = readme[start_index..]
+        .find(end)
+        .ok_or_else(|| anyhow!("Marker '{}' not found in README", end))?
+        + start_index;
+    Ok(format!(
+        "{}\n{}{}",
+        &readme[..start_index],
+        contents,
+        &readme[end_index..]
+    ))
+}
+
+/// Regenerates the scalar and wide benchmark results in the README at
+/// `readme_path`. Each section is replaced between `<!-- summarize:scalar -->`
+/// and `<!-- summarize:scalar:end -->` (or `wide`) marker comments. Library
+/// versions are read from the `Cargo.lock` or `Cargo.toml` next to the README
+/// and the compiler version from the environment the section's benchmark
+/// groups recorded in `criterion_dir`.
+///
+/// Sections without any results are left as they are.
+pub fn update_readme(
+    readme_path: &Path,
+    criterion_dir: &Path,
+    results: &[BenchResult],
+    threshold_percent: f64,
+) -> Result<()> {
+    let workspace_dir = readme_path
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let mut readme = fs::read_to_string(readme_path)
+        .with_context(|| format!("Failed to read {}", readme_path.display()))?;
+
+    let scalar_libs = scalar_libs();
+    for (name, libs, prefix) in [
+        ("scalar", &scalar_libs[..], SCALAR_PREFIX),
+        ("wide", WIDE, WIDE_PREFIX),
+    ] {
+        // only include libraries that have results for this section
+        let section_results: Vec<BenchResult> = results
+            .iter()
+            .filter(|result| result.group_id.starts_with(prefix))
+            .cloned()
+            .collect();
+        let libs: Vec<&str> = libs
+            .iter()
+            .copied()
+            .filter(|lib| section_results.iter().any(|result| result.library == *lib))
+            .collect();
+        if libs.is_empty() {
+            eprintln!("No {} results found, skipping README section.", name);
+            continue;
+        }
+
+        let versions = lib_versions(workspace_dir, &libs)?;
+        let rustc_version = rustc_version(criterion_dir, name, &section_results)?;
+        let contents = generate_section(
+            &section_results,
+            &libs,
+            prefix,
+            threshold_percent,
+            &versions,
+            &rustc_version,
+        )?;
+        readme = replace_between(
+            &readme,
+            &format!("<!-- summarize:{} -->", name),
+            &format!("<!-- summarize:{}:end -->", name),
+            &contents,
+        )?;
+    }
+
+    fs::write(readme_path, readme)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crate_name_strips_lane_suffixes() {
+        assert_eq!(crate_name("glam"), "glam");
+        assert_eq!(crate_name("pathfinder"), "pathfinder_geometry");
+        assert_eq!(crate_name("glam_f32x1"), "glam");
+        assert_eq!(crate_name("ultraviolet_f32x4"), "ultraviolet");
+        assert_eq!(crate_name("ultraviolet_f64x4"), "ultraviolet");
+        assert_eq!(crate_name("nalgebra_f32x16"), "nalgebra");
+    }
+
+    #[test]
+    fn every_library_has_a_crate() {
+        let manifest: toml::Value = toml::from_str(include_str!("../../../Cargo.toml")).unwrap();
+        for lib in crate::all_libs() {
+            assert!(
+                manifest_version(&manifest, crate_name(lib)).is_some(),
+                "{} has no crate in Cargo.toml",
+                lib
+            );
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{display_name, markdown_table, Benches};
+use anyhow::Result;
+use std::{collections::BTreeMap, io::Write};
+
+const OVERALL: &str = "overall";
+
+/// Returns the category a benchmark is scored in, based on its name without the
+/// scalar, scalar f64 or wide prefix.
+fn category(name: &str) -> &str {
+    let first = name.split(' ').next().unwrap_or(name);
+    match first {
+        "euler" | "ray-sphere" => "workloads",
+        // transform types and transforming vectors, e.g. `transform2 inverse`
+        // and `transform vector3`
+        _ if first.starts_with("transform") => "transformations",
+        _ => first,
+    }
+}
+
+/// Running geometric mean of a library's time relative to the fastest library.
+#[derive(Debug, Default, Copy, Clone)]
+struct Score {
+    log_sum: f64,
+    count: usize,
+}
+
+impl Score {
+    fn add(&mut self, ratio: f64) {
+        self.log_sum += ratio.ln();
+        self.count += 1;
+    }
+
+    fn geometric_mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some((self.log_sum / self.count as f64).exp())
+        }
+    }
+}
+
+/// Scores for each library keyed by category then library.
+type Scores<'a> = BTreeMap<&'a str, BTreeMap<&'a str, Score>>;
+
+/// Computes the geometric mean of each library's time relative to the per-row
+/// minimum, per category and overall.
+///
+/// Libraries with no result for a row (`N/A` in the summary table) are not
+/// penalised, the row simply isn't included in that library's score. Rows with
+/// results for fewer than two libraries and "return self" rows, which only
+/// measure benchmark overhead, are skipped entirely.
+///
+/// Returns the scores and the number of rows scored per category.
+fn compute_scores<'a>(
+    benches: &'a Benches,
+    libs: &[&'a str],
+    prefix: &str,
+) -> (Scores<'a>, BTreeMap<&'a str, usize>) {
+    let mut scores = Scores::new();
+    let mut rows = BTreeMap::new();
+    for (bench_name, bench) in benches {
+        let name = match bench_name.strip_prefix(prefix) {
+            Some(name) => name,
+            None => continue,
+        };
+        if name.contains("return self") {
+            continue;
+        }
+
+        let values: Vec<(&str, f64)> = libs
+            .iter()
+            .filter_map(|&lib| bench.get(lib).map(|&value| (lib, value)))
+            .collect();
+        if values.len() < 2 {
+            continue;
+        }
+        let min_value = values
+            .iter()
+            .map(|&(_, value)| value)
+            .fold(f64::MAX, f64::min);
+
+        for category in [category(name), OVERALL] {
+            *rows.entry(category).or_insert(0) += 1;
+            let category_scores = scores.entry(category).or_default();
+            for &(lib, value) in &values {
+                category_scores
+                    .entry(lib)
+                    .or_default()
+                    .add(value / min_value);
+            }
+        }
+    }
+    (scores, rows)
+}
+
+/// Prints a table of geometric mean scores for each library, with libraries
+/// ranked by their overall score. A score of 1.0 means the library was the
+/// fastest on every row it has a result for. The number of rows each score is
+/// based on is shown in brackets when a library is missing some rows.
+///
+/// Only benches whose names start with `prefix` are scored, categories of f64
+/// benches are shown with an `f64` prefix.
+pub fn score<W: Write>(
+    benches: &Benches,
+    libs: &[&str],
+    prefix: &str,
+    writer: &mut W,
+) -> Result<()> {
+    use prettytable::{format::Alignment, Cell, Row};
+
+    let (scores, rows) = compute_scores(benches, libs, prefix);
+    let precision = display_name(prefix);
+    let overall = scores.get(OVERALL);
+    let overall_score = |lib: &str| overall.and_then(|scores| scores.get(lib)?.geometric_mean());
+
+    // rank libraries by overall score, libraries without any score go last
+    let mut ranked: Vec<&str> = libs.to_vec();
+    ranked.sort_by(|a, b| match (overall_score(a), overall_score(b)) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).unwrap(),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    let mut table = markdown_table();
+    let mut titles = vec![Cell::new_align("category", Alignment::LEFT)];
+    for (rank, lib) in ranked.iter().enumerate() {
+        let title = match overall_score(lib) {
+            Some(_) => format!("{}. {}", rank + 1, lib),
+            None => lib.to_string(),
+        };
+        titles.push(Cell::new_align(&title, Alignment::RIGHT));
+    }
+    table.set_titles(Row::new(titles));
+
+    // print the overall score last
+    let categories = scores
+        .keys()
+        .filter(|&&category| category != OVERALL)
+        .chain(scores.keys().filter(|&&category| category == OVERALL));
+    for &category in categories {
+        let total_rows = rows[category];
+        let mut row = vec![Cell::new_align(
+            &format!("{}{} ({} rows)", precision, category, total_rows),
+            Alignment::LEFT,
+        )];
+        for lib in &ranked {
+            let cell = match scores[category].get(lib) {
+                Some(score) => {
+                    let mean = score.geometric_mean().unwrap();
+                    if score.count == total_rows {
+                        format!("{:.3}", mean)
+                    } else {
+                        format!("{:.3} ({})", mean, score.count)
+                    }
+                }
+                None => "N/A".to_string(),
+            };
+            row.push(Cell::new_align(&cell, Alignment::RIGHT));
+        }
+        table.add_row(Row::new(row));
+    }
+
+    table.print(writer)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SCALAR_F64_PREFIX, SCALAR_PREFIX};
+
+    fn benches(rows: &[(&str, &[(&str, f64)])]) -> Benches {
+        rows.iter()
+            .map(|(name, values)| {
+                let values = values
+                    .iter()
+                    .map(|&(lib, value)| (lib.to_string(), value))
+                    .collect();
+                (name.to_string(), values)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn transforms_share_a_category() {
+        assert_eq!(category("transform vector3 x1"), "transformations");
+        assert_eq!(category("transform2 inverse"), "transformations");
+        assert_eq!(category("transform3 mul transform3d"), "transformations");
+        assert_eq!(category("euler 2d x10000"), "workloads");
+        assert_eq!(category("matrix4 inverse"), "matrix4");
+    }
+
+    #[test]
+    fn f64_benches_are_scored_separately() {
+        let benches = benches(&[
+            (
+                "scalar matrix4 inverse",
+                &[("glam", 1.0), ("nalgebra", 2.0)],
+            ),
+            (
+                "scalar-f64 matrix4 inverse",
+                &[("glam", 4.0), ("nalgebra", 2.0)],
+            ),
+        ]);
+        let libs = ["glam", "nalgebra"];
+
+        let (scores, rows) = compute_scores(&benches, &libs, SCALAR_PREFIX);
+        assert_eq!(rows[OVERALL], 1);
+        assert_eq!(scores[OVERALL]["glam"].geometric_mean(), Some(1.0));
+        assert_eq!(scores[OVERALL]["nalgebra"].geometric_mean(), Some(2.0));
+
+        let (scores, rows) = compute_scores(&benches, &libs, SCALAR_F64_PREFIX);
+        assert_eq!(rows[OVERALL], 1);
+        assert_eq!(rows["matrix4"], 1);
+        assert_eq!(scores["matrix4"]["glam"].geometric_mean(), Some(2.0));
+        assert_eq!(scores["matrix4"]["nalgebra"].geometric_mean(), Some(1.0));
+    }
+
+    #[test]
+    fn geometric_mean_of_ratios() {
+        let mut score = Score::default();
+        assert_eq!(score.geometric_mean(), None);
+        score.add(2.0);
+        score.add(8.0);
+        assert!((score.geometric_mean().unwrap() - 4.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn missing_and_overhead_rows_are_not_scored() {
+        let benches = benches(&[
+            (
+                "scalar matrix4 inverse",
+                &[("glam", 1.0), ("cgmath", 4.0), ("nalgebra", 2.0)],
+            ),
+            // nalgebra has no result here, which doesn't count against it
+            (
+                "scalar matrix4 determinant",
+                &[("glam", 2.0), ("cgmath", 2.0)],
+            ),
+            // only one library, nothing to compare
+            ("scalar vector3 dot", &[("glam", 1.0)]),
+            (
+                "scalar matrix4 return self",
+                &[("glam", 1.0), ("cgmath", 100.0)],
+            ),
+        ]);
+        let libs = ["glam", "cgmath", "nalgebra"];
+
+        let (scores, rows) = compute_scores(&benches, &libs, SCALAR_PREFIX);
+        assert_eq!(rows[OVERALL], 2);
+        assert_eq!(rows["matrix4"], 2);
+        assert!(!scores.contains_key("vector3"));
+        let overall = &scores[OVERALL];
+        assert_eq!(overall["glam"].geometric_mean(), Some(1.0));
+        assert_eq!(overall["cgmath"].count, 2);
+        assert!((overall["cgmath"].geometric_mean().unwrap() - 2.0).abs() < 1e-12);
+        assert_eq!(overall["nalgebra"].count, 1);
+        assert_eq!(overall["nalgebra"].geometric_mean(), Some(2.0));
+
+        let mut output = Vec::new();
+        score(&benches, &libs, SCALAR_PREFIX, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        // ranked by overall score, with the rows of incomplete scores
+        assert!(output.contains("1. glam"));
+        assert!(output.contains("2.000 (1)"));
+    }
+}